@@ -0,0 +1,316 @@
+use std::cmp::{max, Ordering};
+
+/// A self-balancing binary search tree.
+///
+/// After every insertion and removal the heights of the two subtrees of any
+/// node differ by at most one, which keeps the height below about
+/// `1.44 * log2(n)` and therefore every operation in O(log n).
+pub struct AvlTree<T: Ord> {
+    root: Tree<T>,
+    len: usize,
+}
+
+type Tree<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    height: usize,
+    left: Tree<T>,
+    right: Tree<T>,
+}
+
+impl<T: Ord> AvlTree<T> {
+    pub fn new() -> Self {
+        AvlTree { root: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of levels of the tree, 0 if it is empty.
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            current = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            }
+        }
+        false
+    }
+
+    /// Insert `value`. Returns false if it was already present.
+    pub fn insert(&mut self, value: T) -> bool {
+        let inserted = insert(&mut self.root, value);
+        if inserted {
+            self.len += 1;
+        }
+        inserted
+    }
+
+    /// Remove `value`. Returns false if it was not present.
+    pub fn remove(&mut self, value: &T) -> bool {
+        let removed = remove(&mut self.root, value);
+        if removed {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// Iterate over the values in ascending order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(&self.root);
+        iter
+    }
+}
+
+impl<T: Ord> Default for AvlTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn height<T>(tree: &Tree<T>) -> usize {
+    tree.as_ref().map_or(0, |node| node.height)
+}
+
+impl<T> Node<T> {
+    fn update_height(&mut self) {
+        self.height = 1 + max(height(&self.left), height(&self.right));
+    }
+
+    // Positive when the left subtree is taller.
+    fn balance_factor(&self) -> isize {
+        height(&self.left) as isize - height(&self.right) as isize
+    }
+}
+
+//     y            x
+//    / \          / \
+//   x   c  -->   a   y
+//  / \              / \
+// a   b            b   c
+fn rotate_right<T>(tree: &mut Tree<T>) {
+    let mut y = tree.take().unwrap();
+    let mut x = y.left.take().unwrap();
+    y.left = x.right.take();
+    y.update_height();
+    x.right = Some(y);
+    x.update_height();
+    *tree = Some(x);
+}
+
+// Mirror image of `rotate_right`.
+fn rotate_left<T>(tree: &mut Tree<T>) {
+    let mut x = tree.take().unwrap();
+    let mut y = x.right.take().unwrap();
+    x.right = y.left.take();
+    x.update_height();
+    y.left = Some(x);
+    y.update_height();
+    *tree = Some(y);
+}
+
+// Restore the AVL property at the root of `tree`, assuming both subtrees
+// are balanced and their heights differ by at most two.
+fn rebalance<T>(tree: &mut Tree<T>) {
+    let node = match tree.as_mut() {
+        Some(node) => node,
+        None => return,
+    };
+    node.update_height();
+    let balance = node.balance_factor();
+
+    if balance > 1 {
+        // LR case: turn it into an LL case first.
+        if node.left.as_ref().unwrap().balance_factor() < 0 {
+            rotate_left(&mut node.left);
+        }
+        rotate_right(tree);
+    } else if balance < -1 {
+        // RL case: turn it into an RR case first.
+        if node.right.as_ref().unwrap().balance_factor() > 0 {
+            rotate_right(&mut node.right);
+        }
+        rotate_left(tree);
+    }
+}
+
+fn insert<T: Ord>(tree: &mut Tree<T>, value: T) -> bool {
+    let inserted = match tree {
+        None => {
+            *tree = Some(Box::new(Node {
+                value,
+                height: 1,
+                left: None,
+                right: None,
+            }));
+            return true;
+        }
+        Some(node) => match value.cmp(&node.value) {
+            Ordering::Less => insert(&mut node.left, value),
+            Ordering::Greater => insert(&mut node.right, value),
+            Ordering::Equal => false,
+        },
+    };
+    if inserted {
+        rebalance(tree);
+    }
+    inserted
+}
+
+fn remove<T: Ord>(tree: &mut Tree<T>, value: &T) -> bool {
+    let removed = match tree {
+        None => return false,
+        Some(node) => match value.cmp(&node.value) {
+            Ordering::Less => remove(&mut node.left, value),
+            Ordering::Greater => remove(&mut node.right, value),
+            Ordering::Equal => {
+                match (node.left.is_some(), node.right.is_some()) {
+                    (true, true) => node.value = remove_min(&mut node.right),
+                    (true, false) => *tree = node.left.take(),
+                    (false, _) => *tree = node.right.take(),
+                }
+                true
+            }
+        },
+    };
+    if removed {
+        rebalance(tree);
+    }
+    removed
+}
+
+// Remove the smallest value of a non-empty tree and return it.
+fn remove_min<T>(tree: &mut Tree<T>) -> T {
+    if tree.as_ref().unwrap().left.is_some() {
+        let min = remove_min(&mut tree.as_mut().unwrap().left);
+        rebalance(tree);
+        min
+    } else {
+        let node = *tree.take().unwrap();
+        *tree = node.right;
+        node.value
+    }
+}
+
+pub struct Iter<'a, T: 'a> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn push_left(&mut self, mut tree: &'a Tree<T>) {
+        while let Some(node) = tree {
+            self.stack.push(node);
+            tree = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        Some(&node.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Check the AVL property and the cached heights of every node.
+    fn is_balanced<T>(tree: &Tree<T>) -> bool {
+        match tree {
+            None => true,
+            Some(node) => {
+                node.height == 1 + max(height(&node.left), height(&node.right))
+                    && node.balance_factor().abs() <= 1
+                    && is_balanced(&node.left)
+                    && is_balanced(&node.right)
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let tree: AvlTree<i32> = AvlTree::new();
+        assert!(tree.is_empty());
+        assert_eq!(tree.height(), 0);
+        assert_eq!(tree.iter().next(), None);
+    }
+
+    #[test]
+    fn sorted_insertion_stays_balanced() {
+        let mut tree = AvlTree::new();
+        for i in 1..=1000 {
+            assert!(tree.insert(i));
+        }
+        assert_eq!(tree.len(), 1000);
+        assert!(is_balanced(&tree.root));
+
+        let bound = 1.44 * (1000f64 + 2.0).log2();
+        assert!((tree.height() as f64) <= bound);
+    }
+
+    #[test]
+    fn iteration_is_sorted() {
+        let mut tree = AvlTree::new();
+        for value in vec![50, 20, 80, 10, 30, 70, 90, 25, 35, 75] {
+            tree.insert(value);
+        }
+        assert!(!tree.insert(30));
+        assert_eq!(
+            tree.iter().cloned().collect::<Vec<_>>(),
+            vec![10, 20, 25, 30, 35, 50, 70, 75, 80, 90]
+        );
+    }
+
+    #[test]
+    fn rotation_cases() {
+        // LL, RR, LR and RL insertion orders all end in the same tree.
+        for order in &[[3, 2, 1], [1, 2, 3], [3, 1, 2], [1, 3, 2]] {
+            let mut tree = AvlTree::new();
+            for &value in order.iter() {
+                tree.insert(value);
+            }
+            assert_eq!(tree.height(), 2);
+            assert_eq!(tree.root.as_ref().unwrap().value, 2);
+        }
+    }
+
+    #[test]
+    fn remove() {
+        let mut tree = AvlTree::new();
+        for i in 0..100 {
+            tree.insert(i);
+        }
+        for i in (0..100).filter(|i| i % 3 != 0) {
+            assert!(tree.remove(&i));
+            assert!(is_balanced(&tree.root));
+        }
+        assert!(!tree.remove(&1));
+
+        for i in 0..100 {
+            assert_eq!(tree.contains(&i), i % 3 == 0);
+        }
+        assert_eq!(tree.len(), 34);
+        assert_eq!(
+            tree.iter().cloned().collect::<Vec<_>>(),
+            (0..100).filter(|i| i % 3 == 0).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,147 @@
+/// A max-heap stored in a `Vec`, with the children of node `i` at
+/// `2 * i + 1` and `2 * i + 2`.
+///
+/// Wrap the items in `std::cmp::Reverse` to get a min-heap instead.
+#[derive(Debug, Clone)]
+pub struct BinaryHeap<T> {
+    data: Vec<T>,
+}
+
+impl<T: Ord> BinaryHeap<T> {
+    pub fn new() -> Self {
+        BinaryHeap { data: Vec::new() }
+    }
+
+    /// Turn `data` into a heap in O(n) by sifting down every inner node,
+    /// starting with the last one.
+    pub fn from_vec(data: Vec<T>) -> Self {
+        let mut heap = BinaryHeap { data };
+        for i in (0..heap.data.len() / 2).rev() {
+            heap.sift_down(i);
+        }
+        heap
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the largest item without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.data.push(item);
+        self.sift_up(self.data.len() - 1);
+    }
+
+    /// Remove and return the largest item.
+    pub fn pop(&mut self) -> Option<T> {
+        let item = self.data.pop()?;
+        if self.data.is_empty() {
+            return Some(item);
+        }
+        let top = std::mem::replace(&mut self.data[0], item);
+        self.sift_down(0);
+        Some(top)
+    }
+
+    /// Consume the heap and return its items in ascending order.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut sorted = Vec::with_capacity(self.len());
+        while let Some(item) = self.pop() {
+            sorted.push(item);
+        }
+        sorted.reverse();
+        sorted
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if self.data[i] <= self.data[parent] {
+                break;
+            }
+            self.data.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let mut largest = i;
+            for child in [2 * i + 1, 2 * i + 2] {
+                if child < self.data.len() && self.data[child] > self.data[largest] {
+                    largest = child;
+                }
+            }
+            if largest == i {
+                break;
+            }
+            self.data.swap(i, largest);
+            i = largest;
+        }
+    }
+}
+
+impl<T: Ord> Default for BinaryHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+    use std::cmp::Reverse;
+
+    #[test]
+    fn pops_in_descending_order() {
+        let mut heap = BinaryHeap::new();
+        for value in [5, 1, 8, 3, 9, 2, 8] {
+            heap.push(value);
+        }
+        assert_eq!(heap.len(), 7);
+        assert_eq!(heap.peek(), Some(&9));
+
+        let mut popped = Vec::new();
+        while let Some(value) = heap.pop() {
+            popped.push(value);
+        }
+        assert_eq!(popped, vec![9, 8, 8, 5, 3, 2, 1]);
+        assert!(heap.is_empty());
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn heapify_matches_push() {
+        let mut rng = XorShift::new(7);
+        let values: Vec<u64> = (0..200).map(|_| rng.gen_range(0, 50)).collect();
+
+        let mut pushed = BinaryHeap::new();
+        for &value in &values {
+            pushed.push(value);
+        }
+        let heapified = BinaryHeap::from_vec(values.clone());
+
+        let mut expected = values;
+        expected.sort();
+        assert_eq!(pushed.into_sorted_vec(), expected);
+        assert_eq!(heapified.into_sorted_vec(), expected);
+    }
+
+    #[test]
+    fn min_heap() {
+        let mut heap = BinaryHeap::from_vec(vec![Reverse(4), Reverse(1), Reverse(3)]);
+        heap.push(Reverse(2));
+        assert_eq!(heap.peek(), Some(&Reverse(1)));
+        let popped: Vec<_> = std::iter::from_fn(|| heap.pop().map(|Reverse(v)| v)).collect();
+        assert_eq!(popped, vec![1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,249 @@
+use std::cmp::Ordering;
+use std::mem;
+
+/// An ordered map stored as a B-tree of minimum degree `t`.
+///
+/// Every node but the root holds between `t - 1` and `2t - 1` sorted
+/// entries, an inner node with `k` entries has `k + 1` children, and all
+/// leaves are at the same depth, so the height is O(log_t n). Insertion
+/// splits every full node it passes on the way down, which guarantees that
+/// the leaf it ends in has room for one more entry.
+pub struct BTree<K: Ord, V> {
+    root: Node<K, V>,
+    min_degree: usize,
+    len: usize,
+}
+
+struct Node<K, V> {
+    entries: Vec<(K, V)>,
+    // empty for leaves
+    children: Vec<Node<K, V>>,
+}
+
+impl<K, V> Node<K, V> {
+    fn new() -> Self {
+        Node {
+            entries: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl<K: Ord, V> BTree<K, V> {
+    /// Create an empty tree whose nodes hold at most `2 * min_degree - 1`
+    /// entries. Panics if `min_degree < 2`.
+    pub fn new(min_degree: usize) -> Self {
+        assert!(min_degree >= 2, "minimum degree must be at least 2");
+        BTree {
+            root: Node::new(),
+            min_degree,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let mut node = &self.root;
+        loop {
+            match node.entries.binary_search_by(|(k, _)| k.cmp(key)) {
+                Ok(i) => return Some(&node.entries[i].1),
+                Err(_) if node.is_leaf() => return None,
+                Err(i) => node = &node.children[i],
+            }
+        }
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Insert `value` under `key`. Returns the previous value, if any.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let t = self.min_degree;
+        if self.root.entries.len() == 2 * t - 1 {
+            // The tree grows at the root only, which keeps all leaves at the
+            // same depth.
+            let old_root = mem::replace(&mut self.root, Node::new());
+            self.root.children.push(old_root);
+            split_child(&mut self.root, 0, t);
+        }
+
+        let old = insert_non_full(&mut self.root, key, value, t);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+}
+
+// Split the full child `i` of `parent` around its median entry, which moves
+// up into `parent`. Both halves keep t - 1 entries.
+fn split_child<K, V>(parent: &mut Node<K, V>, i: usize, t: usize) {
+    let child = &mut parent.children[i];
+    let mut right = Node::new();
+    right.entries = child.entries.split_off(t);
+    if !child.is_leaf() {
+        right.children = child.children.split_off(t);
+    }
+    let median = child.entries.pop().unwrap();
+
+    parent.entries.insert(i, median);
+    parent.children.insert(i + 1, right);
+}
+
+fn insert_non_full<K: Ord, V>(node: &mut Node<K, V>, key: K, value: V, t: usize) -> Option<V> {
+    let mut i = match node.entries.binary_search_by(|(k, _)| k.cmp(&key)) {
+        Ok(i) => return Some(mem::replace(&mut node.entries[i].1, value)),
+        Err(i) => i,
+    };
+    if node.is_leaf() {
+        node.entries.insert(i, (key, value));
+        return None;
+    }
+
+    if node.children[i].entries.len() == 2 * t - 1 {
+        split_child(node, i, t);
+        // The median that moved up may be the key itself, or smaller.
+        match key.cmp(&node.entries[i].0) {
+            Ordering::Equal => {
+                return Some(mem::replace(&mut node.entries[i].1, value));
+            }
+            Ordering::Greater => i += 1,
+            Ordering::Less => {}
+        }
+    }
+    insert_non_full(&mut node.children[i], key, value, t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    // Check the occupancy and ordering invariants and return the keys in
+    // order together with the depth of the leaves.
+    fn validate<K: Ord + Clone, V>(tree: &BTree<K, V>) -> (Vec<K>, usize) {
+        fn walk<K: Ord + Clone, V>(
+            node: &Node<K, V>,
+            is_root: bool,
+            t: usize,
+            depth: usize,
+            keys: &mut Vec<K>,
+            leaf_depth: &mut Option<usize>,
+        ) {
+            let len = node.entries.len();
+            assert!(len < 2 * t);
+            if !is_root {
+                assert!(len >= t - 1);
+            }
+            if node.is_leaf() {
+                assert_eq!(*leaf_depth.get_or_insert(depth), depth);
+                keys.extend(node.entries.iter().map(|(k, _)| k.clone()));
+                return;
+            }
+            assert_eq!(node.children.len(), len + 1);
+            for (child, entry) in node.children.iter().zip(&node.entries) {
+                walk(child, false, t, depth + 1, keys, leaf_depth);
+                keys.push(entry.0.clone());
+            }
+            walk(
+                node.children.last().unwrap(),
+                false,
+                t,
+                depth + 1,
+                keys,
+                leaf_depth,
+            );
+        }
+
+        let mut keys = Vec::new();
+        let mut leaf_depth = None;
+        walk(
+            &tree.root,
+            true,
+            tree.min_degree,
+            0,
+            &mut keys,
+            &mut leaf_depth,
+        );
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(keys.len(), tree.len());
+        (keys, leaf_depth.unwrap())
+    }
+
+    #[test]
+    fn empty() {
+        let tree: BTree<i32, i32> = BTree::new(2);
+        assert!(tree.is_empty());
+        assert_eq!(tree.get(&1), None);
+        validate(&tree);
+    }
+
+    #[test]
+    fn random_insertions() {
+        for t in 2..6 {
+            let mut rng = XorShift::new(t as u64);
+            let mut tree = BTree::new(t);
+            let mut keys: Vec<u64> = (0..2000).collect();
+            for i in (1..keys.len()).rev() {
+                keys.swap(i, rng.gen_range(0, i as u64 + 1) as usize);
+            }
+            for &key in &keys {
+                assert_eq!(tree.insert(key, key * 10), None);
+            }
+
+            let (sorted, depth) = validate(&tree);
+            assert_eq!(sorted, (0..2000).collect::<Vec<_>>());
+            // a tree of height h holds at least 2t^h - 1 keys
+            assert!(2 * t.pow(depth as u32) - 1 <= 2000);
+            for key in 0..2000 {
+                assert_eq!(tree.get(&key), Some(&(key * 10)));
+            }
+            assert!(!tree.contains_key(&2000));
+        }
+    }
+
+    #[test]
+    fn sorted_insertions() {
+        let mut tree = BTree::new(3);
+        for key in 0..2000 {
+            tree.insert(key, ());
+            if key % 100 == 0 {
+                validate(&tree);
+            }
+        }
+        validate(&tree);
+        assert!((0..2000).all(|key| tree.contains_key(&key)));
+    }
+
+    #[test]
+    fn replace_existing() {
+        let mut tree = BTree::new(2);
+        for key in 0..50 {
+            tree.insert(key, "old");
+        }
+        for key in 0..50 {
+            assert_eq!(tree.insert(key, "new"), Some("old"));
+        }
+        assert_eq!(tree.len(), 50);
+        validate(&tree);
+        assert!((0..50).all(|key| tree.get(&key) == Some(&"new")));
+    }
+
+    #[test]
+    #[should_panic]
+    fn degree_too_small() {
+        let _tree: BTree<i32, i32> = BTree::new(1);
+    }
+}
@@ -0,0 +1,124 @@
+/// Build the min-heap ordered Cartesian tree of `arr` and return the parent
+/// index of every element, `None` for the root.
+///
+/// Every node is no larger than its descendants, and an in-order walk of
+/// the tree visits the elements in their original order. Among equal
+/// elements the leftmost one is the ancestor, so the root is the index of
+/// the leftmost minimum. The minimum of `arr[l..=r]` is then the lowest
+/// common ancestor of `l` and `r`.
+///
+/// # Algorithm
+///
+/// Keep a stack holding the right spine of the tree built so far, whose
+/// values increase from bottom to top. Each new element becomes the right
+/// child of the last spine node not larger than it, and the part of the
+/// spine it pops off becomes its left subtree. Every index is pushed and
+/// popped at most once, so this takes O(n) time.
+pub fn cartesian_tree<T: Ord>(arr: &[T]) -> Vec<Option<usize>> {
+    let mut parent = vec![None; arr.len()];
+    let mut spine: Vec<usize> = Vec::new();
+
+    for (i, value) in arr.iter().enumerate() {
+        let mut last = None;
+        while let Some(&top) = spine.last() {
+            if arr[top] <= *value {
+                break;
+            }
+            last = spine.pop();
+        }
+        if let Some(last) = last {
+            parent[last] = Some(i);
+        }
+        if let Some(&top) = spine.last() {
+            parent[i] = Some(top);
+        }
+        spine.push(i);
+    }
+    parent
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    // Check the heap order and that every subtree covers a contiguous range
+    // of indices in which its root is the leftmost minimum.
+    fn check<T: Ord>(arr: &[T], parent: &[Option<usize>]) {
+        let n = arr.len();
+        assert_eq!(parent.iter().filter(|p| p.is_none()).count(), n.min(1));
+
+        let mut subtree = vec![Vec::new(); n];
+        for i in 0..n {
+            let mut node = Some(i);
+            while let Some(ancestor) = node {
+                subtree[ancestor].push(i);
+                node = parent[ancestor];
+                assert!(subtree[ancestor].len() <= n, "cycle");
+            }
+        }
+        for (i, members) in subtree.iter().enumerate() {
+            if let Some(p) = parent[i] {
+                assert!(arr[p] <= arr[i]);
+            }
+            let (lo, hi) = (members[0], *members.last().unwrap());
+            assert_eq!(members.len(), hi - lo + 1);
+            let min = arr[lo..=hi].iter().min().unwrap();
+            assert_eq!(arr[lo..=hi].iter().position(|v| v == min), Some(i - lo));
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert!(cartesian_tree::<i32>(&[]).is_empty());
+    }
+
+    #[test]
+    fn small_array() {
+        //     1
+        //    / \
+        //   9   2
+        //      / \
+        //     3   5
+        //    / \
+        //   7   8
+        let arr = [9, 1, 7, 3, 8, 2, 5];
+        let parent = cartesian_tree(&arr);
+        assert_eq!(
+            parent,
+            vec![Some(1), None, Some(3), Some(5), Some(3), Some(1), Some(5)]
+        );
+        check(&arr, &parent);
+    }
+
+    #[test]
+    fn sorted_inputs_are_paths() {
+        let ascending: Vec<i32> = (0..10).collect();
+        let parent = cartesian_tree(&ascending);
+        assert_eq!(parent[0], None);
+        assert!((1..10).all(|i| parent[i] == Some(i - 1)));
+
+        let descending: Vec<i32> = (0..10).rev().collect();
+        let parent = cartesian_tree(&descending);
+        assert_eq!(parent[9], None);
+        assert!((0..9).all(|i| parent[i] == Some(i + 1)));
+    }
+
+    #[test]
+    fn duplicates() {
+        let arr = [2, 1, 2, 1, 2];
+        let parent = cartesian_tree(&arr);
+        assert_eq!(parent[1], None);
+        assert_eq!(parent[3], Some(1));
+        check(&arr, &parent);
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift::new(73);
+        for len in 1..100 {
+            let arr: Vec<u64> = (0..len).map(|_| rng.gen_range(0, 20)).collect();
+            check(&arr, &cartesian_tree(&arr));
+        }
+    }
+}
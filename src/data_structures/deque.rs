@@ -0,0 +1,194 @@
+/// A double-ended queue in a growable circular buffer.
+///
+/// The items occupy the `len` slots starting at `head`, wrapping around at
+/// the end of the buffer. Slots are `Option<T>` so that no `unsafe` code is
+/// needed for the unused ones. When the buffer is full it is reallocated at
+/// twice the size and the items are moved to the front in order, so pushes
+/// at either end take amortized O(1) time.
+#[derive(Debug)]
+pub struct Deque<T> {
+    buffer: Vec<Option<T>>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> Deque<T> {
+    pub fn new() -> Self {
+        Deque {
+            buffer: Vec::new(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of items the deque holds without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn push_front(&mut self, item: T) {
+        self.grow_if_full();
+        self.head = self.wrap_sub(self.head, 1);
+        self.buffer[self.head] = Some(item);
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, item: T) {
+        self.grow_if_full();
+        let tail = self.slot(self.len);
+        self.buffer[tail] = Some(item);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let item = self.buffer[self.head].take();
+        self.head = self.slot(1);
+        self.len -= 1;
+        item
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.len -= 1;
+        let tail = self.slot(self.len);
+        self.buffer[tail].take()
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    /// Returns the item at position `i`, counting from the front.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        if i < self.len {
+            self.buffer[self.slot(i)].as_ref()
+        } else {
+            None
+        }
+    }
+
+    // Buffer slot of the item at position `i`.
+    fn slot(&self, i: usize) -> usize {
+        (self.head + i) % self.buffer.len()
+    }
+
+    fn wrap_sub(&self, index: usize, n: usize) -> usize {
+        (index + self.buffer.len() - n) % self.buffer.len()
+    }
+
+    fn grow_if_full(&mut self) {
+        if self.len < self.buffer.len() {
+            return;
+        }
+        let capacity = (2 * self.buffer.len()).max(4);
+        let mut buffer: Vec<Option<T>> = Vec::with_capacity(capacity);
+        for i in 0..self.len {
+            let slot = self.slot(i);
+            buffer.push(self.buffer[slot].take());
+        }
+        buffer.resize_with(capacity, || None);
+        self.buffer = buffer;
+        self.head = 0;
+    }
+}
+
+impl<T> Default for Deque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn empty() {
+        let mut deque: Deque<i32> = Deque::new();
+        assert!(deque.is_empty());
+        assert_eq!(deque.pop_front(), None);
+        assert_eq!(deque.pop_back(), None);
+        assert_eq!(deque.front(), None);
+        assert_eq!(deque.back(), None);
+    }
+
+    #[test]
+    fn both_ends() {
+        let mut deque = Deque::new();
+        deque.push_back(2);
+        deque.push_front(1);
+        deque.push_back(3);
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.front(), Some(&1));
+        assert_eq!(deque.back(), Some(&3));
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.pop_front(), Some(2));
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn order_across_growth() {
+        let mut deque = Deque::new();
+        // Wrap the head around the end of the initial buffer first.
+        deque.push_back(3);
+        deque.push_front(2);
+        deque.push_front(1);
+        deque.push_back(4);
+        assert_eq!(deque.capacity(), 4);
+
+        deque.push_front(0);
+        deque.push_back(5);
+        assert!(deque.capacity() > 4);
+        let items: Vec<_> = (0..deque.len()).map(|i| *deque.get(i).unwrap()).collect();
+        assert_eq!(items, vec![0, 1, 2, 3, 4, 5]);
+
+        assert_eq!(deque.pop_back(), Some(5));
+        assert_eq!(deque.pop_front(), Some(0));
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.pop_back(), Some(4));
+    }
+
+    #[test]
+    fn against_vec_deque() {
+        let mut rng = XorShift::new(43);
+        let mut deque = Deque::new();
+        let mut expected = VecDeque::new();
+        for i in 0..2000 {
+            match rng.gen_range(0, 5) {
+                0 => {
+                    deque.push_front(i);
+                    expected.push_front(i);
+                }
+                1 | 2 => {
+                    deque.push_back(i);
+                    expected.push_back(i);
+                }
+                3 => assert_eq!(deque.pop_front(), expected.pop_front()),
+                _ => assert_eq!(deque.pop_back(), expected.pop_back()),
+            }
+            assert_eq!(deque.len(), expected.len());
+            assert_eq!(deque.front(), expected.front());
+            assert_eq!(deque.back(), expected.back());
+        }
+    }
+}
@@ -0,0 +1,115 @@
+/// A disjoint-set forest (union-find) over the elements `0..n`, each
+/// initially in a set of its own.
+///
+/// `union` hangs the smaller tree under the larger one and `find` halves the
+/// path it walks, which together make both run in amortized O(α(n)), where
+/// α is the inverse Ackermann function and at most 4 for any practical `n`.
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    sets: usize,
+}
+
+impl DisjointSet {
+    pub fn new(n: usize) -> Self {
+        DisjointSet {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            sets: n,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of disjoint sets.
+    pub fn set_count(&self) -> usize {
+        self.sets
+    }
+
+    /// Returns the representative of the set containing `x`.
+    pub fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merges the sets containing `x` and `y`. Returns false if they were
+    /// already the same set.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (mut x, mut y) = (self.find(x), self.find(y));
+        if x == y {
+            return false;
+        }
+        if self.size[x] < self.size[y] {
+            std::mem::swap(&mut x, &mut y);
+        }
+        self.parent[y] = x;
+        self.size[x] += self.size[y];
+        self.sets -= 1;
+        true
+    }
+
+    /// Returns true if `x` and `y` are in the same set.
+    pub fn same_set(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// Size of the set containing `x`.
+    pub fn set_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn union_and_find() {
+        let mut sets = DisjointSet::new(6);
+        assert_eq!(sets.set_count(), 6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.same_set(0, 3));
+        assert!(!sets.same_set(0, 4));
+        assert_eq!(sets.set_size(2), 4);
+        assert_eq!(sets.set_size(5), 1);
+        assert_eq!(sets.set_count(), 3);
+        assert_eq!(sets.len(), 6);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let n = 50;
+        let mut rng = XorShift::new(11);
+        let mut sets = DisjointSet::new(n);
+        let mut label: Vec<usize> = (0..n).collect();
+
+        for _ in 0..200 {
+            let x = rng.gen_range(0, n as u64) as usize;
+            let y = rng.gen_range(0, n as u64) as usize;
+            let (lx, ly) = (label[x], label[y]);
+            assert_eq!(sets.union(x, y), lx != ly);
+            for l in label.iter_mut() {
+                if *l == ly {
+                    *l = lx;
+                }
+            }
+            for (u, &l) in label.iter().enumerate() {
+                assert_eq!(sets.same_set(x, u), l == lx);
+            }
+        }
+    }
+}
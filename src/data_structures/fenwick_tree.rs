@@ -0,0 +1,109 @@
+use std::ops::{Add, Sub};
+
+/// A Fenwick tree (binary indexed tree) over `n` values, all initially
+/// `T::default()`.
+///
+/// Both point updates and prefix sums run in O(log n). Internally the tree
+/// is 1-based, since that makes the lowest set bit of an index equal to the
+/// length of the range it covers; the public API is 0-based.
+pub struct FenwickTree<T> {
+    tree: Vec<T>,
+}
+
+impl<T> FenwickTree<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Default + Copy,
+{
+    pub fn new(n: usize) -> Self {
+        FenwickTree {
+            tree: vec![T::default(); n + 1],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Add `delta` to the value at index `i`.
+    pub fn add(&mut self, i: usize, delta: T) {
+        assert!(i < self.len(), "index out of bounds");
+        let mut i = i + 1;
+        while i < self.tree.len() {
+            self.tree[i] = self.tree[i] + delta;
+            i += lowest_bit(i);
+        }
+    }
+
+    /// Returns the sum of the values at indices `0..=i`.
+    pub fn prefix_sum(&self, i: usize) -> T {
+        assert!(i < self.len(), "index out of bounds");
+        let mut i = i + 1;
+        let mut sum = T::default();
+        while i > 0 {
+            sum = sum + self.tree[i];
+            i -= lowest_bit(i);
+        }
+        sum
+    }
+
+    /// Returns the sum of the values at indices `l..=r`.
+    pub fn range_sum(&self, l: usize, r: usize) -> T {
+        assert!(l <= r, "empty range");
+        if l == 0 {
+            self.prefix_sum(r)
+        } else {
+            self.prefix_sum(r) - self.prefix_sum(l - 1)
+        }
+    }
+}
+
+fn lowest_bit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn single_element() {
+        let mut tree = FenwickTree::new(1);
+        tree.add(0, 5);
+        assert_eq!(tree.prefix_sum(0), 5);
+        assert_eq!(tree.range_sum(0, 0), 5);
+    }
+
+    #[test]
+    fn floats() {
+        let mut tree = FenwickTree::new(3);
+        tree.add(0, 0.5);
+        tree.add(2, 1.5);
+        assert_eq!(tree.range_sum(1, 2), 1.5);
+        assert_eq!(tree.prefix_sum(2), 2.0);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let n = 100;
+        let mut rng = XorShift::new(7);
+        let mut tree = FenwickTree::new(n);
+        let mut values = vec![0i64; n];
+
+        for _ in 0..1000 {
+            let i = rng.gen_range(0, n as u64) as usize;
+            let delta = rng.gen_range(0, 200) as i64 - 100;
+            tree.add(i, delta);
+            values[i] += delta;
+
+            let l = rng.gen_range(0, n as u64) as usize;
+            let r = rng.gen_range(l as u64, n as u64) as usize;
+            assert_eq!(tree.prefix_sum(r), values[..=r].iter().sum());
+            assert_eq!(tree.range_sum(l, r), values[l..=r].iter().sum());
+        }
+    }
+}
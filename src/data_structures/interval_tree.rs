@@ -0,0 +1,153 @@
+/// A static interval tree answering "which intervals overlap this one?"
+///
+/// The intervals are sorted by their start and arranged as a balanced binary
+/// search tree: the root of a range of `order` is its middle element, as in
+/// a binary search. Every node is annotated with the largest end point in
+/// its subtree. A query can skip any subtree whose largest end lies before
+/// the query starts, and any right subtree once the starts lie after the
+/// query ends, so it takes O(log n + k) time for `k` overlapping intervals.
+///
+/// Intervals are closed, so `(1, 2)` and `(2, 3)` overlap.
+pub struct IntervalTree<T> {
+    intervals: Vec<(T, T)>,
+    // indices into `intervals`, sorted by start
+    order: Vec<usize>,
+    // max_end[i] is the largest end in the subtree rooted at order[i]
+    max_end: Vec<T>,
+}
+
+impl<T: Ord + Copy> IntervalTree<T> {
+    /// Build a tree in O(n log n). Panics if some interval ends before it
+    /// starts.
+    pub fn new(intervals: Vec<(T, T)>) -> Self {
+        assert!(
+            intervals.iter().all(|&(start, end)| start <= end),
+            "invalid interval"
+        );
+        let mut order: Vec<usize> = (0..intervals.len()).collect();
+        order.sort_by_key(|&i| intervals[i].0);
+
+        let mut tree = IntervalTree {
+            max_end: order.iter().map(|&i| intervals[i].1).collect(),
+            intervals,
+            order,
+        };
+        tree.annotate(0, tree.order.len());
+        tree
+    }
+
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns the indices of all stored intervals that share at least one
+    /// point with `query`, in ascending order.
+    pub fn overlapping(&self, query: (T, T)) -> Vec<usize> {
+        let mut result = Vec::new();
+        self.collect(0, self.order.len(), query, &mut result);
+        result.sort_unstable();
+        result
+    }
+
+    // Fill in max_end for the subtree over order[lo..hi] and return it.
+    fn annotate(&mut self, lo: usize, hi: usize) -> Option<T> {
+        if lo == hi {
+            return None;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let left = self.annotate(lo, mid);
+        let right = self.annotate(mid + 1, hi);
+        let max_end = left
+            .into_iter()
+            .chain(right)
+            .fold(self.max_end[mid], T::max);
+        self.max_end[mid] = max_end;
+        Some(max_end)
+    }
+
+    fn collect(&self, lo: usize, hi: usize, query: (T, T), result: &mut Vec<usize>) {
+        if lo == hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        // Everything in this subtree ends before the query starts.
+        if self.max_end[mid] < query.0 {
+            return;
+        }
+
+        self.collect(lo, mid, query, result);
+        let index = self.order[mid];
+        let (start, end) = self.intervals[index];
+        if start <= query.1 {
+            if query.0 <= end {
+                result.push(index);
+            }
+            // Only the right subtree has later starts that may still fit.
+            self.collect(mid + 1, hi, query, result);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn nested() {
+        let tree = IntervalTree::new(vec![(0, 100), (10, 50), (20, 30), (60, 70)]);
+        assert_eq!(tree.overlapping((25, 25)), vec![0, 1, 2]);
+        assert_eq!(tree.overlapping((55, 58)), vec![0]);
+        assert_eq!(tree.overlapping((40, 65)), vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn touching_endpoints() {
+        let tree = IntervalTree::new(vec![(1, 2), (3, 4), (5, 6)]);
+        assert_eq!(tree.overlapping((2, 3)), vec![0, 1]);
+        assert_eq!(tree.overlapping((4, 4)), vec![1]);
+        assert_eq!(tree.overlapping((6, 10)), vec![2]);
+        assert_eq!(tree.overlapping((0, 1)), vec![0]);
+    }
+
+    #[test]
+    fn no_overlap() {
+        let tree = IntervalTree::new(vec![(1, 2), (5, 6), (10, 12)]);
+        assert!(tree.overlapping((3, 4)).is_empty());
+        assert!(tree.overlapping((13, 20)).is_empty());
+        assert!(IntervalTree::<i32>::new(vec![])
+            .overlapping((0, 1))
+            .is_empty());
+    }
+
+    #[test]
+    fn against_scan() {
+        let mut rng = XorShift::new(41);
+        let intervals: Vec<(u64, u64)> = (0..300)
+            .map(|_| {
+                let start = rng.gen_range(0, 1000);
+                (start, start + rng.gen_range(0, 50))
+            })
+            .collect();
+        let tree = IntervalTree::new(intervals.clone());
+
+        for _ in 0..200 {
+            let start = rng.gen_range(0, 1100);
+            let query = (start, start + rng.gen_range(0, 30));
+            let expected: Vec<usize> = (0..intervals.len())
+                .filter(|&i| intervals[i].0 <= query.1 && query.0 <= intervals[i].1)
+                .collect();
+            assert_eq!(tree.overlapping(query), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn reversed_interval() {
+        IntervalTree::new(vec![(3, 1)]);
+    }
+}
@@ -0,0 +1,215 @@
+/// A 2-d tree for nearest-neighbor and radius queries on points in the
+/// plane.
+///
+/// The points are split at the median of the x coordinate, then each half at
+/// the median of y, and so on, alternating the dimension with every level.
+/// The tree is stored implicitly: the root of a range of `order` is its
+/// middle element. Building takes O(n log n) expected time. A nearest
+/// neighbor query visits O(log n) nodes on typical data, skipping every
+/// subtree that lies farther away than the best point found so far.
+pub struct KdTree {
+    points: Vec<[f64; 2]>,
+    // indices into `points`, arranged as an implicit tree
+    order: Vec<usize>,
+}
+
+fn squared_distance(a: [f64; 2], b: [f64; 2]) -> f64 {
+    let (dx, dy) = (a[0] - b[0], a[1] - b[1]);
+    dx * dx + dy * dy
+}
+
+impl KdTree {
+    pub fn new(points: Vec<[f64; 2]>) -> Self {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        build(&points, &mut order, 0);
+        KdTree { points, order }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Returns the index of the stored point closest to `point`. On ties the
+    /// smallest index wins. Panics if the tree is empty.
+    pub fn nearest(&self, point: [f64; 2]) -> usize {
+        assert!(!self.is_empty(), "tree is empty");
+        let mut best = (f64::INFINITY, usize::MAX);
+        self.nearest_in(&self.order, 0, point, &mut best);
+        best.1
+    }
+
+    /// Returns the indices of all stored points at distance at most `r`
+    /// from `point`, in ascending order. Panics if `r` is negative or NaN.
+    pub fn within_radius(&self, point: [f64; 2], r: f64) -> Vec<usize> {
+        assert!(r >= 0.0, "radius must be non-negative");
+        let mut result = Vec::new();
+        self.within_radius_in(&self.order, 0, point, r, &mut result);
+        result.sort_unstable();
+        result
+    }
+
+    fn nearest_in(&self, order: &[usize], axis: usize, point: [f64; 2], best: &mut (f64, usize)) {
+        if order.is_empty() {
+            return;
+        }
+        let mid = order.len() / 2;
+        let index = order[mid];
+        let d = squared_distance(self.points[index], point);
+        if d < best.0 || (d == best.0 && index < best.1) {
+            *best = (d, index);
+        }
+
+        // Search the side containing `point` first, then the other side if
+        // the splitting line is not farther away than the best point.
+        let diff = point[axis] - self.points[index][axis];
+        let (near, far) = if diff < 0.0 {
+            (&order[..mid], &order[mid + 1..])
+        } else {
+            (&order[mid + 1..], &order[..mid])
+        };
+        self.nearest_in(near, 1 - axis, point, best);
+        if diff * diff <= best.0 {
+            self.nearest_in(far, 1 - axis, point, best);
+        }
+    }
+
+    fn within_radius_in(
+        &self,
+        order: &[usize],
+        axis: usize,
+        point: [f64; 2],
+        r: f64,
+        result: &mut Vec<usize>,
+    ) {
+        if order.is_empty() {
+            return;
+        }
+        let mid = order.len() / 2;
+        let index = order[mid];
+        if squared_distance(self.points[index], point) <= r * r {
+            result.push(index);
+        }
+
+        // Coordinates are at most the split value on the left and at least
+        // it on the right.
+        let diff = point[axis] - self.points[index][axis];
+        if diff <= r {
+            self.within_radius_in(&order[..mid], 1 - axis, point, r, result);
+        }
+        if diff >= -r {
+            self.within_radius_in(&order[mid + 1..], 1 - axis, point, r, result);
+        }
+    }
+}
+
+// Put the median along `axis` in the middle of `order`, with smaller
+// coordinates before it and larger ones after it, and recurse on both halves.
+fn build(points: &[[f64; 2]], order: &mut [usize], axis: usize) {
+    if order.len() < 2 {
+        return;
+    }
+    let mid = order.len() / 2;
+    order.select_nth_unstable_by(mid, |&a, &b| points[a][axis].total_cmp(&points[b][axis]));
+    let (left, right) = order.split_at_mut(mid);
+    build(points, left, 1 - axis);
+    build(points, &mut right[1..], 1 - axis);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn brute_nearest(points: &[[f64; 2]], point: [f64; 2]) -> usize {
+        let mut best = 0;
+        for (i, &p) in points.iter().enumerate() {
+            if squared_distance(p, point) < squared_distance(points[best], point) {
+                best = i;
+            }
+        }
+        best
+    }
+
+    // Points on a small integer grid, so that distances often tie.
+    fn random_points(rng: &mut XorShift, n: usize) -> Vec<[f64; 2]> {
+        (0..n)
+            .map(|_| [rng.gen_range(0, 30) as f64, rng.gen_range(0, 30) as f64])
+            .collect()
+    }
+
+    #[test]
+    fn nearest_against_scan() {
+        let mut rng = XorShift::new(17);
+        for n in [1, 2, 10, 200] {
+            let points = random_points(&mut rng, n);
+            let tree = KdTree::new(points.clone());
+            for _ in 0..200 {
+                let query = [
+                    rng.gen_range(0, 600) as f64 / 20.0,
+                    rng.gen_range(0, 600) as f64 / 20.0,
+                ];
+                assert_eq!(tree.nearest(query), brute_nearest(&points, query));
+            }
+        }
+    }
+
+    #[test]
+    fn ties() {
+        // (1, 1) is equally far from all four corners.
+        let points = vec![[2.0, 2.0], [0.0, 0.0], [2.0, 0.0], [0.0, 2.0]];
+        let tree = KdTree::new(points);
+        assert_eq!(tree.nearest([1.0, 1.0]), 0);
+    }
+
+    #[test]
+    fn query_on_stored_point() {
+        let mut rng = XorShift::new(23);
+        let points = random_points(&mut rng, 100);
+        let tree = KdTree::new(points.clone());
+        for (i, &p) in points.iter().enumerate() {
+            let nearest = tree.nearest(p);
+            assert_eq!(points[nearest], p);
+            assert!(nearest <= i);
+        }
+    }
+
+    #[test]
+    fn within_radius() {
+        let mut rng = XorShift::new(29);
+        let points = random_points(&mut rng, 300);
+        let tree = KdTree::new(points.clone());
+        for _ in 0..50 {
+            let query = [rng.gen_range(0, 30) as f64, rng.gen_range(0, 30) as f64];
+            let r = rng.gen_range(0, 8) as f64;
+            let expected: Vec<usize> = (0..points.len())
+                .filter(|&i| squared_distance(points[i], query) <= r * r)
+                .collect();
+            assert_eq!(tree.within_radius(query, r), expected);
+        }
+        assert!(KdTree::new(vec![])
+            .within_radius([0.0, 0.0], 1.0)
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "radius must be non-negative")]
+    fn negative_radius() {
+        KdTree::new(vec![[0.0, 0.0]]).within_radius([0.0, 0.0], -1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "radius must be non-negative")]
+    fn nan_radius() {
+        KdTree::new(vec![[0.0, 0.0]]).within_radius([0.0, 0.0], f64::NAN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nearest_on_empty_tree() {
+        KdTree::new(vec![]).nearest([0.0, 0.0]);
+    }
+}
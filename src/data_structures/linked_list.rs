@@ -0,0 +1,186 @@
+/// A singly linked list built only from `Option<Box<Node<T>>>`, without any
+/// `unsafe` code.
+pub struct LinkedList<T> {
+    head: Link<T>,
+    len: usize,
+}
+
+type Link<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    elem: T,
+    next: Link<T>,
+}
+
+impl<T> LinkedList<T> {
+    pub fn new() -> Self {
+        LinkedList { head: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn push_front(&mut self, elem: T) {
+        let next = self.head.take();
+        self.head = Some(Box::new(Node { elem, next }));
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|node| {
+            self.head = node.next;
+            self.len -= 1;
+            node.elem
+        })
+    }
+
+    /// Returns a reference to the first element.
+    pub fn peek(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.elem)
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node| &mut node.elem)
+    }
+
+    /// Iterate over the elements from front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The derived drop would recurse once per node and overflow the stack on
+// long lists, so unlink the nodes one at a time instead.
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        let mut link = self.head.take();
+        while let Some(mut node) = link {
+            link = node.next.take();
+        }
+    }
+}
+
+pub struct Iter<'a, T: 'a> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.elem
+        })
+    }
+}
+
+pub struct IntoIter<T>(LinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn push_pop_order() {
+        let mut list = LinkedList::new();
+        assert_eq!(list.pop_front(), None);
+
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.peek(), Some(&3));
+
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), Some(2));
+        list.push_front(4);
+        assert_eq!(list.pop_front(), Some(4));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn peek_mut() {
+        let mut list = LinkedList::new();
+        list.push_front(1);
+        if let Some(value) = list.peek_mut() {
+            *value = 10;
+        }
+        assert_eq!(list.peek(), Some(&10));
+    }
+
+    #[test]
+    fn iterators() {
+        let mut list = LinkedList::new();
+        for i in 0..4 {
+            list.push_front(i);
+        }
+        assert_eq!(list.iter().cloned().collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+        assert_eq!((&list).into_iter().count(), 4);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+    }
+
+    struct DropCounter(Rc<Cell<usize>>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn long_list_drops_without_overflow() {
+        let drops = Rc::new(Cell::new(0));
+        let n = 1_000_000;
+        {
+            let mut list = LinkedList::new();
+            for _ in 0..n {
+                list.push_front(DropCounter(drops.clone()));
+            }
+        }
+        assert_eq!(drops.get(), n);
+    }
+}
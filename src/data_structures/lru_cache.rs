@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A fixed-capacity cache that evicts the least recently used entry.
+///
+/// Entries live in a slab (`Vec`) and are threaded into a doubly linked
+/// usage list through their indices, most recently used first. A `HashMap`
+/// maps each key to its slot, so `get` and `put` both run in O(1).
+pub struct LruCache<K: Hash + Eq, V> {
+    capacity: usize,
+    map: HashMap<K, usize>,
+    entries: Vec<Entry<K, V>>,
+    // most recently used entry
+    head: Option<usize>,
+    // least recently used entry
+    tail: Option<usize>,
+}
+
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be positive");
+        LruCache {
+            capacity,
+            map: HashMap::with_capacity(capacity),
+            entries: Vec::with_capacity(capacity),
+            head: None,
+            tail: None,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns the value cached for `key` and marks it most recently used.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let index = *self.map.get(key)?;
+        self.move_to_front(index);
+        Some(&self.entries[index].value)
+    }
+
+    /// Insert or update `key`, making it the most recently used entry.
+    ///
+    /// If the cache was full and `key` is new, the least recently used entry
+    /// is evicted and returned.
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if let Some(&index) = self.map.get(&key) {
+            self.entries[index].value = value;
+            self.move_to_front(index);
+            return None;
+        }
+
+        if self.entries.len() < self.capacity {
+            let index = self.entries.len();
+            self.entries.push(Entry {
+                key: key.clone(),
+                value,
+                prev: None,
+                next: None,
+            });
+            self.map.insert(key, index);
+            self.push_front(index);
+            return None;
+        }
+
+        // Reuse the slot of the least recently used entry.
+        let index = self.tail.unwrap();
+        self.unlink(index);
+        let old_key = std::mem::replace(&mut self.entries[index].key, key.clone());
+        let old_value = std::mem::replace(&mut self.entries[index].value, value);
+        self.map.remove(&old_key);
+        self.map.insert(key, index);
+        self.push_front(index);
+        Some((old_key, old_value))
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let (prev, next) = (self.entries[index].prev, self.entries[index].next);
+        match prev {
+            Some(prev) => self.entries[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.entries[next].prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    fn push_front(&mut self, index: usize) {
+        self.entries[index].prev = None;
+        self.entries[index].next = self.head;
+        match self.head {
+            Some(head) => self.entries[head].prev = Some(index),
+            None => self.tail = Some(index),
+        }
+        self.head = Some(index);
+    }
+
+    fn move_to_front(&mut self, index: usize) {
+        if self.head != Some(index) {
+            self.unlink(index);
+            self.push_front(index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eviction_order() {
+        let mut cache = LruCache::new(3);
+        assert_eq!(cache.put(1, "one"), None);
+        assert_eq!(cache.put(2, "two"), None);
+        assert_eq!(cache.put(3, "three"), None);
+
+        // 1 becomes the most recently used, so 2 is evicted next.
+        assert_eq!(cache.get(&1), Some(&"one"));
+        assert_eq!(cache.put(4, "four"), Some((2, "two")));
+        assert_eq!(cache.put(5, "five"), Some((3, "three")));
+        assert_eq!(cache.put(6, "six"), Some((1, "one")));
+
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[test]
+    fn update_existing_key() {
+        let mut cache = LruCache::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+
+        // Updating refreshes both the value and the recency of "a".
+        assert_eq!(cache.put("a", 10), None);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.put("c", 3), Some(("b", 2)));
+        assert_eq!(cache.get(&"a"), Some(&10));
+    }
+
+    #[test]
+    fn capacity_one() {
+        let mut cache = LruCache::new(1);
+        assert_eq!(cache.put(1, 1), None);
+        assert_eq!(cache.get(&1), Some(&1));
+        assert_eq!(cache.put(2, 2), Some((1, 1)));
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.get(&2), Some(&2));
+        assert_eq!(cache.put(2, 3), None);
+        assert_eq!(cache.get(&2), Some(&3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_capacity() {
+        let _cache: LruCache<u32, u32> = LruCache::new(0);
+    }
+}
@@ -1,4 +1,47 @@
+mod avl_tree;
+mod binary_heap;
+mod btree;
+mod cartesian_tree;
+mod deque;
+mod disjoint_set;
+mod fenwick_tree;
 pub mod graph;
 mod heap;
+mod interval_tree;
+mod kd_tree;
+mod linked_list;
+mod lru_cache;
+mod ordered_f64;
+mod pairing_heap;
+mod priority_queue;
+mod ring_buffer;
+mod segment_tree;
+mod skip_list;
+mod sparse_table;
+mod stack;
+mod treap;
+mod trie;
 
+pub use self::avl_tree::AvlTree;
+pub use self::binary_heap::BinaryHeap;
+pub use self::btree::BTree;
+pub use self::cartesian_tree::cartesian_tree;
+pub use self::deque::Deque;
+pub use self::disjoint_set::DisjointSet;
+pub use self::fenwick_tree::FenwickTree;
 pub use self::heap::MinHeap;
+pub use self::interval_tree::IntervalTree;
+pub use self::kd_tree::KdTree;
+pub use self::linked_list::LinkedList;
+pub use self::lru_cache::LruCache;
+pub use self::ordered_f64::OrderedF64;
+pub use self::pairing_heap::Handle;
+pub use self::pairing_heap::PairingHeap;
+pub use self::priority_queue::PriorityQueue;
+pub use self::ring_buffer::RingBuffer;
+pub use self::segment_tree::SegmentTree;
+pub use self::skip_list::SkipList;
+pub use self::sparse_table::SparseTable;
+pub use self::stack::Stack;
+pub use self::treap::Treap;
+pub use self::trie::Trie;
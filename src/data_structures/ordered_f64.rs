@@ -0,0 +1,73 @@
+use std::cmp::Ordering;
+
+/// An `f64` that is never NaN, and therefore totally ordered.
+///
+/// `f64` only implements `PartialOrd`, since NaN compares neither smaller,
+/// equal nor greater than anything, which rules it out wherever `Ord` is
+/// needed, e.g. as a heap priority. Rejecting NaN when the value is made
+/// leaves a type that can be ordered by `f64::total_cmp`. Note that this
+/// tells `-0.0` and `0.0` apart, the former being smaller.
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedF64(f64);
+
+impl OrderedF64 {
+    /// Returns `None` if `value` is NaN.
+    pub fn new(value: f64) -> Option<Self> {
+        if value.is_nan() {
+            None
+        } else {
+            Some(OrderedF64(value))
+        }
+    }
+
+    pub fn get(self) -> f64 {
+        self.0
+    }
+}
+
+impl PartialEq for OrderedF64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedF64 {}
+
+impl PartialOrd for OrderedF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedF64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_nan() {
+        assert_eq!(OrderedF64::new(f64::NAN), None);
+        assert_eq!(OrderedF64::new(1.5).map(OrderedF64::get), Some(1.5));
+    }
+
+    #[test]
+    fn total_order() {
+        let mut values: Vec<OrderedF64> = [2.5, -1.0, f64::INFINITY, 0.0, -0.0, f64::NEG_INFINITY]
+            .iter()
+            .map(|&x| OrderedF64::new(x).unwrap())
+            .collect();
+        values.sort();
+        let sorted: Vec<f64> = values.iter().map(|x| x.get()).collect();
+        assert_eq!(
+            sorted,
+            [f64::NEG_INFINITY, -1.0, -0.0, 0.0, 2.5, f64::INFINITY]
+        );
+        assert!(sorted[2].is_sign_negative());
+        assert_ne!(values[2], values[3]);
+    }
+}
@@ -0,0 +1,273 @@
+/// A min-ordered pairing heap with handles for `decrease_key`.
+///
+/// The heap is a tree in which every node is at most its children, stored
+/// as first-child/next-sibling links in an arena so that no `unsafe` code is
+/// needed. Pushing and merging just link two roots under the smaller one,
+/// in O(1). Popping removes the root and pairs up its children left to
+/// right, then melds the pairs right to left, in O(log n) amortized time.
+/// Decreasing a key cuts the node's subtree off and links it with the root,
+/// which is O(1) in practice, making the heap a good fit for Dijkstra.
+pub struct PairingHeap<T> {
+    nodes: Vec<Node<T>>,
+    root: Option<usize>,
+    len: usize,
+}
+
+/// Refers to an item pushed into a `PairingHeap`. It stays valid until the
+/// item is popped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Handle(usize);
+
+struct Node<T> {
+    // None once the item has been popped
+    value: Option<T>,
+    child: Option<usize>,
+    next: Option<usize>,
+    // the parent for a first child, the previous sibling otherwise
+    prev: Option<usize>,
+}
+
+impl<T: Ord> PairingHeap<T> {
+    pub fn new() -> Self {
+        PairingHeap {
+            nodes: Vec::new(),
+            root: None,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, value: T) -> Handle {
+        let node = self.nodes.len();
+        self.nodes.push(Node {
+            value: Some(value),
+            child: None,
+            next: None,
+            prev: None,
+        });
+        self.root = Some(self.meld(self.root, node));
+        self.len += 1;
+        Handle(node)
+    }
+
+    /// Returns the smallest item without removing it.
+    pub fn peek_min(&self) -> Option<&T> {
+        self.root.map(|root| self.value(root))
+    }
+
+    /// Remove and return the smallest item.
+    pub fn pop_min(&mut self) -> Option<T> {
+        let root = self.root?;
+        let value = self.nodes[root].value.take();
+        let children = self.nodes[root].child.take();
+        self.root = self.merge_pairs(children);
+        self.len -= 1;
+        value
+    }
+
+    /// Returns the item behind `handle`, or `None` if it was popped.
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        self.nodes
+            .get(handle.0)
+            .and_then(|node| node.value.as_ref())
+    }
+
+    /// Replace the item behind `handle` with the smaller or equal `value`.
+    ///
+    /// Panics if `value` is larger than the current item or if the item was
+    /// already popped.
+    pub fn decrease_key(&mut self, handle: Handle, value: T) {
+        let node = handle.0;
+        let current = self.nodes[node].value.as_mut().expect("item was popped");
+        assert!(value <= *current, "new value is larger");
+        *current = value;
+
+        if self.root == Some(node) {
+            return;
+        }
+        // Cut the subtree off its parent and link it with the root.
+        let (prev, next) = (self.nodes[node].prev.take(), self.nodes[node].next.take());
+        let prev = prev.unwrap();
+        if self.nodes[prev].child == Some(node) {
+            self.nodes[prev].child = next;
+        } else {
+            self.nodes[prev].next = next;
+        }
+        if let Some(next) = next {
+            self.nodes[next].prev = Some(prev);
+        }
+        self.root = Some(self.meld(self.root, node));
+    }
+
+    /// Move all items of `other` into this heap in O(other.len()). Handles
+    /// returned by `other` are not valid for this heap.
+    pub fn merge(&mut self, other: PairingHeap<T>) {
+        let offset = self.nodes.len();
+        let shift = |link: Option<usize>| link.map(|i| i + offset);
+        self.nodes.extend(other.nodes.into_iter().map(|node| Node {
+            value: node.value,
+            child: shift(node.child),
+            next: shift(node.next),
+            prev: shift(node.prev),
+        }));
+        if let Some(other_root) = shift(other.root) {
+            self.root = Some(self.meld(self.root, other_root));
+        }
+        self.len += other.len;
+    }
+
+    fn value(&self, node: usize) -> &T {
+        self.nodes[node].value.as_ref().unwrap()
+    }
+
+    // Link the tree `b` with the tree `a`, if any, and return the new root.
+    // Both must be roots, i.e. have no siblings.
+    fn meld(&mut self, a: Option<usize>, b: usize) -> usize {
+        let a = match a {
+            Some(a) => a,
+            None => return b,
+        };
+        let (parent, child) = if self.value(b) < self.value(a) {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        let first = self.nodes[parent].child;
+        self.nodes[child].next = first;
+        self.nodes[child].prev = Some(parent);
+        if let Some(first) = first {
+            self.nodes[first].prev = Some(child);
+        }
+        self.nodes[parent].child = Some(child);
+        parent
+    }
+
+    // Meld a list of siblings into one tree with the two-pass scheme.
+    fn merge_pairs(&mut self, first: Option<usize>) -> Option<usize> {
+        let mut trees = Vec::new();
+        let mut current = first;
+        while let Some(node) = current {
+            current = self.nodes[node].next.take();
+            self.nodes[node].prev = None;
+            trees.push(node);
+        }
+
+        let mut pairs = Vec::with_capacity(trees.len().div_ceil(2));
+        for pair in trees.chunks(2) {
+            pairs.push(match *pair {
+                [a, b] => self.meld(Some(a), b),
+                [a] => a,
+                _ => unreachable!(),
+            });
+        }
+        pairs
+            .into_iter()
+            .rev()
+            .fold(None, |root, tree| Some(self.meld(root, tree)))
+    }
+}
+
+impl<T: Ord> Default for PairingHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    #[test]
+    fn empty() {
+        let mut heap: PairingHeap<i32> = PairingHeap::new();
+        assert!(heap.is_empty());
+        assert_eq!(heap.peek_min(), None);
+        assert_eq!(heap.pop_min(), None);
+    }
+
+    #[test]
+    fn random_pushes_and_pops() {
+        let mut rng = XorShift::new(47);
+        let mut heap = PairingHeap::new();
+        let mut expected = BinaryHeap::new();
+        for _ in 0..3000 {
+            if rng.gen_range(0, 3) < 2 {
+                let value = rng.gen_range(0, 500);
+                heap.push(value);
+                expected.push(Reverse(value));
+            } else {
+                assert_eq!(heap.pop_min(), expected.pop().map(|Reverse(v)| v));
+            }
+            assert_eq!(heap.len(), expected.len());
+            assert_eq!(heap.peek_min(), expected.peek().map(|Reverse(v)| v));
+        }
+    }
+
+    #[test]
+    fn decrease_key_to_front() {
+        let mut heap = PairingHeap::new();
+        let handles: Vec<Handle> = [50, 20, 40, 30, 10].iter().map(|&v| heap.push(v)).collect();
+        assert_eq!(heap.pop_min(), Some(10));
+
+        // 40 sits somewhere below the root after the pop.
+        heap.decrease_key(handles[2], 5);
+        assert_eq!(heap.get(handles[2]), Some(&5));
+        assert_eq!(heap.peek_min(), Some(&5));
+
+        heap.decrease_key(handles[0], 25);
+        let popped: Vec<_> = std::iter::from_fn(|| heap.pop_min()).collect();
+        assert_eq!(popped, vec![5, 20, 25, 30]);
+        assert_eq!(heap.get(handles[0]), None);
+    }
+
+    #[test]
+    fn random_decrease_keys() {
+        let mut rng = XorShift::new(53);
+        let mut heap = PairingHeap::new();
+        let mut values: Vec<u64> = (0..300).map(|_| rng.gen_range(1000, 2000)).collect();
+        let handles: Vec<Handle> = values.iter().map(|&v| heap.push(v)).collect();
+        for _ in 0..500 {
+            let i = rng.gen_range(0, values.len() as u64) as usize;
+            values[i] -= rng.gen_range(0, values[i] / 2 + 1);
+            heap.decrease_key(handles[i], values[i]);
+        }
+        values.sort_unstable();
+        let popped: Vec<_> = std::iter::from_fn(|| heap.pop_min()).collect();
+        assert_eq!(popped, values);
+    }
+
+    #[test]
+    fn merge() {
+        let mut a = PairingHeap::new();
+        let mut b = PairingHeap::new();
+        for v in [5, 1, 9] {
+            a.push(v);
+        }
+        for v in [4, 0, 7] {
+            b.push(v);
+        }
+        b.pop_min();
+        a.merge(b);
+        assert_eq!(a.len(), 5);
+        let popped: Vec<_> = std::iter::from_fn(|| a.pop_min()).collect();
+        assert_eq!(popped, vec![1, 4, 5, 7, 9]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn increase_key() {
+        let mut heap = PairingHeap::new();
+        let handle = heap.push(1);
+        heap.decrease_key(handle, 2);
+    }
+}
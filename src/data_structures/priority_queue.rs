@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A binary min-heap of keys ordered by an associated priority.
+///
+/// Next to the heap array, a map from each key to its position in the heap
+/// is maintained. This makes it possible to find a key in O(1) and therefore
+/// to lower its priority in O(log n), which `std::collections::BinaryHeap`
+/// does not support.
+pub struct PriorityQueue<K, P: Ord> {
+    heap: Vec<(K, P)>,
+    index: HashMap<K, usize>,
+}
+
+impl<K, P> PriorityQueue<K, P>
+where
+    K: Hash + Eq + Clone,
+    P: Ord,
+{
+    pub fn new() -> Self {
+        PriorityQueue {
+            heap: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.index.contains_key(key)
+    }
+
+    /// Returns the current priority of `key`, if it is in the queue.
+    pub fn priority(&self, key: &K) -> Option<&P> {
+        self.index.get(key).map(|&i| &self.heap[i].1)
+    }
+
+    /// Returns the entry with the smallest priority without removing it.
+    pub fn peek_min(&self) -> Option<(&K, &P)> {
+        self.heap.first().map(|(key, priority)| (key, priority))
+    }
+
+    /// Insert `key` with `priority`. If `key` is already queued, its priority
+    /// is replaced and the old one is returned.
+    pub fn push(&mut self, key: K, priority: P) -> Option<P> {
+        if let Some(&i) = self.index.get(&key) {
+            let old = std::mem::replace(&mut self.heap[i].1, priority);
+            if self.heap[i].1 < old {
+                self.sift_up(i);
+            } else {
+                self.sift_down(i);
+            }
+            return Some(old);
+        }
+
+        self.index.insert(key.clone(), self.heap.len());
+        self.heap.push((key, priority));
+        let last = self.heap.len() - 1;
+        self.sift_up(last);
+        None
+    }
+
+    /// Remove and return the entry with the smallest priority.
+    pub fn pop_min(&mut self) -> Option<(K, P)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+
+        let min = self.heap.swap_remove(0);
+        self.index.remove(&min.0);
+        if !self.heap.is_empty() {
+            self.index.insert(self.heap[0].0.clone(), 0);
+            self.sift_down(0);
+        }
+        Some(min)
+    }
+
+    /// Lower the priority of `key` to `new_priority`.
+    ///
+    /// Returns false and leaves the queue unchanged if `key` is not queued
+    /// or if `new_priority` is not smaller than its current priority.
+    pub fn decrease_priority(&mut self, key: &K, new_priority: P) -> bool {
+        match self.index.get(key) {
+            Some(&i) if new_priority < self.heap[i].1 => {
+                self.heap[i].1 = new_priority;
+                self.sift_up(i);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // Swap two heap slots and keep `index` in sync.
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.index.insert(self.heap[a].0.clone(), a);
+        self.index.insert(self.heap[b].0.clone(), b);
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if self.heap[i].1 >= self.heap[parent].1 {
+                break;
+            }
+            self.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        let len = self.heap.len();
+        loop {
+            let left = 2 * i + 1;
+            let right = left + 1;
+            let mut smallest = i;
+            if left < len && self.heap[left].1 < self.heap[smallest].1 {
+                smallest = left;
+            }
+            if right < len && self.heap[right].1 < self.heap[smallest].1 {
+                smallest = right;
+            }
+            if smallest == i {
+                break;
+            }
+            self.swap(i, smallest);
+            i = smallest;
+        }
+    }
+}
+
+impl<K, P> Default for PriorityQueue<K, P>
+where
+    K: Hash + Eq + Clone,
+    P: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn empty() {
+        let mut queue: PriorityQueue<u32, u32> = PriorityQueue::new();
+        assert!(queue.is_empty());
+        assert_eq!(queue.pop_min(), None);
+        assert!(!queue.decrease_priority(&1, 0));
+    }
+
+    #[test]
+    fn decrease_deep_element() {
+        let mut queue = PriorityQueue::new();
+        for i in 0..100 {
+            queue.push(i, i + 10);
+        }
+
+        // The last pushed key sits at the bottom of the heap.
+        assert!(queue.decrease_priority(&99, 0));
+        assert_eq!(queue.pop_min(), Some((99, 0)));
+        assert_eq!(queue.pop_min(), Some((0, 10)));
+
+        // Raising a priority is not a decrease.
+        assert!(!queue.decrease_priority(&50, 100));
+        assert_eq!(queue.priority(&50), Some(&60));
+    }
+
+    #[test]
+    fn push_existing_key_replaces_priority() {
+        let mut queue = PriorityQueue::new();
+        queue.push("a", 1);
+        queue.push("b", 2);
+        assert_eq!(queue.push("a", 3), Some(1));
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.pop_min(), Some(("b", 2)));
+        assert_eq!(queue.pop_min(), Some(("a", 3)));
+    }
+
+    #[test]
+    fn random_insertions_pop_in_order() {
+        let mut rng = XorShift::new(42);
+        let mut queue = PriorityQueue::new();
+        let mut expected = Vec::new();
+        for key in 0..500 {
+            let priority = rng.gen_range(0, 1000);
+            queue.push(key, priority);
+            expected.push(priority);
+        }
+        for (key, priority) in expected.iter_mut().enumerate() {
+            if rng.gen_range(0, 4) == 0 {
+                let lowered = *priority / 2;
+                if queue.decrease_priority(&key, lowered) {
+                    *priority = lowered;
+                }
+            }
+        }
+        expected.sort();
+
+        let mut popped = Vec::new();
+        while let Some((key, priority)) = queue.pop_min() {
+            assert!(!queue.contains(&key));
+            popped.push(priority);
+        }
+        assert_eq!(popped, expected);
+    }
+}
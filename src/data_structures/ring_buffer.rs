@@ -0,0 +1,133 @@
+/// A first-in, first-out queue with a fixed capacity.
+///
+/// The items live in a circular array: `head` is the slot of the oldest item
+/// and `tail` the slot the next item goes into, both wrapping around at the
+/// end of the backing store. `len` tells a full buffer apart from an empty
+/// one, since `head == tail` in both cases.
+#[derive(Debug)]
+pub struct RingBuffer<T> {
+    buffer: Vec<Option<T>>,
+    head: usize,
+    tail: usize,
+    len: usize,
+}
+
+impl<T> RingBuffer<T> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be positive");
+        RingBuffer {
+            buffer: (0..capacity).map(|_| None).collect(),
+            head: 0,
+            tail: 0,
+            len: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Append `item` at the back. If the buffer is full, `item` is handed back
+    /// as the error.
+    pub fn enqueue(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+        self.buffer[self.tail] = Some(item);
+        self.tail = (self.tail + 1) % self.capacity();
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Remove and return the item at the front, or `None` if it is empty.
+    pub fn dequeue(&mut self) -> Option<T> {
+        let item = self.buffer[self.head].take()?;
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+        Some(item)
+    }
+
+    /// Returns the item at the front without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        self.buffer[self.head].as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fifo_order() {
+        let mut queue = RingBuffer::with_capacity(4);
+        for i in 1..=3 {
+            assert_eq!(queue.enqueue(i), Ok(()));
+        }
+        assert_eq!(queue.peek(), Some(&1));
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn wrap_around() {
+        let mut queue = RingBuffer::with_capacity(3);
+        queue.enqueue(1).unwrap();
+        queue.enqueue(2).unwrap();
+        assert_eq!(queue.dequeue(), Some(1));
+        assert_eq!(queue.dequeue(), Some(2));
+
+        // head and tail are now at the last slot, so these wrap to the front.
+        for i in 3..=5 {
+            queue.enqueue(i).unwrap();
+        }
+        assert!(queue.is_full());
+        assert_eq!(queue.dequeue(), Some(3));
+        queue.enqueue(6).unwrap();
+        assert_eq!(queue.dequeue(), Some(4));
+        assert_eq!(queue.dequeue(), Some(5));
+        assert_eq!(queue.dequeue(), Some(6));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn full_and_empty() {
+        let mut queue = RingBuffer::with_capacity(2);
+        assert!(queue.is_empty());
+        assert!(!queue.is_full());
+        assert_eq!(queue.peek(), None);
+
+        queue.enqueue('a').unwrap();
+        queue.enqueue('b').unwrap();
+        assert!(queue.is_full());
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.enqueue('c'), Err('c'));
+
+        assert_eq!(queue.dequeue(), Some('a'));
+        assert!(!queue.is_full());
+        assert_eq!(queue.enqueue('c'), Ok(()));
+        assert_eq!(queue.dequeue(), Some('b'));
+        assert_eq!(queue.dequeue(), Some('c'));
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.len(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_capacity() {
+        let _queue: RingBuffer<u32> = RingBuffer::with_capacity(0);
+    }
+}
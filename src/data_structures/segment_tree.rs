@@ -0,0 +1,132 @@
+/// A segment tree answering range queries for any associative operation.
+///
+/// `combine` must be associative and `identity` must be its neutral element,
+/// i.e. together they form a monoid: `(0, +)` for sums, `(T::MAX, min)` for
+/// minimums, `(T::MIN, max)` for maximums, and so on. The operation does not
+/// need to be commutative.
+///
+/// The tree is stored bottom-up in an array of `2 * n` nodes: the leaves live
+/// at `n..2 * n` and node `i` combines its children `2 * i` and `2 * i + 1`.
+pub struct SegmentTree<T> {
+    len: usize,
+    tree: Vec<T>,
+    combine: fn(&T, &T) -> T,
+    identity: T,
+}
+
+impl<T: Clone> SegmentTree<T> {
+    /// Build a tree over `arr` in O(n).
+    pub fn build(arr: &[T], combine: fn(&T, &T) -> T, identity: T) -> Self {
+        let len = arr.len();
+        let mut tree = vec![identity.clone(); 2 * len];
+        tree[len..].clone_from_slice(arr);
+        for i in (1..len).rev() {
+            tree[i] = combine(&tree[2 * i], &tree[2 * i + 1]);
+        }
+
+        SegmentTree {
+            len,
+            tree,
+            combine,
+            identity,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Replace the value at index `i` with `value` in O(log n).
+    pub fn update(&mut self, i: usize, value: T) {
+        assert!(i < self.len, "index out of bounds");
+        let mut i = i + self.len;
+        self.tree[i] = value;
+        while i > 1 {
+            i /= 2;
+            self.tree[i] = (self.combine)(&self.tree[2 * i], &self.tree[2 * i + 1]);
+        }
+    }
+
+    /// Combine the values at indices `l..=r` in O(log n).
+    pub fn query(&self, l: usize, r: usize) -> T {
+        assert!(l <= r && r < self.len, "invalid range");
+
+        // Results from the left and right borders are kept apart so that the
+        // operands are combined in index order.
+        let mut left = self.identity.clone();
+        let mut right = self.identity.clone();
+        let mut l = l + self.len;
+        let mut r = r + self.len + 1;
+        while l < r {
+            if l % 2 == 1 {
+                left = (self.combine)(&left, &self.tree[l]);
+                l += 1;
+            }
+            if r % 2 == 1 {
+                r -= 1;
+                right = (self.combine)(&self.tree[r], &right);
+            }
+            l /= 2;
+            r /= 2;
+        }
+        (self.combine)(&left, &right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+    use std::cmp::{max, min};
+
+    fn check_against_scan(combine: fn(&i64, &i64) -> i64, identity: i64) {
+        let mut rng = XorShift::new(11);
+        let mut values: Vec<i64> = (0..64).map(|_| rng.gen_range(0, 1000) as i64).collect();
+        let mut tree = SegmentTree::build(&values, combine, identity);
+
+        for _ in 0..500 {
+            let i = rng.gen_range(0, values.len() as u64) as usize;
+            let value = rng.gen_range(0, 1000) as i64;
+            values[i] = value;
+            tree.update(i, value);
+
+            let l = rng.gen_range(0, values.len() as u64) as usize;
+            let r = rng.gen_range(l as u64, values.len() as u64) as usize;
+            let expected = values[l..=r]
+                .iter()
+                .fold(identity, |acc, value| combine(&acc, value));
+            assert_eq!(tree.query(l, r), expected);
+        }
+    }
+
+    #[test]
+    fn sum() {
+        check_against_scan(|a, b| a + b, 0);
+    }
+
+    #[test]
+    fn minimum() {
+        check_against_scan(|a, b| *min(a, b), i64::MAX);
+    }
+
+    #[test]
+    fn maximum() {
+        check_against_scan(|a, b| *max(a, b), i64::MIN);
+    }
+
+    #[test]
+    fn non_commutative() {
+        let words: Vec<String> = vec!["a", "b", "c", "d", "e"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let mut tree = SegmentTree::build(&words, |a, b| format!("{}{}", a, b), String::new());
+        assert_eq!(tree.query(1, 3), "bcd");
+        tree.update(2, String::from("x"));
+        assert_eq!(tree.query(0, 4), "abxde");
+    }
+}
@@ -0,0 +1,246 @@
+use crate::random::XorShift;
+
+const MAX_LEVEL: usize = 32;
+
+/// An ordered set stored as a skip list.
+///
+/// All values sit in a sorted linked list, and each node also joins `h - 1`
+/// sparser express lists above it, where `h` is drawn at random with
+/// probability `2^-h`. A search runs along the sparsest list until it
+/// would overshoot and then drops a level, which takes O(log n) steps in
+/// expectation. Nodes are kept in an arena and linked by index, so no
+/// `unsafe` code is needed.
+///
+/// Levels come from a seeded generator, so a given sequence of operations
+/// always builds the same list.
+pub struct SkipList<T: Ord> {
+    // nodes[0] is the head, which has no value and links on every level
+    nodes: Vec<Node<T>>,
+    // slots of removed nodes, reused by later insertions
+    free: Vec<usize>,
+    // number of levels in use
+    level: usize,
+    len: usize,
+    rng: XorShift,
+}
+
+struct Node<T> {
+    value: Option<T>,
+    // next[l] is the following node on level l
+    next: Vec<Option<usize>>,
+}
+
+impl<T: Ord> SkipList<T> {
+    pub fn new() -> Self {
+        Self::with_seed(0x5EED)
+    }
+
+    /// Create an empty skip list whose node levels are drawn from a
+    /// generator seeded with `seed`.
+    pub fn with_seed(seed: u64) -> Self {
+        SkipList {
+            nodes: vec![Node {
+                value: None,
+                next: vec![None; MAX_LEVEL],
+            }],
+            free: Vec::new(),
+            level: 1,
+            len: 0,
+            rng: XorShift::new(seed),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let before = self.predecessors(value)[0];
+        self.nodes[before].next[0].is_some_and(|node| self.value(node) == value)
+    }
+
+    /// Insert `value`. Returns false if it was already present.
+    pub fn insert(&mut self, value: T) -> bool {
+        let before = self.predecessors(&value);
+        if self.nodes[before[0]].next[0].is_some_and(|node| *self.value(node) == value) {
+            return false;
+        }
+
+        let height = self.random_height();
+        if height > self.level {
+            // before[self.level..height] already points at the head
+            self.level = height;
+        }
+        let next = (0..height).map(|l| self.nodes[before[l]].next[l]).collect();
+        let node = Node {
+            value: Some(value),
+            next,
+        };
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+        for (l, &before) in before.iter().enumerate().take(height) {
+            self.nodes[before].next[l] = Some(index);
+        }
+
+        self.len += 1;
+        true
+    }
+
+    /// Remove `value`. Returns false if it was not present.
+    pub fn remove(&mut self, value: &T) -> bool {
+        let before = self.predecessors(value);
+        let index = match self.nodes[before[0]].next[0] {
+            Some(index) if self.value(index) == value => index,
+            _ => return false,
+        };
+
+        let next = std::mem::take(&mut self.nodes[index].next);
+        for (l, next) in next.into_iter().enumerate() {
+            self.nodes[before[l]].next[l] = next;
+        }
+        self.nodes[index].value = None;
+        self.free.push(index);
+        while self.level > 1 && self.nodes[0].next[self.level - 1].is_none() {
+            self.level -= 1;
+        }
+
+        self.len -= 1;
+        true
+    }
+
+    /// Iterate over the values in ascending order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            next: self.nodes[0].next[0],
+        }
+    }
+
+    fn value(&self, node: usize) -> &T {
+        self.nodes[node].value.as_ref().unwrap()
+    }
+
+    // For every level, the last node whose value is less than `value`.
+    fn predecessors(&self, value: &T) -> [usize; MAX_LEVEL] {
+        let mut before = [0; MAX_LEVEL];
+        let mut node = 0;
+        for l in (0..self.level).rev() {
+            while let Some(next) = self.nodes[node].next[l] {
+                if self.value(next) >= value {
+                    break;
+                }
+                node = next;
+            }
+            before[l] = node;
+        }
+        before
+    }
+
+    // A level h >= 1 with probability 2^-h, from the trailing one bits of a
+    // random number.
+    fn random_height(&mut self) -> usize {
+        let height = self.rng.next_u64().trailing_ones() as usize + 1;
+        height.min(MAX_LEVEL)
+    }
+}
+
+impl<T: Ord> Default for SkipList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Iter<'a, T: Ord + 'a> {
+    list: &'a SkipList<T>,
+    next: Option<usize>,
+}
+
+impl<'a, T: Ord> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next?;
+        self.next = self.list.nodes[node].next[0];
+        Some(self.list.value(node))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn empty() {
+        let mut list: SkipList<i32> = SkipList::new();
+        assert!(list.is_empty());
+        assert!(!list.contains(&1));
+        assert!(!list.remove(&1));
+        assert_eq!(list.iter().next(), None);
+    }
+
+    #[test]
+    fn many_insertions() {
+        let mut rng = XorShift::new(61);
+        let mut list = SkipList::new();
+        let mut expected = BTreeSet::new();
+        for _ in 0..5000 {
+            let value = rng.gen_range(0, 10_000);
+            assert_eq!(list.insert(value), expected.insert(value));
+        }
+        assert_eq!(list.len(), expected.len());
+        assert!(list.iter().eq(expected.iter()));
+        for value in 0..10_000 {
+            assert_eq!(list.contains(&value), expected.contains(&value));
+        }
+        // the number of levels should be about log2(len)
+        let log_len = 64 - list.len().leading_zeros() as usize;
+        assert!(list.level <= 2 * log_len);
+    }
+
+    #[test]
+    fn remove_present_and_absent() {
+        let mut list = SkipList::new();
+        for value in (0..200).step_by(2) {
+            list.insert(value);
+        }
+        for value in 0..100 {
+            // even values are present until removed, odd ones never are
+            assert_eq!(list.remove(&value), value % 2 == 0);
+            assert!(!list.contains(&value));
+        }
+        assert_eq!(list.len(), 50);
+        assert!(list.iter().cloned().eq((100..200).step_by(2)));
+    }
+
+    #[test]
+    fn reinsert_after_remove() {
+        let mut rng = XorShift::new(67);
+        let mut list = SkipList::with_seed(3);
+        let mut expected = BTreeSet::new();
+        for _ in 0..5000 {
+            let value = rng.gen_range(0, 300);
+            if rng.gen_range(0, 2) == 0 {
+                assert_eq!(list.insert(value), expected.insert(value));
+            } else {
+                assert_eq!(list.remove(&value), expected.remove(&value));
+            }
+        }
+        assert_eq!(list.len(), expected.len());
+        assert!(list.iter().eq(expected.iter()));
+        // removed slots are reused
+        assert!(list.nodes.len() <= 301);
+    }
+}
@@ -0,0 +1,98 @@
+/// A sparse table answering range queries in O(1) for idempotent operations.
+///
+/// `combine` must be associative and idempotent, i.e. `combine(x, x) == x`,
+/// as is the case for `min`, `max` and `gcd`. Level `k` of the table stores
+/// the combination of every range of length `2^k`. Any range `l..=r` is the
+/// union of two, possibly overlapping, ranges of the same power-of-two
+/// length, and idempotence makes the overlap harmless.
+///
+/// Building takes O(n log n) time and memory. Unlike `SegmentTree`, the
+/// values cannot be updated afterwards.
+pub struct SparseTable<T> {
+    table: Vec<Vec<T>>,
+    combine: fn(&T, &T) -> T,
+}
+
+impl<T: Clone> SparseTable<T> {
+    pub fn build(arr: &[T], combine: fn(&T, &T) -> T) -> Self {
+        let mut table = vec![arr.to_vec()];
+        let mut width = 1;
+        while 2 * width <= arr.len() {
+            let prev = table.last().unwrap();
+            let level = (0..=arr.len() - 2 * width)
+                .map(|i| combine(&prev[i], &prev[i + width]))
+                .collect();
+            table.push(level);
+            width *= 2;
+        }
+        SparseTable { table, combine }
+    }
+
+    pub fn len(&self) -> usize {
+        self.table[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table[0].is_empty()
+    }
+
+    /// Combine the values at indices `l..=r` in O(1).
+    pub fn query(&self, l: usize, r: usize) -> T {
+        assert!(l <= r && r < self.len(), "invalid range");
+        // largest k with 2^k <= r - l + 1
+        let k = (r - l + 1).ilog2() as usize;
+        let level = &self.table[k];
+        (self.combine)(&level[l], &level[r + 1 - (1 << k)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::gcd;
+    use crate::random::XorShift;
+    use std::cmp::{max, min};
+
+    #[test]
+    fn minimum_against_scan() {
+        let mut rng = XorShift::new(5);
+        let values: Vec<u64> = (0..100).map(|_| rng.gen_range(0, 1000)).collect();
+        let table = SparseTable::build(&values, |a, b| *min(a, b));
+
+        for _ in 0..1000 {
+            let l = rng.gen_range(0, values.len() as u64) as usize;
+            let r = rng.gen_range(l as u64, values.len() as u64) as usize;
+            assert_eq!(table.query(l, r), *values[l..=r].iter().min().unwrap());
+        }
+    }
+
+    #[test]
+    fn single_element_ranges() {
+        let values = [4, -2, 7, 0, 7];
+        let table = SparseTable::build(&values, |a, b| *max(a, b));
+        for (i, &value) in values.iter().enumerate() {
+            assert_eq!(table.query(i, i), value);
+        }
+        assert_eq!(table.query(0, 4), 7);
+        assert_eq!(table.query(0, 1), 4);
+
+        let single = SparseTable::build(&[9], |a, b| *min(a, b));
+        assert_eq!(single.query(0, 0), 9);
+    }
+
+    #[test]
+    fn gcd_ranges() {
+        let values = [12, 18, 24, 7, 14, 21];
+        let table = SparseTable::build(&values, |&a, &b| gcd(a, b));
+        assert_eq!(table.query(0, 2), 6);
+        assert_eq!(table.query(3, 5), 7);
+        assert_eq!(table.query(0, 5), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        let table = SparseTable::build(&[1, 2, 3], |a, b| *min(a, b));
+        table.query(1, 3);
+    }
+}
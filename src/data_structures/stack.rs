@@ -0,0 +1,117 @@
+use std::iter::FromIterator;
+
+/// A last-in, first-out stack backed by a `Vec<T>`.
+///
+/// Dropping a `Vec` frees its elements in a loop, so unlike a naively boxed
+/// linked stack even a very deep `Stack` cannot overflow the call stack when
+/// it is dropped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    pub fn new() -> Self {
+        Stack { items: Vec::new() }
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    /// Remove and return the top item, or `None` if the stack is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    /// Returns the top item without removing it.
+    #[must_use]
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    #[must_use]
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.items.last_mut()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Items are pushed in iteration order, so the last one ends up on top.
+impl<T> FromIterator<T> for Stack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Stack {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Owned iteration pops the items, from the top of the stack down.
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = std::iter::Rev<std::vec::IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter().rev()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lifo_order() {
+        let mut stack = Stack::new();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Some(&3));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        stack.push(4);
+        assert_eq!(stack.pop(), Some(4));
+        assert_eq!(stack.pop(), Some(1));
+    }
+
+    #[test]
+    fn pop_empty() {
+        let mut stack: Stack<i32> = Stack::new();
+        assert!(stack.is_empty());
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.peek(), None);
+    }
+
+    #[test]
+    fn peek_mut() {
+        let mut stack = Stack::new();
+        stack.push(String::from("a"));
+        if let Some(top) = stack.peek_mut() {
+            top.push('b');
+        }
+        assert_eq!(stack.pop(), Some(String::from("ab")));
+    }
+
+    #[test]
+    fn iterators() {
+        let stack: Stack<_> = (1..=4).collect();
+        assert_eq!(stack.peek(), Some(&4));
+        assert_eq!(stack.into_iter().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+    }
+}
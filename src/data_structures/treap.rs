@@ -0,0 +1,289 @@
+use crate::random::XorShift;
+use std::cmp::{max, Ordering};
+
+/// A randomized binary search tree.
+///
+/// Every node gets a random priority and the tree is kept in heap order on
+/// the priorities as well as in search order on the values. The shape is
+/// then the same as if the values had been inserted in random order, so the
+/// expected height is O(log n) whatever the actual insertion order.
+///
+/// Priorities come from a seeded generator, so a given sequence of
+/// operations always builds the same tree.
+pub struct Treap<T: Ord> {
+    root: Tree<T>,
+    len: usize,
+    rng: XorShift,
+}
+
+type Tree<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    priority: u64,
+    left: Tree<T>,
+    right: Tree<T>,
+}
+
+impl<T: Ord> Treap<T> {
+    pub fn new() -> Self {
+        Self::with_seed(0x5EED)
+    }
+
+    /// Create an empty treap whose priorities are drawn from a generator
+    /// seeded with `seed`.
+    pub fn with_seed(seed: u64) -> Self {
+        Treap {
+            root: None,
+            len: 0,
+            rng: XorShift::new(seed),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of levels of the tree, 0 if it is empty.
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            current = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            }
+        }
+        false
+    }
+
+    /// Insert `value`. Returns false if it was already present.
+    pub fn insert(&mut self, value: T) -> bool {
+        let priority = self.rng.next_u64();
+        let inserted = insert(&mut self.root, value, priority);
+        if inserted {
+            self.len += 1;
+        }
+        inserted
+    }
+
+    /// Remove `value`. Returns false if it was not present.
+    pub fn remove(&mut self, value: &T) -> bool {
+        let removed = remove(&mut self.root, value);
+        if removed {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// Iterate over the values in ascending order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(&self.root);
+        iter
+    }
+}
+
+impl<T: Ord> Default for Treap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn height<T>(tree: &Tree<T>) -> usize {
+    tree.as_ref()
+        .map_or(0, |node| 1 + max(height(&node.left), height(&node.right)))
+}
+
+fn rotate_right<T>(tree: &mut Tree<T>) {
+    let mut y = tree.take().unwrap();
+    let mut x = y.left.take().unwrap();
+    y.left = x.right.take();
+    x.right = Some(y);
+    *tree = Some(x);
+}
+
+fn rotate_left<T>(tree: &mut Tree<T>) {
+    let mut x = tree.take().unwrap();
+    let mut y = x.right.take().unwrap();
+    x.right = y.left.take();
+    y.left = Some(x);
+    *tree = Some(y);
+}
+
+fn priority<T>(tree: &Tree<T>) -> u64 {
+    tree.as_ref().map_or(0, |node| node.priority)
+}
+
+// Insert as a leaf, then rotate the new node up while its priority is
+// larger than its parent's.
+fn insert<T: Ord>(tree: &mut Tree<T>, value: T, new_priority: u64) -> bool {
+    let node = match tree {
+        None => {
+            *tree = Some(Box::new(Node {
+                value,
+                priority: new_priority,
+                left: None,
+                right: None,
+            }));
+            return true;
+        }
+        Some(node) => node,
+    };
+    match value.cmp(&node.value) {
+        Ordering::Less => {
+            let inserted = insert(&mut node.left, value, new_priority);
+            if inserted && priority(&node.left) > node.priority {
+                rotate_right(tree);
+            }
+            inserted
+        }
+        Ordering::Greater => {
+            let inserted = insert(&mut node.right, value, new_priority);
+            if inserted && priority(&node.right) > node.priority {
+                rotate_left(tree);
+            }
+            inserted
+        }
+        Ordering::Equal => false,
+    }
+}
+
+// Rotate the node holding `value` down, always lifting the child with the
+// larger priority, until it has at most one child and can be unlinked.
+fn remove<T: Ord>(tree: &mut Tree<T>, value: &T) -> bool {
+    let node = match tree {
+        None => return false,
+        Some(node) => node,
+    };
+    match value.cmp(&node.value) {
+        Ordering::Less => remove(&mut node.left, value),
+        Ordering::Greater => remove(&mut node.right, value),
+        Ordering::Equal => match (node.left.is_some(), node.right.is_some()) {
+            (true, true) => {
+                if priority(&node.left) > priority(&node.right) {
+                    rotate_right(tree);
+                    remove(&mut tree.as_mut().unwrap().right, value)
+                } else {
+                    rotate_left(tree);
+                    remove(&mut tree.as_mut().unwrap().left, value)
+                }
+            }
+            (true, false) => {
+                *tree = node.left.take();
+                true
+            }
+            (false, _) => {
+                *tree = node.right.take();
+                true
+            }
+        },
+    }
+}
+
+pub struct Iter<'a, T: 'a> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn push_left(&mut self, mut tree: &'a Tree<T>) {
+        while let Some(node) = tree {
+            self.stack.push(node);
+            tree = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        Some(&node.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Check search order and heap order at every node.
+    fn is_treap<T: Ord>(tree: &Tree<T>, low: Option<&T>, high: Option<&T>) -> bool {
+        match tree {
+            None => true,
+            Some(node) => {
+                low.is_none_or(|low| *low < node.value)
+                    && high.is_none_or(|high| node.value < *high)
+                    && priority(&node.left) <= node.priority
+                    && priority(&node.right) <= node.priority
+                    && is_treap(&node.left, low, Some(&node.value))
+                    && is_treap(&node.right, Some(&node.value), high)
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let treap: Treap<i32> = Treap::new();
+        assert!(treap.is_empty());
+        assert_eq!(treap.height(), 0);
+        assert!(!treap.contains(&1));
+        assert_eq!(treap.iter().next(), None);
+    }
+
+    #[test]
+    fn sequential_insertion_stays_shallow() {
+        let n = 10_000;
+        let mut treap = Treap::new();
+        for i in 0..n {
+            assert!(treap.insert(i));
+        }
+        assert_eq!(treap.len(), n);
+        assert!(is_treap(&treap.root, None, None));
+
+        // The expected height of a random BST is about 4.3 * ln(n), which is
+        // below 3 * log2(n).
+        let bound = 3.0 * (n as f64).log2();
+        assert!((treap.height() as f64) <= bound);
+        assert!(treap.iter().cloned().eq(0..n));
+    }
+
+    #[test]
+    fn duplicates() {
+        let mut treap = Treap::new();
+        assert!(treap.insert("b"));
+        assert!(treap.insert("a"));
+        assert!(!treap.insert("b"));
+        assert_eq!(treap.len(), 2);
+        assert!(treap.contains(&"a"));
+        assert!(!treap.contains(&"c"));
+    }
+
+    #[test]
+    fn remove() {
+        let mut treap = Treap::with_seed(3);
+        for i in (0..100).rev() {
+            treap.insert(i);
+        }
+        for i in (0..100).filter(|i| i % 3 != 0) {
+            assert!(treap.remove(&i));
+            assert!(is_treap(&treap.root, None, None));
+        }
+        assert!(!treap.remove(&1));
+
+        for i in 0..100 {
+            assert_eq!(treap.contains(&i), i % 3 == 0);
+        }
+        assert_eq!(treap.len(), 34);
+        assert!(treap.iter().cloned().eq((0..100).filter(|i| i % 3 == 0)));
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+
+/// A prefix tree over string keys.
+///
+/// Children are stored in a `HashMap<char, Node>`, so keys may contain any
+/// Unicode scalar value rather than just ASCII letters.
+#[derive(Debug, Default)]
+pub struct Trie {
+    root: Node,
+    len: usize,
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    children: HashMap<char, Node>,
+    // true iff the path from the root to this node spells a stored key
+    is_key: bool,
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Trie::default()
+    }
+
+    /// Returns the number of stored keys.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Insert `key`. Returns false if it was already present.
+    pub fn insert(&mut self, key: &str) -> bool {
+        let mut node = &mut self.root;
+        for c in key.chars() {
+            node = node.children.entry(c).or_default();
+        }
+
+        if node.is_key {
+            false
+        } else {
+            node.is_key = true;
+            self.len += 1;
+            true
+        }
+    }
+
+    /// Returns true iff `key` itself was inserted. A prefix of a stored key
+    /// is not enough.
+    pub fn contains(&self, key: &str) -> bool {
+        self.find(key).is_some_and(|node| node.is_key)
+    }
+
+    /// Returns true iff some stored key starts with `prefix`.
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        // Branches are pruned on removal, so only the root can be a non-key
+        // node without children.
+        self.find(prefix)
+            .is_some_and(|node| node.is_key || !node.children.is_empty())
+    }
+
+    /// Remove `key`, pruning branches that no longer lead to any key.
+    /// Returns false if `key` was not present.
+    pub fn remove(&mut self, key: &str) -> bool {
+        let chars: Vec<char> = key.chars().collect();
+        if Self::remove_from(&mut self.root, &chars) {
+            self.len -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn find(&self, prefix: &str) -> Option<&Node> {
+        let mut node = &self.root;
+        for c in prefix.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+
+    fn remove_from(node: &mut Node, key: &[char]) -> bool {
+        match key.split_first() {
+            None => {
+                let was_key = node.is_key;
+                node.is_key = false;
+                was_key
+            }
+            Some((c, rest)) => {
+                let removed = match node.children.get_mut(c) {
+                    Some(child) => Self::remove_from(child, rest),
+                    None => return false,
+                };
+                if removed {
+                    let child = &node.children[c];
+                    if !child.is_key && child.children.is_empty() {
+                        node.children.remove(c);
+                    }
+                }
+                removed
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let trie = Trie::new();
+        assert!(!trie.contains(""));
+        assert!(!trie.starts_with(""));
+        assert!(!trie.starts_with("a"));
+    }
+
+    #[test]
+    fn prefix_queries() {
+        let mut trie = Trie::new();
+        trie.insert("tea");
+        trie.insert("ten");
+        trie.insert("inn");
+
+        assert!(trie.starts_with("te"));
+        assert!(trie.starts_with("inn"));
+        assert!(!trie.starts_with("to"));
+        assert!(!trie.starts_with("inns"));
+    }
+
+    #[test]
+    fn prefix_is_not_a_key() {
+        let mut trie = Trie::new();
+        trie.insert("tea");
+        assert!(trie.contains("tea"));
+        assert!(!trie.contains("te"));
+        assert!(!trie.contains("teas"));
+    }
+
+    #[test]
+    fn remove_key_that_is_prefix_of_another() {
+        let mut trie = Trie::new();
+        trie.insert("car");
+        trie.insert("cart");
+
+        assert!(trie.remove("car"));
+        assert!(!trie.contains("car"));
+        assert!(trie.contains("cart"));
+        assert!(trie.starts_with("car"));
+
+        assert!(!trie.remove("car"));
+        assert!(trie.remove("cart"));
+        assert!(!trie.starts_with("c"));
+        assert!(trie.is_empty());
+    }
+
+    #[test]
+    fn unicode_keys() {
+        let mut trie = Trie::new();
+        assert!(trie.insert("日本語"));
+        assert!(!trie.insert("日本語"));
+        trie.insert("日本");
+        assert_eq!(trie.len(), 2);
+        assert!(trie.contains("日本"));
+        assert!(trie.starts_with("日"));
+        assert!(!trie.contains("日"));
+    }
+}
@@ -0,0 +1,94 @@
+/// Coin change
+///
+/// `min_coins(coins, amount)` returns the fewest coins, each taken from the
+/// denominations in `coins` as often as needed, that add up to `amount`, or
+/// `None` if `amount` cannot be made. Coins of value 0 are ignored.
+///
+/// `best[a]` is the answer for amount `a`, computed bottom-up from
+/// `best[a] = 1 + min(best[a - c])` over all coins `c <= a`, in
+/// O(amount * coins.len()) time.
+pub fn min_coins(coins: &[u64], amount: u64) -> Option<usize> {
+    let amount = amount as usize;
+    let mut best: Vec<Option<usize>> = vec![None; amount + 1];
+    best[0] = Some(0);
+
+    for a in 1..=amount {
+        best[a] = coins
+            .iter()
+            .map(|&coin| coin as usize)
+            .filter(|&coin| coin != 0 && coin <= a)
+            .filter_map(|coin| best[a - coin])
+            .min()
+            .map(|count| count + 1);
+    }
+
+    best[amount]
+}
+
+/// `count_ways(coins, amount)` returns the number of distinct multisets of
+/// coins adding up to `amount`. The order of the coins does not matter, so
+/// `1 + 2` and `2 + 1` count once. Returns `None` if the count does not fit
+/// in a `u64`.
+///
+/// Handling one denomination at a time, in the outer loop, is what keeps
+/// different orders of the same coins from being counted separately. A
+/// count that overflowed is kept as `None`; since counts only grow, every
+/// count built on it would overflow too.
+pub fn count_ways(coins: &[u64], amount: u64) -> Option<u64> {
+    let amount = amount as usize;
+    let mut ways = vec![Some(0u64); amount + 1];
+    ways[0] = Some(1);
+
+    for &coin in coins.iter().filter(|&&coin| coin != 0) {
+        let coin = coin as usize;
+        for a in coin..=amount {
+            ways[a] = match (ways[a], ways[a - coin]) {
+                (Some(x), Some(y)) => x.checked_add(y),
+                _ => None,
+            };
+        }
+    }
+
+    ways[amount]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classic() {
+        assert_eq!(min_coins(&[1, 2, 5], 11), Some(3));
+        assert_eq!(min_coins(&[1, 3, 4], 6), Some(2));
+    }
+
+    #[test]
+    fn impossible() {
+        assert_eq!(min_coins(&[2], 3), None);
+        assert_eq!(min_coins(&[], 1), None);
+        assert_eq!(count_ways(&[2], 3), Some(0));
+    }
+
+    #[test]
+    fn zero_amount() {
+        assert_eq!(min_coins(&[1, 2, 5], 0), Some(0));
+        assert_eq!(min_coins(&[], 0), Some(0));
+        assert_eq!(count_ways(&[1, 2, 5], 0), Some(1));
+    }
+
+    #[test]
+    fn ways() {
+        assert_eq!(count_ways(&[1, 2, 5], 5), Some(4));
+        assert_eq!(count_ways(&[2, 5, 3, 6], 10), Some(5));
+        assert_eq!(count_ways(&[0, 1], 3), Some(1));
+    }
+
+    #[test]
+    fn ways_overflow() {
+        // With every coin up to the amount, the count is the partition
+        // number p(n), and p(416) is the largest one that fits in a u64.
+        let coins: Vec<u64> = (1..=417).collect();
+        assert_eq!(count_ways(&coins, 416), Some(17_873_792_969_689_876_004));
+        assert_eq!(count_ways(&coins, 417), None);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::searching::lower_bound;
+
+/// Longest strictly increasing subsequence via patience sorting
+///
+/// `tails[k]` is the smallest value that ends an increasing subsequence of
+/// length `k + 1` seen so far. The tails are increasing, so each element can
+/// be placed with a binary search, giving O(n log n) overall.
+///
+/// `lis_length(arr)` returns the length of the longest strictly increasing
+/// subsequence of `arr`.
+pub fn lis_length<T: Ord + Copy>(arr: &[T]) -> usize {
+    let mut tails: Vec<T> = Vec::new();
+    for &x in arr {
+        let k = lower_bound(&x, &tails);
+        if k == tails.len() {
+            tails.push(x);
+        } else {
+            tails[k] = x;
+        }
+    }
+    tails.len()
+}
+
+/// `lis(arr)` returns one longest strictly increasing subsequence of `arr`.
+pub fn lis<T: Ord + Copy>(arr: &[T]) -> Vec<T> {
+    let mut tails: Vec<T> = Vec::new();
+    // index into `arr` of the element currently stored in each tail
+    let mut tail_index: Vec<usize> = Vec::new();
+    // index of the element before arr[i] in the subsequence ending at arr[i]
+    let mut predecessor: Vec<Option<usize>> = vec![None; arr.len()];
+
+    for (i, &x) in arr.iter().enumerate() {
+        let k = lower_bound(&x, &tails);
+        if k > 0 {
+            predecessor[i] = Some(tail_index[k - 1]);
+        }
+        if k == tails.len() {
+            tails.push(x);
+            tail_index.push(i);
+        } else {
+            tails[k] = x;
+            tail_index[k] = i;
+        }
+    }
+
+    let mut result = Vec::with_capacity(tails.len());
+    let mut current = tail_index.last().cloned();
+    while let Some(i) = current {
+        result.push(arr[i]);
+        current = predecessor[i];
+    }
+    result.reverse();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_strictly_increasing(arr: &[i32]) -> bool {
+        arr.windows(2).all(|pair| pair[0] < pair[1])
+    }
+
+    #[test]
+    fn classic() {
+        let arr = [10, 9, 2, 5, 3, 7, 101, 18];
+        assert_eq!(lis_length(&arr), 4);
+
+        let sub = lis(&arr);
+        assert_eq!(sub.len(), 4);
+        assert!(is_strictly_increasing(&sub));
+    }
+
+    #[test]
+    fn reconstruction() {
+        assert_eq!(lis(&[0, 8, 4, 12, 2, 10, 6, 14, 1, 9]), vec![0, 2, 6, 9]);
+        assert_eq!(lis(&[3, 1, 2]), vec![1, 2]);
+    }
+
+    #[test]
+    fn strictly_decreasing() {
+        let arr = [5, 4, 3, 2, 1];
+        assert_eq!(lis_length(&arr), 1);
+        assert_eq!(lis(&arr).len(), 1);
+    }
+
+    #[test]
+    fn repeated_elements() {
+        let arr = [2, 2, 2, 2];
+        assert_eq!(lis_length(&arr), 1);
+        assert_eq!(lis(&arr), vec![2]);
+    }
+
+    #[test]
+    fn empty() {
+        let arr: [i32; 0] = [];
+        assert_eq!(lis_length(&arr), 0);
+        assert!(lis(&arr).is_empty());
+    }
+}
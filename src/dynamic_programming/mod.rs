@@ -1,5 +1,17 @@
+mod coin_change;
 mod egg_dropping;
 mod fibonacci;
+mod lis;
+mod rod_cutting;
+mod subset_sum;
 
+pub use self::coin_change::count_ways;
+pub use self::coin_change::min_coins;
 pub use self::egg_dropping::egg_drop;
 pub use self::fibonacci::fibonacci;
+pub use self::lis::lis;
+pub use self::lis::lis_length;
+pub use self::rod_cutting::max_revenue;
+pub use self::rod_cutting::optimal_cuts;
+pub use self::subset_sum::can_partition;
+pub use self::subset_sum::subset_sum;
@@ -0,0 +1,111 @@
+/// Rod cutting
+///
+/// A rod of length `n` can be cut into pieces of integer lengths, and a piece
+/// of length `i + 1` sells for `prices[i]`. `max_revenue(prices)` returns the
+/// best total price for a rod of length `prices.len()`, or `None` if it does
+/// not fit in a `u64`.
+///
+/// `best[l]` is the best revenue for length `l`: the first piece has some
+/// length `i` and the rest is sold optimally, so
+/// `best[l] = max(prices[i - 1] + best[l - i])` over `1 <= i <= l`. This
+/// takes O(n^2) time. Cutting a piece off a longer rod never lowers its
+/// best revenue, so once `best[l]` overflows, so does `best[n]`.
+pub fn max_revenue(prices: &[u64]) -> Option<u64> {
+    solve(prices).map(|(best, _)| best[prices.len()])
+}
+
+/// Like `max_revenue`, but returns the lengths of the pieces of one optimal
+/// cut, longest first. An uncut rod is a single piece.
+pub fn optimal_cuts(prices: &[u64]) -> Option<Vec<usize>> {
+    let (_, first_piece) = solve(prices)?;
+    let mut cuts = Vec::new();
+    let mut length = prices.len();
+    while length > 0 {
+        cuts.push(first_piece[length]);
+        length -= first_piece[length];
+    }
+    cuts.sort_unstable_by(|a, b| b.cmp(a));
+    Some(cuts)
+}
+
+// Returns the best revenue for every length and the length of the first
+// piece of an optimal cut, or `None` on overflow.
+fn solve(prices: &[u64]) -> Option<(Vec<u64>, Vec<usize>)> {
+    let n = prices.len();
+    let mut best = vec![0; n + 1];
+    let mut first_piece = vec![0; n + 1];
+    for length in 1..=n {
+        for piece in 1..=length {
+            let revenue = prices[piece - 1].checked_add(best[length - piece])?;
+            if revenue > best[length] || first_piece[length] == 0 {
+                best[length] = revenue;
+                first_piece[length] = piece;
+            }
+        }
+    }
+    Some((best, first_piece))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the price table from CLRS, section 15.1
+    const CLRS: [u64; 10] = [1, 5, 8, 9, 10, 17, 17, 20, 24, 30];
+
+    #[test]
+    fn clrs() {
+        let expected = [1, 5, 8, 10, 13, 17, 18, 22, 25, 30];
+        for (length, &revenue) in expected.iter().enumerate() {
+            assert_eq!(max_revenue(&CLRS[..=length]), Some(revenue));
+        }
+        assert_eq!(optimal_cuts(&CLRS[..4]), Some(vec![2, 2]));
+        assert_eq!(optimal_cuts(&CLRS), Some(vec![10]));
+        assert_eq!(optimal_cuts(&CLRS[..8]), Some(vec![6, 2]));
+    }
+
+    #[test]
+    fn no_cut() {
+        // Longer pieces are worth more than their parts.
+        let prices = [1, 3, 6, 10, 15];
+        assert_eq!(max_revenue(&prices), Some(15));
+        assert_eq!(optimal_cuts(&prices), Some(vec![5]));
+    }
+
+    #[test]
+    fn cut_into_unit_pieces() {
+        let prices = [3, 4, 5];
+        assert_eq!(max_revenue(&prices), Some(9));
+        assert_eq!(optimal_cuts(&prices), Some(vec![1, 1, 1]));
+    }
+
+    #[test]
+    fn empty_rod() {
+        assert_eq!(max_revenue(&[]), Some(0));
+        assert_eq!(optimal_cuts(&[]), Some(vec![]));
+    }
+
+    #[test]
+    fn cuts_add_up() {
+        for length in 1..=CLRS.len() {
+            let prices = &CLRS[..length];
+            let cuts = optimal_cuts(prices).unwrap();
+            assert_eq!(cuts.iter().sum::<usize>(), length);
+            let revenue: u64 = cuts.iter().map(|&piece| prices[piece - 1]).sum();
+            assert_eq!(Some(revenue), max_revenue(prices));
+        }
+    }
+
+    #[test]
+    fn revenue_overflow() {
+        let half = u64::MAX / 2;
+        // Two pieces of length 1 make u64::MAX - 1, which still fits.
+        assert_eq!(max_revenue(&[half, 0]), Some(2 * half));
+        assert_eq!(optimal_cuts(&[half, 0]), Some(vec![1, 1]));
+        assert_eq!(max_revenue(&[half + 1, 0]), None);
+        assert_eq!(optimal_cuts(&[half + 1, 0]), None);
+        // The overflow in length 2 would carry over to length 3.
+        assert_eq!(max_revenue(&[half + 1, 0, u64::MAX]), None);
+        assert_eq!(max_revenue(&[u64::MAX]), Some(u64::MAX));
+    }
+}
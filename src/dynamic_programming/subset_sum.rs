@@ -0,0 +1,84 @@
+/// Subset sum
+///
+/// `subset_sum(nums, target)` returns true iff some subset of `nums`, each
+/// element used at most once, adds up to `target`. The empty subset sums to
+/// zero.
+///
+/// `reachable[s]` records whether the elements seen so far can make the sum
+/// `s`. Each element `x` adds `s + x` for every reachable `s`; the sums are
+/// visited from high to low so that `x` is not used twice. This takes
+/// O(target * nums.len()) time and O(target) memory.
+pub fn subset_sum(nums: &[u64], target: u64) -> bool {
+    let target = target as usize;
+    let mut reachable = vec![false; target + 1];
+    reachable[0] = true;
+
+    for &x in nums {
+        let x = x as usize;
+        if x > target {
+            continue;
+        }
+        for s in (x..=target).rev() {
+            if reachable[s - x] {
+                reachable[s] = true;
+            }
+        }
+        if reachable[target] {
+            return true;
+        }
+    }
+
+    reachable[target]
+}
+
+/// `can_partition(nums)` returns true iff `nums` can be split into two
+/// multisets with equal sums, i.e. iff some subset makes half the total.
+/// Returns false if the total does not fit in a `u64`.
+pub fn can_partition(nums: &[u64]) -> bool {
+    match nums.iter().try_fold(0u64, |total, &x| total.checked_add(x)) {
+        Some(total) => total.is_multiple_of(2) && subset_sum(nums, total / 2),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reachable_target() {
+        assert!(subset_sum(&[3, 34, 4, 12, 5, 2], 9));
+        assert!(subset_sum(&[3, 34, 4, 12, 5, 2], 60));
+        assert!(subset_sum(&[7], 7));
+    }
+
+    #[test]
+    fn unreachable_target() {
+        assert!(!subset_sum(&[3, 34, 4, 12, 5, 2], 30));
+        assert!(!subset_sum(&[2, 4, 6], 5));
+        // each element only once
+        assert!(!subset_sum(&[5], 10));
+    }
+
+    #[test]
+    fn empty_set() {
+        assert!(subset_sum(&[], 0));
+        assert!(!subset_sum(&[], 1));
+        assert!(subset_sum(&[1, 2], 0));
+    }
+
+    #[test]
+    fn partition() {
+        assert!(can_partition(&[1, 5, 11, 5]));
+        assert!(!can_partition(&[1, 2, 3, 5]));
+        assert!(!can_partition(&[1, 2, 4]));
+        assert!(can_partition(&[]));
+        assert!(can_partition(&[0, 0]));
+    }
+
+    #[test]
+    fn partition_total_overflows() {
+        assert!(!can_partition(&[u64::MAX, 1]));
+        assert!(!can_partition(&[u64::MAX, u64::MAX]));
+    }
+}
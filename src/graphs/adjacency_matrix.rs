@@ -0,0 +1,447 @@
+/// A directed graph on the nodes `0..n`, stored as an `n * n` matrix of
+/// optional edge weights, with the edge from `u` to `v` at `u * n + v`.
+///
+/// Adding, removing and looking up an edge take O(1), at the price of
+/// O(n^2) memory, so this suits dense graphs. There is at most one edge per
+/// ordered pair, and adding another one replaces its weight. The algorithms
+/// in `graphs` take adjacency lists instead, `graph[u]` holding `(v, w)` for
+/// every edge from `u` to `v`; `from_adjacency_list` and `to_adjacency_list`
+/// convert between the two, and `from_rows` and `to_rows` to and from a
+/// nested `Vec<Vec<Option<W>>>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdjacencyMatrix<W> {
+    n: usize,
+    weights: Vec<Option<W>>,
+}
+
+impl<W> AdjacencyMatrix<W> {
+    /// Creates a graph with `n` nodes and no edges.
+    pub fn new(n: usize) -> Self {
+        let size = n.checked_mul(n).expect("matrix size overflows usize");
+        AdjacencyMatrix {
+            n,
+            weights: (0..size).map(|_| None).collect(),
+        }
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    fn index(&self, u: usize, v: usize) -> usize {
+        assert!(
+            u < self.n && v < self.n,
+            "edge ({}, {}) out of range for {} nodes",
+            u,
+            v,
+            self.n
+        );
+        u * self.n + v
+    }
+
+    /// Adds the edge from `u` to `v`, returning the weight it replaces.
+    pub fn add_edge(&mut self, u: usize, v: usize, w: W) -> Option<W> {
+        let i = self.index(u, v);
+        self.weights[i].replace(w)
+    }
+
+    /// Adds the undirected edge between `u` and `v`, stored as an edge in
+    /// each direction. A self-loop is a single edge.
+    pub fn add_undirected_edge(&mut self, u: usize, v: usize, w: W)
+    where
+        W: Clone,
+    {
+        if u != v {
+            self.add_edge(v, u, w.clone());
+        }
+        self.add_edge(u, v, w);
+    }
+
+    /// Builds a graph with `n` nodes from directed `(u, v, w)` edges. Panics
+    /// if an endpoint is not below `n`.
+    pub fn from_edge_list(n: usize, edges: &[(usize, usize, W)]) -> Self
+    where
+        W: Clone,
+    {
+        let mut matrix = AdjacencyMatrix::new(n);
+        for (u, v, w) in edges {
+            matrix.add_edge(*u, *v, w.clone());
+        }
+        matrix
+    }
+
+    /// Builds a graph with `n` nodes from undirected `(u, v, w)` edges.
+    pub fn from_undirected_edges(n: usize, edges: &[(usize, usize, W)]) -> Self
+    where
+        W: Clone,
+    {
+        let mut matrix = AdjacencyMatrix::new(n);
+        for (u, v, w) in edges {
+            matrix.add_undirected_edge(*u, *v, w.clone());
+        }
+        matrix
+    }
+
+    /// Removes the edge from `u` to `v`, returning its weight.
+    pub fn remove_edge(&mut self, u: usize, v: usize) -> Option<W> {
+        let i = self.index(u, v);
+        self.weights[i].take()
+    }
+
+    /// Returns the weight of the edge from `u` to `v`.
+    pub fn edge(&self, u: usize, v: usize) -> Option<&W> {
+        self.weights[self.index(u, v)].as_ref()
+    }
+
+    /// Returns true if there is an edge from `u` to `v`.
+    pub fn has_edge(&self, u: usize, v: usize) -> bool {
+        self.edge(u, v).is_some()
+    }
+
+    /// Returns `(v, w)` for every edge from `u`, in increasing order of `v`.
+    pub fn neighbors<'a>(&'a self, u: usize) -> impl Iterator<Item = (usize, &'a W)> + 'a {
+        assert!(u < self.n, "node {} out of range for {} nodes", u, self.n);
+        self.weights[u * self.n..(u + 1) * self.n]
+            .iter()
+            .enumerate()
+            .filter_map(|(v, w)| w.as_ref().map(|w| (v, w)))
+    }
+
+    /// Returns `(u, v, w)` for every edge, ordered by `u` and then `v`. An
+    /// undirected edge shows up once in each direction, and a self-loop once.
+    pub fn edges<'a>(&'a self) -> impl Iterator<Item = (usize, usize, &'a W)> + 'a {
+        let n = self.n;
+        self.weights
+            .iter()
+            .enumerate()
+            .filter_map(move |(i, w)| w.as_ref().map(|w| (i / n, i % n, w)))
+    }
+
+    /// Number of edges leaving `u`, in O(n).
+    pub fn out_degree(&self, u: usize) -> usize {
+        self.neighbors(u).count()
+    }
+
+    /// Number of edges entering `v`, in O(n).
+    pub fn in_degree(&self, v: usize) -> usize {
+        assert!(v < self.n, "node {} out of range for {} nodes", v, self.n);
+        (0..self.n).filter(|&u| self.has_edge(u, v)).count()
+    }
+
+    /// Builds the matrix of adjacency lists. Of parallel edges, the last one
+    /// wins.
+    pub fn from_adjacency_list(graph: &[Vec<(usize, W)>]) -> Self
+    where
+        W: Clone,
+    {
+        let mut matrix = AdjacencyMatrix::new(graph.len());
+        for (u, edges) in graph.iter().enumerate() {
+            for (v, w) in edges {
+                matrix.add_edge(u, *v, w.clone());
+            }
+        }
+        matrix
+    }
+
+    /// Returns the adjacency lists, with the targets of each node in
+    /// increasing order.
+    pub fn to_adjacency_list(&self) -> Vec<Vec<(usize, W)>>
+    where
+        W: Clone,
+    {
+        if self.n == 0 {
+            return Vec::new();
+        }
+        self.weights
+            .chunks(self.n)
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .filter_map(|(v, w)| w.clone().map(|w| (v, w)))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Builds the matrix from its rows, with `rows[u][v]` the weight of the
+    /// edge from `u` to `v`. Returns `None` unless the rows form a square.
+    pub fn from_rows(rows: Vec<Vec<Option<W>>>) -> Option<Self> {
+        let n = rows.len();
+        if rows.iter().any(|row| row.len() != n) {
+            return None;
+        }
+        Some(AdjacencyMatrix {
+            n,
+            weights: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Returns the rows of the matrix.
+    pub fn to_rows(&self) -> Vec<Vec<Option<W>>>
+    where
+        W: Clone,
+    {
+        if self.n == 0 {
+            return Vec::new();
+        }
+        self.weights.chunks(self.n).map(<[_]>::to_vec).collect()
+    }
+}
+
+/// Converts a square matrix, with `matrix[u][v]` the weight of the edge from
+/// `u` to `v`, to adjacency lists. Returns `None` if it is not square.
+pub fn from_adjacency_matrix<W: Clone>(matrix: &[Vec<Option<W>>]) -> Option<Vec<Vec<(usize, W)>>> {
+    AdjacencyMatrix::from_rows(matrix.to_vec()).map(|matrix| matrix.to_adjacency_list())
+}
+
+/// Converts adjacency lists to a square matrix. Of parallel edges, the last
+/// one wins.
+pub fn to_adjacency_matrix<W: Clone>(graph: &[Vec<(usize, W)>]) -> Vec<Vec<Option<W>>> {
+    AdjacencyMatrix::from_adjacency_list(graph).to_rows()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::dijkstra;
+    use crate::random::XorShift;
+
+    // Random adjacency lists without parallel edges, targets sorted.
+    fn random_graph(rng: &mut XorShift, n: usize) -> Vec<Vec<(usize, u64)>> {
+        let mut graph = vec![Vec::new(); n];
+        for edges in graph.iter_mut() {
+            for v in 0..n {
+                if rng.gen_range(0, 4) == 0 {
+                    edges.push((v, rng.gen_range(1, 100)));
+                }
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn add_and_remove_edges() {
+        let mut matrix = AdjacencyMatrix::new(3);
+        assert_eq!(matrix.len(), 3);
+        assert_eq!(matrix.add_edge(0, 1, 5), None);
+        assert_eq!(matrix.add_edge(0, 1, 7), Some(5));
+        assert_eq!(matrix.add_edge(2, 2, 1), None);
+        assert_eq!(matrix.edge(0, 1), Some(&7));
+        assert_eq!(matrix.edge(1, 0), None);
+        assert_eq!(matrix.edge(2, 2), Some(&1));
+        assert_eq!(matrix.remove_edge(0, 1), Some(7));
+        assert_eq!(matrix.remove_edge(0, 1), None);
+        assert_eq!(
+            matrix.to_adjacency_list(),
+            vec![vec![], vec![], vec![(2, 1)]]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn edge_out_of_range() {
+        let mut matrix = AdjacencyMatrix::new(3);
+        matrix.add_edge(0, 3, 1);
+    }
+
+    #[test]
+    fn from_edge_list() {
+        let edges = [(0, 1, 7u64), (0, 2, 9), (1, 2, 10), (2, 0, 1), (3, 3, 2)];
+        let matrix = AdjacencyMatrix::from_edge_list(4, &edges);
+        assert_eq!(
+            matrix.to_adjacency_list(),
+            vec![
+                vec![(1, 7), (2, 9)],
+                vec![(2, 10)],
+                vec![(0, 1)],
+                vec![(3, 2)]
+            ]
+        );
+        assert_eq!(
+            dijkstra(&matrix.to_adjacency_list(), 1),
+            vec![Some(11), Some(0), Some(10), None]
+        );
+        let empty: AdjacencyMatrix<u64> = AdjacencyMatrix::from_edge_list(2, &[]);
+        assert_eq!(empty.to_adjacency_list(), vec![vec![], vec![]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "edge (1, 4) out of range for 4 nodes")]
+    fn edge_list_out_of_range() {
+        AdjacencyMatrix::from_edge_list(4, &[(0, 1, 1), (1, 4, 1)]);
+    }
+
+    #[test]
+    fn degrees_and_neighbors() {
+        let edges = [(0, 1, 7), (0, 2, 9), (1, 2, 10), (2, 0, 1), (3, 3, 2)];
+        let matrix = AdjacencyMatrix::from_edge_list(5, &edges);
+        let out: Vec<usize> = (0..5).map(|u| matrix.out_degree(u)).collect();
+        let into: Vec<usize> = (0..5).map(|v| matrix.in_degree(v)).collect();
+        assert_eq!(out, vec![2, 1, 1, 1, 0]);
+        assert_eq!(into, vec![1, 1, 2, 1, 0]);
+        assert_eq!(
+            matrix.neighbors(0).collect::<Vec<_>>(),
+            vec![(1, &7), (2, &9)]
+        );
+        assert_eq!(matrix.neighbors(3).collect::<Vec<_>>(), vec![(3, &2)]);
+        assert_eq!(matrix.neighbors(4).count(), 0);
+        assert!(matrix.has_edge(2, 0));
+        assert!(!matrix.has_edge(0, 3));
+
+        let triangle =
+            AdjacencyMatrix::from_undirected_edges(3, &[(0, 1, 4), (1, 2, 5), (2, 0, 6)]);
+        for u in 0..3 {
+            assert_eq!(triangle.out_degree(u), 2);
+            assert_eq!(triangle.in_degree(u), 2);
+        }
+    }
+
+    #[test]
+    fn edges() {
+        let edges = [(2, 0, 1), (0, 2, 9), (3, 3, 2), (0, 1, 7), (1, 2, 10)];
+        let matrix = AdjacencyMatrix::from_edge_list(5, &edges);
+        assert_eq!(matrix.edges().count(), 5);
+        assert_eq!(
+            matrix.edges().collect::<Vec<_>>(),
+            vec![(0, 1, &7), (0, 2, &9), (1, 2, &10), (2, 0, &1), (3, 3, &2)]
+        );
+
+        let undirected =
+            AdjacencyMatrix::from_undirected_edges(3, &[(0, 1, 4), (2, 1, 5), (2, 2, 6)]);
+        assert_eq!(undirected.edges().count(), 5);
+        assert_eq!(
+            undirected.edges().collect::<Vec<_>>(),
+            vec![(0, 1, &4), (1, 0, &4), (1, 2, &5), (2, 1, &5), (2, 2, &6)]
+        );
+
+        assert_eq!(AdjacencyMatrix::<u64>::new(0).edges().count(), 0);
+        assert_eq!(AdjacencyMatrix::<u64>::new(4).edges().count(), 0);
+    }
+
+    #[test]
+    fn edges_match_adjacency_list() {
+        let mut rng = XorShift::new(137);
+        for n in 0..20 {
+            let graph = random_graph(&mut rng, n);
+            let matrix = AdjacencyMatrix::from_adjacency_list(&graph);
+            let expected: Vec<(usize, usize, &u64)> = graph
+                .iter()
+                .enumerate()
+                .flat_map(|(u, edges)| edges.iter().map(move |(v, w)| (u, *v, w)))
+                .collect();
+            assert_eq!(matrix.edges().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "node 3 out of range for 3 nodes")]
+    fn in_degree_out_of_range() {
+        AdjacencyMatrix::<u64>::new(3).in_degree(3);
+    }
+
+    #[test]
+    fn undirected_edges() {
+        let triangle =
+            AdjacencyMatrix::from_undirected_edges(3, &[(0, 1, 4), (1, 2, 5), (2, 0, 6)]);
+        assert_eq!(
+            triangle.to_adjacency_list(),
+            vec![
+                vec![(1, 4), (2, 6)],
+                vec![(0, 4), (2, 5)],
+                vec![(0, 6), (1, 5)],
+            ]
+        );
+
+        let mut matrix = AdjacencyMatrix::new(2);
+        matrix.add_undirected_edge(1, 1, 3);
+        matrix.add_undirected_edge(0, 1, 2);
+        matrix.add_undirected_edge(1, 0, 7);
+        assert_eq!(
+            matrix.to_adjacency_list(),
+            vec![vec![(1, 7)], vec![(0, 7), (1, 3)]]
+        );
+        assert_eq!(matrix.remove_edge(1, 1), Some(3));
+        assert_eq!(matrix.remove_edge(1, 1), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn undirected_edge_out_of_range() {
+        AdjacencyMatrix::from_undirected_edges(2, &[(0, 1, 1), (2, 0, 1)]);
+    }
+
+    #[test]
+    fn empty_matrix() {
+        let matrix: AdjacencyMatrix<u64> = AdjacencyMatrix::new(0);
+        assert!(matrix.is_empty());
+        assert_eq!(matrix.to_adjacency_list(), Vec::<Vec<(usize, u64)>>::new());
+    }
+
+    #[test]
+    fn parallel_edges_keep_the_last() {
+        let matrix = AdjacencyMatrix::from_adjacency_list(&[vec![(1, 4), (1, 2)], vec![]]);
+        assert_eq!(matrix.edge(0, 1), Some(&2));
+    }
+
+    #[test]
+    fn round_trips() {
+        let mut rng = XorShift::new(127);
+        for n in 0..20 {
+            let graph = random_graph(&mut rng, n);
+            let matrix = AdjacencyMatrix::from_adjacency_list(&graph);
+            let list = matrix.to_adjacency_list();
+            assert_eq!(list, graph);
+            assert_eq!(AdjacencyMatrix::from_adjacency_list(&list), matrix);
+        }
+    }
+
+    #[test]
+    fn from_rows() {
+        let rows = vec![
+            vec![None, Some(3), None],
+            vec![None, None, Some(1)],
+            vec![Some(2), None, Some(5)],
+        ];
+        let matrix = AdjacencyMatrix::from_rows(rows.clone()).unwrap();
+        assert_eq!(matrix.edge(0, 1), Some(&3));
+        assert_eq!(matrix.edge(2, 2), Some(&5));
+        assert_eq!(matrix.edge(1, 0), None);
+        assert_eq!(matrix.to_rows(), rows);
+        assert_eq!(
+            from_adjacency_matrix(&rows),
+            Some(vec![vec![(1, 3)], vec![(2, 1)], vec![(0, 2), (2, 5)]])
+        );
+    }
+
+    #[test]
+    fn rejects_non_square_rows() {
+        let rows = vec![vec![None, Some(1)], vec![None]];
+        assert_eq!(AdjacencyMatrix::from_rows(rows.clone()), None);
+        assert_eq!(from_adjacency_matrix(&rows), None);
+        let rows: Vec<Vec<Option<u64>>> = vec![vec![None, None, None], vec![None, None, None]];
+        assert_eq!(from_adjacency_matrix(&rows), None);
+        let rows: Vec<Vec<Option<u64>>> = vec![vec![]];
+        assert_eq!(from_adjacency_matrix(&rows), None);
+        let rows: Vec<Vec<Option<u64>>> = vec![];
+        assert_eq!(from_adjacency_matrix(&rows), Some(vec![]));
+    }
+
+    #[test]
+    fn matrix_round_trips() {
+        let mut rng = XorShift::new(131);
+        for n in 0..20 {
+            let graph = random_graph(&mut rng, n);
+            let rows = to_adjacency_matrix(&graph);
+            assert_eq!(rows.len(), n);
+            assert!(rows.iter().all(|row| row.len() == n));
+            assert_eq!(from_adjacency_matrix(&rows), Some(graph));
+            let matrix = AdjacencyMatrix::from_rows(rows.clone()).unwrap();
+            assert_eq!(matrix.to_rows(), rows);
+        }
+    }
+}
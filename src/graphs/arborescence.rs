@@ -0,0 +1,192 @@
+//! Chu-Liu/Edmonds minimum spanning arborescence
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding `(v, w)` for
+//! every edge from `u` to `v` with weight `w`. An arborescence rooted at
+//! `root` is a set of edges with exactly one edge into every other node,
+//! such that every node can be reached from the root, a directed spanning
+//! tree.
+//!
+//! # Algorithm
+//!
+//! Every node but the root picks its cheapest incoming edge. If these edges
+//! contain no cycle, they are the answer. Otherwise some cycle `C` is
+//! contracted into a single node: all its picked edges are paid for, and an
+//! edge entering the cycle at `v` from outside gets cheaper by the weight of
+//! the picked edge into `v`, since choosing it means dropping that one. The
+//! optimum of the contracted graph plus the weight of the cycle is the
+//! optimum of the original. Every round takes O(m) and removes at least one
+//! node, for O(n * m) overall.
+
+/// Returns the weight of a minimum spanning arborescence rooted at `root`,
+/// or `None` if some node cannot be reached from `root`.
+pub fn min_arborescence(graph: &[Vec<(usize, u64)>], root: usize) -> Option<u64> {
+    let mut edges: Vec<(usize, usize, u64)> = graph
+        .iter()
+        .enumerate()
+        .flat_map(|(u, out)| out.iter().map(move |&(v, w)| (u, v, w)))
+        .filter(|&(u, v, _)| u != v)
+        .collect();
+    let mut n = graph.len();
+    let mut root = root;
+    let mut total = 0u64;
+
+    loop {
+        // the cheapest edge into every node
+        let mut cheapest: Vec<Option<u64>> = vec![None; n];
+        let mut parent = vec![usize::MAX; n];
+        for &(u, v, w) in &edges {
+            if v != root && (cheapest[v].is_none() || Some(w) < cheapest[v]) {
+                cheapest[v] = Some(w);
+                parent[v] = u;
+            }
+        }
+
+        // pay for all of them, or give up if some node has no incoming edge
+        for v in (0..n).filter(|&v| v != root) {
+            total = total
+                .checked_add(cheapest[v]?)
+                .expect("weight overflows u64");
+        }
+
+        // Follow the picked edges backwards from every node. A walk that
+        // comes back to a node it visited itself has found a new cycle.
+        let mut component = vec![usize::MAX; n];
+        let mut visited_by = vec![usize::MAX; n];
+        let mut count = 0;
+        for v in 0..n {
+            if v == root {
+                continue;
+            }
+            let mut x = v;
+            while x != root && visited_by[x] != v && component[x] == usize::MAX {
+                visited_by[x] = v;
+                x = parent[x];
+            }
+            if x != root && component[x] == usize::MAX {
+                let mut y = parent[x];
+                while y != x {
+                    component[y] = count;
+                    y = parent[y];
+                }
+                component[x] = count;
+                count += 1;
+            }
+        }
+        if count == 0 {
+            return Some(total);
+        }
+
+        // Contract every cycle, and give each other node its own component.
+        // Edges into the root are never picked and can be dropped.
+        for c in component.iter_mut() {
+            if *c == usize::MAX {
+                *c = count;
+                count += 1;
+            }
+        }
+        edges = edges
+            .iter()
+            .filter(|&&(u, v, _)| v != root && component[u] != component[v])
+            .map(|&(u, v, w)| (component[u], component[v], w - cheapest[v].unwrap()))
+            .collect();
+        n = count;
+        root = component[root];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::reachable_from;
+    use crate::random::XorShift;
+
+    // Tries every choice of one incoming edge per non-root node.
+    fn brute_force(graph: &[Vec<(usize, u64)>], root: usize) -> Option<u64> {
+        let n = graph.len();
+        let mut incoming = vec![Vec::new(); n];
+        for (u, edges) in graph.iter().enumerate() {
+            for &(v, w) in edges {
+                if v != root && u != v {
+                    incoming[v].push((u, w));
+                }
+            }
+        }
+        let mut best = None;
+        let mut choice = vec![0; n];
+        loop {
+            let mut tree = vec![Vec::new(); n];
+            let mut weight = 0;
+            let mut complete = true;
+            for v in (0..n).filter(|&v| v != root) {
+                match incoming[v].get(choice[v]) {
+                    Some(&(u, w)) => {
+                        tree[u].push(v);
+                        weight += w;
+                    }
+                    None => complete = false,
+                }
+            }
+            if complete
+                && reachable_from(&tree, root).iter().all(|&r| r)
+                && (best.is_none() || Some(weight) < best)
+            {
+                best = Some(weight);
+            }
+
+            // next choice, counting in mixed radix
+            let mut v = 0;
+            while v < n {
+                choice[v] += 1;
+                if choice[v] < incoming[v].len() {
+                    break;
+                }
+                choice[v] = 0;
+                v += 1;
+            }
+            if v == n {
+                return best;
+            }
+        }
+    }
+
+    #[test]
+    fn contracts_a_cycle() {
+        // The cheapest edges into 1, 2 and 3 form the cycle 1 → 2 → 3 → 1,
+        // so one of them has to be swapped for an edge from the root. Entering
+        // at 2 costs 10 - 1 more than the cycle edge, entering at 1 costs
+        // 5 - 2, which is cheaper.
+        let graph = vec![
+            vec![(1, 5), (2, 10), (3, 20)],
+            vec![(2, 1)],
+            vec![(3, 1)],
+            vec![(1, 2)],
+        ];
+        assert_eq!(min_arborescence(&graph, 0), Some(7));
+        assert_eq!(brute_force(&graph, 0), Some(7));
+    }
+
+    #[test]
+    fn unreachable_node() {
+        // 2 and 3 only point at each other
+        let graph = vec![vec![(1, 1)], vec![], vec![(3, 1)], vec![(2, 1)]];
+        assert_eq!(min_arborescence(&graph, 0), None);
+        assert_eq!(min_arborescence(&[vec![], vec![(0, 1)]], 0), None);
+        assert_eq!(min_arborescence(&[vec![]], 0), Some(0));
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = XorShift::new(73);
+        for _ in 0..100 {
+            let n = rng.gen_range(1, 6) as usize;
+            let mut graph = vec![Vec::new(); n];
+            for _ in 0..rng.gen_range(0, 3 * n as u64) {
+                let u = rng.gen_range(0, n as u64) as usize;
+                let v = rng.gen_range(0, n as u64) as usize;
+                graph[u].push((v, rng.gen_range(0, 10)));
+            }
+            let root = rng.gen_range(0, n as u64) as usize;
+            assert_eq!(min_arborescence(&graph, root), brute_force(&graph, root));
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Bidirectional Dijkstra
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding `(v, w)` for
+//! every edge from `u` to `v` with weight `w`.
+//!
+//! # Algorithm
+//!
+//! One Dijkstra runs forward from the source and another backward from the
+//! target, on the reversed edges, always advancing the one whose next node
+//! is closer. Whenever an edge is relaxed that reaches a node the other
+//! search has seen, the two halves make a path, and the shortest of those
+//! is kept. Once the next forward distance plus the next backward one is at
+//! least that length, no path through unsettled nodes can be shorter, and
+//! the search stops. On road-like graphs the two searches together visit
+//! far fewer nodes than a single one that runs all the way to the target.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Returns the length of the shortest path from `source` to `target`, or
+/// `None` if there is none. Panics if a distance does not fit in a `u64`.
+pub fn bidirectional_dijkstra(
+    graph: &[Vec<(usize, u64)>],
+    source: usize,
+    target: usize,
+) -> Option<u64> {
+    if source == target {
+        return Some(0);
+    }
+    let mut reverse = vec![Vec::new(); graph.len()];
+    for (u, edges) in graph.iter().enumerate() {
+        for &(v, w) in edges {
+            reverse[v].push((u, w));
+        }
+    }
+    let graphs = [graph, &reverse[..]];
+
+    let mut dist = [vec![None; graph.len()], vec![None; graph.len()]];
+    let mut done = [vec![false; graph.len()], vec![false; graph.len()]];
+    let mut heaps = [BinaryHeap::new(), BinaryHeap::new()];
+    dist[0][source] = Some(0);
+    dist[1][target] = Some(0);
+    heaps[0].push(Reverse((0u64, source)));
+    heaps[1].push(Reverse((0u64, target)));
+    let mut best: Option<u64> = None;
+
+    loop {
+        // drop entries of nodes that were settled since they were pushed
+        for side in 0..2 {
+            while let Some(&Reverse((_, u))) = heaps[side].peek() {
+                if !done[side][u] {
+                    break;
+                }
+                heaps[side].pop();
+            }
+        }
+        let (forward, backward) = match (heaps[0].peek(), heaps[1].peek()) {
+            (Some(&Reverse((f, _))), Some(&Reverse((b, _)))) => (f, b),
+            _ => break,
+        };
+        if matches!(best, Some(best) if forward.saturating_add(backward) >= best) {
+            break;
+        }
+
+        let side = if forward <= backward { 0 } else { 1 };
+        let Reverse((d, u)) = heaps[side].pop().unwrap();
+        done[side][u] = true;
+        for &(v, w) in &graphs[side][u] {
+            let candidate = d.checked_add(w).expect("distance overflows u64");
+            if dist[side][v].is_none() || Some(candidate) < dist[side][v] {
+                dist[side][v] = Some(candidate);
+                heaps[side].push(Reverse((candidate, v)));
+            }
+            if let Some(other) = dist[1 - side][v] {
+                let length = candidate
+                    .checked_add(other)
+                    .expect("distance overflows u64");
+                if best.is_none() || Some(length) < best {
+                    best = Some(length);
+                }
+            }
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::dijkstra;
+    use crate::random::XorShift;
+
+    #[test]
+    fn small_graph() {
+        // 0 → 1 (4), 0 → 2 (1), 2 → 1 (2), 1 → 3 (1), 2 → 3 (5), 4 alone
+        let graph = vec![
+            vec![(1, 4), (2, 1)],
+            vec![(3, 1)],
+            vec![(1, 2), (3, 5)],
+            vec![],
+            vec![],
+        ];
+        assert_eq!(bidirectional_dijkstra(&graph, 0, 3), Some(4));
+        assert_eq!(bidirectional_dijkstra(&graph, 0, 0), Some(0));
+        assert_eq!(bidirectional_dijkstra(&graph, 3, 0), None);
+        assert_eq!(bidirectional_dijkstra(&graph, 0, 4), None);
+    }
+
+    #[test]
+    fn matches_dijkstra() {
+        let mut rng = XorShift::new(71);
+        for _ in 0..200 {
+            let n = rng.gen_range(1, 40) as usize;
+            let mut graph = vec![Vec::new(); n];
+            for _ in 0..rng.gen_range(0, 4 * n as u64) {
+                let u = rng.gen_range(0, n as u64) as usize;
+                let v = rng.gen_range(0, n as u64) as usize;
+                graph[u].push((v, rng.gen_range(0, 20)));
+            }
+            let source = rng.gen_range(0, n as u64) as usize;
+            let target = rng.gen_range(0, n as u64) as usize;
+            assert_eq!(
+                bidirectional_dijkstra(&graph, source, target),
+                dijkstra(&graph, source)[target]
+            );
+        }
+    }
+}
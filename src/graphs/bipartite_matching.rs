@@ -0,0 +1,144 @@
+//! Hopcroft-Karp maximum bipartite matching
+//!
+//! # Algorithm
+//!
+//! Work in phases. Each phase starts with a breadth-first search from all
+//! free left nodes at once, which alternates between unmatched edges going
+//! right and matched edges coming back, and labels every left node with its
+//! distance. Then depth-first searches that only follow these layers find a
+//! maximal set of vertex-disjoint shortest augmenting paths, and flipping
+//! the edges along them grows the matching. The length of the shortest
+//! augmenting path grows with every phase, so there are only O(sqrt(V))
+//! phases, for O(E * sqrt(V)) overall.
+
+use std::collections::VecDeque;
+
+/// Returns a maximum matching of the bipartite graph with `left` nodes on
+/// one side, `right` nodes on the other and the given `(left, right)` edges.
+/// Entry `u` of the result is the right node matched with left node `u`.
+pub fn maximum_matching(left: usize, right: usize, edges: &[(usize, usize)]) -> Vec<Option<usize>> {
+    let mut adjacency = vec![Vec::new(); left];
+    for &(u, v) in edges {
+        assert!(u < left && v < right, "node out of bounds");
+        adjacency[u].push(v);
+    }
+
+    let mut matching = Matching {
+        adjacency,
+        pair_left: vec![None; left],
+        pair_right: vec![None; right],
+        dist: vec![usize::MAX; left],
+    };
+    while matching.bfs() {
+        for u in 0..left {
+            if matching.pair_left[u].is_none() {
+                matching.dfs(u);
+            }
+        }
+    }
+    matching.pair_left
+}
+
+struct Matching {
+    adjacency: Vec<Vec<usize>>,
+    pair_left: Vec<Option<usize>>,
+    pair_right: Vec<Option<usize>>,
+    // layer of each left node in the current phase
+    dist: Vec<usize>,
+}
+
+impl Matching {
+    // Label the left nodes by layer. Returns true iff an augmenting path
+    // exists, i.e. some edge leads to a free right node.
+    fn bfs(&mut self) -> bool {
+        let mut queue = VecDeque::new();
+        for (u, dist) in self.dist.iter_mut().enumerate() {
+            if self.pair_left[u].is_none() {
+                *dist = 0;
+                queue.push_back(u);
+            } else {
+                *dist = usize::MAX;
+            }
+        }
+
+        let mut found = false;
+        while let Some(u) = queue.pop_front() {
+            for &v in &self.adjacency[u] {
+                match self.pair_right[v] {
+                    None => found = true,
+                    Some(w) if self.dist[w] == usize::MAX => {
+                        self.dist[w] = self.dist[u] + 1;
+                        queue.push_back(w);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        found
+    }
+
+    // Look for an augmenting path from `u` along the layers and flip it.
+    fn dfs(&mut self, u: usize) -> bool {
+        for i in 0..self.adjacency[u].len() {
+            let v = self.adjacency[u][i];
+            let augments = match self.pair_right[v] {
+                None => true,
+                Some(w) => self.dist[w] == self.dist[u] + 1 && self.dfs(w),
+            };
+            if augments {
+                self.pair_left[u] = Some(v);
+                self.pair_right[v] = Some(u);
+                return true;
+            }
+        }
+        // Dead end: no need to look at `u` again in this phase.
+        self.dist[u] = usize::MAX;
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_matching(left: usize, right: usize, edges: &[(usize, usize)], size: usize) {
+        let matching = maximum_matching(left, right, edges);
+        assert_eq!(matching.len(), left);
+
+        let mut used = vec![false; right];
+        for (u, v) in matching.iter().enumerate() {
+            if let Some(v) = *v {
+                assert!(edges.contains(&(u, v)), "({}, {}) is not an edge", u, v);
+                assert!(!used[v], "right node {} matched twice", v);
+                used[v] = true;
+            }
+        }
+        assert_eq!(matching.iter().filter(|v| v.is_some()).count(), size);
+    }
+
+    #[test]
+    fn small_graph() {
+        let edges = [(0, 0), (0, 1), (1, 0), (2, 1), (2, 2), (3, 2), (3, 3)];
+        check_matching(4, 4, &edges, 4);
+    }
+
+    #[test]
+    fn needs_augmenting_through_matched_edges() {
+        // A greedy matching 0-0, 1-1 has to be rearranged to fit in 2.
+        let edges = [(0, 0), (0, 1), (1, 1), (1, 2), (2, 0)];
+        check_matching(3, 3, &edges, 3);
+    }
+
+    #[test]
+    fn imperfect() {
+        // Left nodes 0, 1 and 2 all compete for right node 0.
+        let edges = [(0, 0), (1, 0), (2, 0), (2, 1), (3, 2)];
+        check_matching(4, 3, &edges, 3);
+    }
+
+    #[test]
+    fn no_edges() {
+        assert_eq!(maximum_matching(2, 3, &[]), vec![None, None]);
+        assert!(maximum_matching(0, 0, &[]).is_empty());
+    }
+}
@@ -0,0 +1,129 @@
+//! Borůvka's minimum spanning tree
+//!
+//! The undirected graph on the nodes `0..n` is given as a list of edges
+//! `(u, v, w)`. If it is not connected, the result is a minimum spanning
+//! forest.
+//!
+//! # Algorithm
+//!
+//! Every node starts as a tree of its own. In each round, every tree picks
+//! the cheapest edge leaving it, and all picked edges are added at once.
+//! Ties are broken by the position of the edge in the list, so that all
+//! trees agree on one order of the edges; otherwise two trees could each
+//! pick a different edge of equal weight and close a cycle. Every round at
+//! least halves the number of trees that still have edges leaving them, so
+//! there are O(log n) rounds of O(m) each. The trees are tracked with a
+//! disjoint set.
+
+use crate::data_structures::DisjointSet;
+
+/// Returns the edges of a minimum spanning forest found by Borůvka's
+/// algorithm.
+pub fn boruvka_mst(n: usize, edges: &[(usize, usize, u64)]) -> Vec<(usize, usize, u64)> {
+    let mut sets = DisjointSet::new(n);
+    let mut tree = Vec::new();
+    loop {
+        // the cheapest edge leaving each tree, by index into `edges`
+        let mut cheapest: Vec<Option<usize>> = vec![None; n];
+        for (i, &(u, v, w)) in edges.iter().enumerate() {
+            let (ru, rv) = (sets.find(u), sets.find(v));
+            if ru == rv {
+                continue;
+            }
+            for &r in &[ru, rv] {
+                match cheapest[r] {
+                    Some(j) if (edges[j].2, j) < (w, i) => {}
+                    _ => cheapest[r] = Some(i),
+                }
+            }
+        }
+
+        let mut added = false;
+        for i in cheapest.into_iter().flatten() {
+            let (u, v, _) = edges[i];
+            if sets.union(u, v) {
+                tree.push(edges[i]);
+                added = true;
+            }
+        }
+        if !added {
+            return tree;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::kruskal_mst;
+    use crate::graphs::prim_mst;
+    use crate::graphs::total_weight;
+    use crate::random::XorShift;
+
+    fn is_forest(n: usize, tree: &[(usize, usize, u64)]) -> bool {
+        let mut sets = DisjointSet::new(n);
+        tree.iter().all(|&(u, v, _)| sets.union(u, v))
+    }
+
+    #[test]
+    fn small_graph() {
+        let edges = [
+            (0, 1, 4),
+            (0, 2, 1),
+            (1, 2, 2),
+            (1, 3, 5),
+            (2, 3, 8),
+            (3, 4, 3),
+        ];
+        let tree = boruvka_mst(5, &edges);
+        assert_eq!(tree.len(), 4);
+        assert_eq!(total_weight(&tree), 11);
+    }
+
+    #[test]
+    fn equal_weights() {
+        // In a complete graph with equal weights every tree has many
+        // cheapest edges to choose from.
+        let n = 8;
+        let mut edges = Vec::new();
+        for u in 0..n {
+            for v in u + 1..n {
+                edges.push((v, u, 1));
+            }
+        }
+        let tree = boruvka_mst(n, &edges);
+        assert_eq!(tree.len(), n - 1);
+        assert!(is_forest(n, &tree));
+    }
+
+    #[test]
+    fn forest() {
+        // 0 - 1 and 2 - 3 - 4, with 5 alone
+        let edges = [(0, 1, 7), (2, 3, 1), (3, 4, 2), (2, 4, 3)];
+        let tree = boruvka_mst(6, &edges);
+        assert_eq!(tree.len(), 3);
+        assert_eq!(total_weight(&tree), 10);
+        assert!(boruvka_mst(3, &[]).is_empty());
+    }
+
+    #[test]
+    fn matches_kruskal_and_prim() {
+        let mut rng = XorShift::new(97);
+        for _ in 0..50 {
+            let n = rng.gen_range(1, 40) as usize;
+            let edges: Vec<(usize, usize, u64)> = (0..rng.gen_range(0, 4 * n as u64))
+                .map(|_| {
+                    let u = rng.gen_range(0, n as u64) as usize;
+                    let v = rng.gen_range(0, n as u64) as usize;
+                    (u, v, rng.gen_range(0, 5))
+                })
+                .collect();
+            let tree = boruvka_mst(n, &edges);
+            assert!(is_forest(n, &tree));
+            let kruskal = kruskal_mst(n, &edges);
+            assert_eq!(tree.len(), kruskal.len());
+            assert_eq!(total_weight(&tree), total_weight(&kruskal));
+            assert_eq!(total_weight(&tree), total_weight(&prim_mst(n, &edges)));
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Centroid decomposition
+//!
+//! The tree is given as adjacency lists, `tree[u]` holding the neighbors of
+//! `u`, with every edge listed in both directions.
+//!
+//! # Algorithm
+//!
+//! A centroid of a tree is a node whose removal leaves no piece with more
+//! than half of the nodes. One is found by computing subtree sizes from any
+//! node and walking towards the child with more than half of the nodes
+//! while there is one. The centroid becomes the root of the decomposition,
+//! is removed, and each remaining piece is decomposed the same way, its
+//! centroid becoming a child of the removed one. Pieces at least halve at
+//! every level, so the decomposition has depth at most log2(n) + 1, and
+//! all levels together take O(n log n). The pieces are processed from an
+//! explicit stack, so deep trees cannot overflow the call stack.
+
+/// Returns the parent of every node in the centroid decomposition of
+/// `tree`, `None` for its root.
+pub fn centroid_decomposition(tree: &[Vec<usize>]) -> Vec<Option<usize>> {
+    let n = tree.len();
+    let mut parent = vec![None; n];
+    let mut removed = vec![false; n];
+    let mut size = vec![0; n];
+    let mut tree_parent = vec![usize::MAX; n];
+    // pieces still to decompose, as any node of the piece and the centroid
+    // the piece hangs from
+    let mut pieces = Vec::new();
+    if n > 0 {
+        pieces.push((0, None));
+    }
+
+    while let Some((start, above)) = pieces.pop() {
+        // breadth-first order of the piece, so children come after parents
+        let mut order = vec![start];
+        tree_parent[start] = usize::MAX;
+        let mut i = 0;
+        while i < order.len() {
+            let u = order[i];
+            for &v in &tree[u] {
+                if !removed[v] && v != tree_parent[u] {
+                    tree_parent[v] = u;
+                    order.push(v);
+                }
+            }
+            i += 1;
+        }
+        for &u in order.iter().rev() {
+            size[u] = 1 + tree[u]
+                .iter()
+                .filter(|&&v| !removed[v] && v != tree_parent[u])
+                .map(|&v| size[v])
+                .sum::<usize>();
+        }
+
+        let total = order.len();
+        let mut centroid = start;
+        while let Some(&heavy) = tree[centroid]
+            .iter()
+            .find(|&&v| !removed[v] && v != tree_parent[centroid] && 2 * size[v] > total)
+        {
+            centroid = heavy;
+        }
+
+        removed[centroid] = true;
+        parent[centroid] = above;
+        for &v in &tree[centroid] {
+            if !removed[v] {
+                pieces.push((v, Some(centroid)));
+            }
+        }
+    }
+    parent
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn path(n: usize) -> Vec<Vec<usize>> {
+        (0..n)
+            .map(|u| {
+                let mut next = Vec::new();
+                if u > 0 {
+                    next.push(u - 1);
+                }
+                if u + 1 < n {
+                    next.push(u + 1);
+                }
+                next
+            })
+            .collect()
+    }
+
+    // Checks that every piece is connected in `tree` and at most half the
+    // size of the piece it was cut from, and returns the depth.
+    fn check(tree: &[Vec<usize>], parent: &[Option<usize>]) -> usize {
+        let n = tree.len();
+        assert_eq!(parent.iter().filter(|p| p.is_none()).count(), 1);
+        let mut piece_size = vec![0; n];
+        let mut depth = 0;
+        for v in 0..n {
+            let mut level = 1;
+            let mut u = v;
+            piece_size[u] += 1;
+            while let Some(p) = parent[u] {
+                u = p;
+                piece_size[u] += 1;
+                level += 1;
+                assert!(level <= n, "cycle in the parent array");
+            }
+            depth = depth.max(level);
+        }
+        for v in 0..n {
+            if let Some(p) = parent[v] {
+                assert!(2 * piece_size[v] <= piece_size[p]);
+            }
+        }
+        for (u, edges) in tree.iter().enumerate() {
+            for &v in edges {
+                // One endpoint of every edge is an ancestor of the other in
+                // the decomposition, which is what makes the pieces
+                // connected.
+                let is_ancestor = |a: usize, mut b: usize| loop {
+                    if a == b {
+                        return true;
+                    }
+                    match parent[b] {
+                        Some(p) => b = p,
+                        None => return false,
+                    }
+                };
+                assert!(is_ancestor(u, v) || is_ancestor(v, u));
+            }
+        }
+        depth
+    }
+
+    #[test]
+    fn short_path() {
+        let tree = path(7);
+        let parent = centroid_decomposition(&tree);
+        assert_eq!(parent[3], None);
+        assert_eq!(parent[1], Some(3));
+        assert_eq!(parent[5], Some(3));
+        assert_eq!(check(&tree, &parent), 3);
+    }
+
+    #[test]
+    fn long_path() {
+        let tree = path(100_000);
+        let parent = centroid_decomposition(&tree);
+        // 2^17 > 100000
+        assert!(check(&tree, &parent) <= 17);
+    }
+
+    #[test]
+    fn random_trees() {
+        let mut rng = XorShift::new(83);
+        for _ in 0..30 {
+            let n = rng.gen_range(1, 200) as usize;
+            let mut tree = vec![Vec::new(); n];
+            for v in 1..n {
+                let u = rng.gen_range(0, v as u64) as usize;
+                tree[u].push(v);
+                tree[v].push(u);
+            }
+            let parent = centroid_decomposition(&tree);
+            let depth = check(&tree, &parent);
+            assert!(1 << (depth - 1) <= n);
+        }
+        assert!(centroid_decomposition(&[]).is_empty());
+        assert_eq!(centroid_decomposition(&[vec![]]), vec![None]);
+    }
+}
@@ -0,0 +1,214 @@
+//! Graph coloring
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding the nodes that
+//! `u` has an edge to. Edges are treated as undirected, and self-loops are
+//! ignored. A proper coloring gives adjacent nodes different colors, which
+//! are numbered from 0.
+//!
+//! # Greedy coloring
+//!
+//! Nodes are colored one after another, each with the smallest color that
+//! none of its already colored neighbors has. A node with `d` neighbors
+//! finds a free color among the first `d + 1`, so at most `max_degree + 1`
+//! colors are used. This takes O(n + m).
+//!
+//! The order matters a lot. Welsh-Powell colors the nodes by decreasing
+//! degree, so that the hardest nodes get the small colors. DSATUR picks the
+//! order as it goes: next is always the uncolored node whose neighbors
+//! already have the most different colors, its saturation, breaking ties
+//! by degree. On bipartite graphs, and on cycles, this finds an optimal
+//! coloring, while a bad fixed order can need arbitrarily many colors. This
+//! implementation scans for the next node, for O(n^2 + m).
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+/// Colors the nodes greedily in the order `0..n`.
+pub fn greedy_coloring(graph: &[Vec<usize>]) -> Vec<usize> {
+    let neighbors = undirected(graph);
+    let order: Vec<usize> = (0..graph.len()).collect();
+    color_in_order(&neighbors, &order)
+}
+
+/// Colors the nodes greedily by decreasing degree, the Welsh-Powell order.
+pub fn welsh_powell_coloring(graph: &[Vec<usize>]) -> Vec<usize> {
+    let neighbors = undirected(graph);
+    let mut order: Vec<usize> = (0..graph.len()).collect();
+    order.sort_by_key(|&u| Reverse(neighbors[u].len()));
+    color_in_order(&neighbors, &order)
+}
+
+/// Colors the nodes with DSATUR, always coloring a node with the most
+/// differently colored neighbors next.
+pub fn dsatur_coloring(graph: &[Vec<usize>]) -> Vec<usize> {
+    let n = graph.len();
+    let neighbors = undirected(graph);
+    let mut color = vec![usize::MAX; n];
+    let mut neighbor_colors = vec![HashSet::new(); n];
+    for _ in 0..n {
+        let u = (0..n)
+            .filter(|&u| color[u] == usize::MAX)
+            .max_by_key(|&u| (neighbor_colors[u].len(), neighbors[u].len(), Reverse(u)))
+            .unwrap();
+        color[u] = (0..).find(|c| !neighbor_colors[u].contains(c)).unwrap();
+        for &v in &neighbors[u] {
+            neighbor_colors[v].insert(color[u]);
+        }
+    }
+    color
+}
+
+// Symmetric neighbor lists without self-loops or duplicates.
+fn undirected(graph: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut neighbors = vec![Vec::new(); graph.len()];
+    for (u, edges) in graph.iter().enumerate() {
+        for &v in edges {
+            if u != v {
+                neighbors[u].push(v);
+                neighbors[v].push(u);
+            }
+        }
+    }
+    for list in &mut neighbors {
+        list.sort_unstable();
+        list.dedup();
+    }
+    neighbors
+}
+
+fn color_in_order(neighbors: &[Vec<usize>], order: &[usize]) -> Vec<usize> {
+    let mut color = vec![usize::MAX; neighbors.len()];
+    // `used[c] == u` marks color `c` as taken by a neighbor of `u`
+    let mut used = vec![usize::MAX; neighbors.len() + 1];
+    for &u in order {
+        for &v in &neighbors[u] {
+            if color[v] != usize::MAX {
+                used[color[v]] = u;
+            }
+        }
+        color[u] = (0..).find(|&c| used[c] != u).unwrap();
+    }
+    color
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn is_proper(graph: &[Vec<usize>], color: &[usize]) -> bool {
+        graph
+            .iter()
+            .enumerate()
+            .all(|(u, edges)| edges.iter().all(|&v| u == v || color[u] != color[v]))
+    }
+
+    fn color_count(color: &[usize]) -> usize {
+        color.iter().map(|&c| c + 1).max().unwrap_or(0)
+    }
+
+    #[test]
+    fn cycles() {
+        let even: Vec<Vec<usize>> = (0..6).map(|u| vec![(u + 1) % 6]).collect();
+        let color = greedy_coloring(&even);
+        assert!(is_proper(&even, &color));
+        assert_eq!(color_count(&color), 2);
+
+        let odd: Vec<Vec<usize>> = (0..5).map(|u| vec![(u + 1) % 5]).collect();
+        let color = greedy_coloring(&odd);
+        assert!(is_proper(&odd, &color));
+        assert_eq!(color_count(&color), 3);
+    }
+
+    #[test]
+    fn complete_graph() {
+        let k4: Vec<Vec<usize>> = (0..4).map(|u| (u + 1..4).collect()).collect();
+        let color = greedy_coloring(&k4);
+        assert!(is_proper(&k4, &color));
+        assert_eq!(color_count(&color), 4);
+    }
+
+    #[test]
+    fn random_graphs() {
+        let mut rng = XorShift::new(103);
+        for _ in 0..50 {
+            let n = rng.gen_range(1, 40) as usize;
+            let mut graph = vec![Vec::new(); n];
+            for _ in 0..rng.gen_range(0, 4 * n as u64) {
+                let u = rng.gen_range(0, n as u64) as usize;
+                let v = rng.gen_range(0, n as u64) as usize;
+                graph[u].push(v);
+            }
+            let max_degree = undirected(&graph).iter().map(Vec::len).max().unwrap();
+            let color = greedy_coloring(&graph);
+            assert!(is_proper(&graph, &color));
+            assert!(color_count(&color) <= max_degree + 1);
+        }
+        assert!(greedy_coloring(&[]).is_empty());
+    }
+
+    // K_{n,n} without a perfect matching, with u_i = 2i and v_i = 2i + 1
+    fn crown(n: usize) -> Vec<Vec<usize>> {
+        (0..2 * n)
+            .map(|u| {
+                if u % 2 == 0 {
+                    (0..n).filter(|&j| 2 * j != u).map(|j| 2 * j + 1).collect()
+                } else {
+                    Vec::new()
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn crown_graphs() {
+        for n in 3..8 {
+            let graph = crown(n);
+            let greedy = greedy_coloring(&graph);
+            let welsh_powell = welsh_powell_coloring(&graph);
+            let dsatur = dsatur_coloring(&graph);
+            for color in &[&greedy, &welsh_powell, &dsatur] {
+                assert!(is_proper(&graph, color));
+            }
+            // Interleaving the sides is the worst order for greedy, and all
+            // degrees are equal, so Welsh-Powell keeps it.
+            assert_eq!(color_count(&greedy), n);
+            assert_eq!(color_count(&welsh_powell), n);
+            assert_eq!(color_count(&dsatur), 2);
+        }
+    }
+
+    #[test]
+    fn dsatur_on_cycles() {
+        for n in 3..12 {
+            let graph: Vec<Vec<usize>> = (0..n).map(|u| vec![(u + 1) % n]).collect();
+            let color = dsatur_coloring(&graph);
+            assert!(is_proper(&graph, &color));
+            assert_eq!(color_count(&color), if n % 2 == 0 { 2 } else { 3 });
+        }
+    }
+
+    #[test]
+    fn dsatur_on_bipartite_graphs() {
+        let mut rng = XorShift::new(107);
+        for _ in 0..50 {
+            let n = rng.gen_range(2, 40) as usize;
+            let side: Vec<bool> = (0..n).map(|_| rng.gen_range(0, 2) == 1).collect();
+            let mut graph = vec![Vec::new(); n];
+            for _ in 0..rng.gen_range(0, 4 * n as u64) {
+                let u = rng.gen_range(0, n as u64) as usize;
+                let v = rng.gen_range(0, n as u64) as usize;
+                if side[u] != side[v] {
+                    graph[u].push(v);
+                }
+            }
+            let has_edges = graph.iter().any(|edges| !edges.is_empty());
+            let color = dsatur_coloring(&graph);
+            assert!(is_proper(&graph, &color));
+            assert_eq!(color_count(&color), if has_edges { 2 } else { 1 });
+
+            let color = welsh_powell_coloring(&graph);
+            assert!(is_proper(&graph, &color));
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Weakly connected components
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding the nodes that
+//! `u` has an edge to.
+//!
+//! # Algorithm
+//!
+//! Two nodes are in the same weak component if they are connected when edge
+//! directions are ignored, so every edge `u -> v` simply unions `u` and `v`
+//! in a disjoint set. Afterwards the set representatives are renumbered in
+//! the order they are first met. This takes O((n + m) α(n)).
+
+use crate::data_structures::DisjointSet;
+
+/// Returns the weak component of every node. Components are numbered from
+/// 0 in order of their smallest node.
+pub fn weakly_connected_components(graph: &[Vec<usize>]) -> Vec<usize> {
+    let n = graph.len();
+    let mut sets = DisjointSet::new(n);
+    for (u, edges) in graph.iter().enumerate() {
+        for &v in edges {
+            sets.union(u, v);
+        }
+    }
+
+    let mut id = vec![usize::MAX; n];
+    let mut count = 0;
+    (0..n)
+        .map(|u| {
+            let root = sets.find(u);
+            if id[root] == usize::MAX {
+                id[root] = count;
+                count += 1;
+            }
+            id[root]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::tarjan_scc;
+
+    #[test]
+    fn ignores_direction() {
+        // 0 -> 1 -> 2 is a chain, 3 <-> 4 a cycle fed by 5, and 6 is alone.
+        let graph = vec![vec![1], vec![2], vec![], vec![4], vec![3], vec![4], vec![]];
+        assert_eq!(
+            weakly_connected_components(&graph),
+            vec![0, 0, 0, 1, 1, 1, 2]
+        );
+        // Only 3 and 4 share a strong component.
+        assert_eq!(tarjan_scc(&graph).len(), 6);
+    }
+
+    #[test]
+    fn edge_into_earlier_node() {
+        let graph = vec![vec![], vec![], vec![0], vec![1]];
+        assert_eq!(weakly_connected_components(&graph), vec![0, 1, 0, 1]);
+        assert!(weakly_connected_components(&[]).is_empty());
+    }
+}
@@ -0,0 +1,107 @@
+//! Longest paths in a directed acyclic graph
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding `(v, w)` for
+//! every edge from `u` to `v` with weight `w`. Weights may be negative.
+//!
+//! # Algorithm
+//!
+//! Longest paths are hard in general, but in a DAG every path follows a
+//! topological order, so relaxing the edges of each node in that order,
+//! keeping the larger distance, sees every path prefix before it is
+//! extended. The order comes from Kahn's algorithm: repeatedly take a node
+//! with no remaining incoming edges and remove its edges. If nodes are left
+//! that never get there, they lie on a cycle. This takes O(n + m), and is
+//! how the critical path of a schedule is found.
+
+/// Returns the length of the longest path from `source` to every node, or
+/// `None` for nodes that cannot be reached. Returns `None` if the graph has
+/// a cycle. Panics if a length does not fit in an `i64`.
+pub fn longest_path_dag(graph: &[Vec<(usize, i64)>], source: usize) -> Option<Vec<Option<i64>>> {
+    let n = graph.len();
+    let mut indegree = vec![0; n];
+    for edges in graph {
+        for &(v, _) in edges {
+            indegree[v] += 1;
+        }
+    }
+    let mut order: Vec<usize> = (0..n).filter(|&u| indegree[u] == 0).collect();
+    let mut i = 0;
+    while i < order.len() {
+        for &(v, _) in &graph[order[i]] {
+            indegree[v] -= 1;
+            if indegree[v] == 0 {
+                order.push(v);
+            }
+        }
+        i += 1;
+    }
+    if order.len() < n {
+        return None;
+    }
+
+    let mut dist: Vec<Option<i64>> = vec![None; n];
+    dist[source] = Some(0);
+    for &u in &order {
+        let du = match dist[u] {
+            Some(du) => du,
+            None => continue,
+        };
+        for &(v, w) in &graph[u] {
+            let candidate = du.checked_add(w).expect("path length overflows i64");
+            if dist[v].is_none() || Some(candidate) > dist[v] {
+                dist[v] = Some(candidate);
+            }
+        }
+    }
+    Some(dist)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_dag() {
+        // 0 → 1 (3), 0 → 2 (2), 1 → 3 (4), 2 → 3 (6), 3 → 4 (1), 5 → 4 (10)
+        let graph = vec![
+            vec![(1, 3), (2, 2)],
+            vec![(3, 4)],
+            vec![(3, 6)],
+            vec![(4, 1)],
+            vec![],
+            vec![(4, 10)],
+        ];
+        assert_eq!(
+            longest_path_dag(&graph, 0),
+            Some(vec![Some(0), Some(3), Some(2), Some(8), Some(9), None])
+        );
+    }
+
+    #[test]
+    fn negative_weights() {
+        // the longest way to 2 avoids the very negative direct edge
+        let graph = vec![vec![(1, -1), (2, -10)], vec![(2, -2)], vec![]];
+        assert_eq!(
+            longest_path_dag(&graph, 0),
+            Some(vec![Some(0), Some(-1), Some(-3)])
+        );
+    }
+
+    #[test]
+    fn cycle() {
+        // 1 → 2 → 1, not even reachable from 0
+        let graph = vec![vec![], vec![(2, 1)], vec![(1, 1)]];
+        assert_eq!(longest_path_dag(&graph, 0), None);
+        assert_eq!(longest_path_dag(&[vec![(0, 0)]], 0), None);
+    }
+
+    #[test]
+    fn long_chain() {
+        let n = 100_000;
+        let graph: Vec<Vec<(usize, i64)>> = (0..n)
+            .map(|u| if u + 1 < n { vec![(u + 1, 1)] } else { vec![] })
+            .collect();
+        let dist = longest_path_dag(&graph, 0).unwrap();
+        assert_eq!(dist[n - 1], Some(n as i64 - 1));
+    }
+}
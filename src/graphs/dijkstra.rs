@@ -0,0 +1,521 @@
+//! Dijkstra's shortest paths
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding `(v, w)` for
+//! every edge from `u` to `v` with weight `w`. Weights must not be negative.
+//! Any `Weight` type works: the unsigned integers, `i64`, and `f64` through
+//! `OrderedF64`.
+//!
+//! # Algorithm
+//!
+//! Every source starts at distance 0 in a min-heap of tentative distances.
+//! The node popped from the heap has its final distance, since all weights
+//! are non-negative and any other path to it would have to leave the heap
+//! at a distance at least as large. Its edges are then relaxed, pushing the
+//! improved distances. Stale heap entries, for nodes that were already
+//! settled, are skipped when popped. This takes O((n + m) log n).
+//!
+//! Seeding the heap with several sources at once gives the distance to the
+//! nearest of them in a single run, as if they were all joined to a virtual
+//! source by edges of weight 0.
+//!
+//! The heap is one of two `Frontier` backends that `dijkstra_with` accepts.
+//! The other is `PriorityQueue`, which keeps at most one entry per node and
+//! lowers it in place when a shorter path turns up, instead of pushing
+//! duplicates. It then never holds more than n entries, at the cost of
+//! maintaining the queue's position map. `dijkstra_decrease_key` uses it.
+//!
+//! `count_shortest_paths` also counts the shortest paths to every node. When
+//! a node is settled its count is final, and every edge out of it that lies
+//! on a shortest path passes the count on: it replaces the count of a node
+//! that just got a shorter distance, and adds to one with an equal distance.
+//!
+//! Distances are added with `checked_add`. A sum that overflows is longer
+//! than any distance that fits, so that relaxation is skipped; only if some
+//! node can be reached by such paths alone is its distance too large.
+
+use crate::data_structures::OrderedF64;
+use crate::data_structures::PriorityQueue;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// An edge weight that distances can be built from.
+pub trait Weight: Copy + Ord {
+    fn zero() -> Self;
+
+    /// Returns `None` if the sum cannot be represented.
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_integer_weight {
+    ($($kind: ty),*) => {
+        $(
+            impl Weight for $kind {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$kind>::checked_add(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_weight!(u32, u64, usize, i64);
+
+impl Weight for OrderedF64 {
+    fn zero() -> Self {
+        OrderedF64::new(0.0).unwrap()
+    }
+
+    /// Floats do not overflow, but `inf + -inf` is NaN.
+    fn checked_add(self, other: Self) -> Option<Self> {
+        OrderedF64::new(self.get() + other.get())
+    }
+}
+
+/// The tentative distances Dijkstra picks the next node from.
+pub trait Frontier<W>: Default {
+    /// Adds `node`, which has not been pushed before, at distance `dist`.
+    fn push(&mut self, node: usize, dist: W);
+
+    /// Lowers the distance of the pushed but not yet popped `node`.
+    fn decrease_key(&mut self, node: usize, dist: W);
+
+    /// Removes a node with the smallest distance. Nodes that were popped
+    /// before may come up again and are skipped.
+    fn pop_min(&mut self) -> Option<(usize, W)>;
+}
+
+/// A binary heap that implements `decrease_key` by pushing a second entry
+/// and leaving the old one behind.
+pub struct HeapFrontier<W> {
+    heap: BinaryHeap<Reverse<(W, usize)>>,
+}
+
+impl<W: Ord> Default for HeapFrontier<W> {
+    fn default() -> Self {
+        HeapFrontier {
+            heap: BinaryHeap::new(),
+        }
+    }
+}
+
+impl<W: Ord> Frontier<W> for HeapFrontier<W> {
+    fn push(&mut self, node: usize, dist: W) {
+        self.heap.push(Reverse((dist, node)));
+    }
+
+    fn decrease_key(&mut self, node: usize, dist: W) {
+        self.push(node, dist);
+    }
+
+    fn pop_min(&mut self) -> Option<(usize, W)> {
+        self.heap.pop().map(|Reverse((dist, node))| (node, dist))
+    }
+}
+
+impl<W: Ord> Frontier<W> for PriorityQueue<usize, W> {
+    fn push(&mut self, node: usize, dist: W) {
+        PriorityQueue::push(self, node, dist);
+    }
+
+    fn decrease_key(&mut self, node: usize, dist: W) {
+        self.decrease_priority(&node, dist);
+    }
+
+    fn pop_min(&mut self) -> Option<(usize, W)> {
+        PriorityQueue::pop_min(self)
+    }
+}
+
+/// Returns the shortest distance from `source` to every node, or `None` for
+/// nodes that cannot be reached.
+pub fn dijkstra<W: Weight>(graph: &[Vec<(usize, W)>], source: usize) -> Vec<Option<W>> {
+    dijkstra_multi(graph, &[source])
+}
+
+/// Returns the shortest distance from the nearest of `sources` to every
+/// node, or `None` for nodes that none of them reach. Panics if a distance
+/// does not fit in `W`.
+pub fn dijkstra_multi<W: Weight>(graph: &[Vec<(usize, W)>], sources: &[usize]) -> Vec<Option<W>> {
+    dijkstra_with::<HeapFrontier<W>, W>(graph, sources)
+}
+
+/// Like `dijkstra_multi`, but returns `None` instead of panicking if the
+/// shortest distance to some reachable node does not fit in `W`.
+pub fn checked_dijkstra_multi<W: Weight>(
+    graph: &[Vec<(usize, W)>],
+    sources: &[usize],
+) -> Option<Vec<Option<W>>> {
+    run::<HeapFrontier<W>, W>(graph, sources)
+}
+
+/// Dijkstra from `source` with a decrease-key priority queue. Returns the
+/// same distances as `dijkstra`.
+pub fn dijkstra_decrease_key<W: Weight>(
+    graph: &[Vec<(usize, W)>],
+    source: usize,
+) -> Vec<Option<W>> {
+    dijkstra_with::<PriorityQueue<usize, W>, W>(graph, &[source])
+}
+
+/// Returns the shortest distance from `source` to every node along paths
+/// that avoid the nodes marked in `blocked`. Blocked nodes get `None`, and
+/// so does everything if `source` itself is blocked.
+pub fn dijkstra_avoiding<W: Weight>(
+    graph: &[Vec<(usize, W)>],
+    source: usize,
+    blocked: &[bool],
+) -> Vec<Option<W>> {
+    assert_eq!(blocked.len(), graph.len(), "one flag per node");
+    let open: Vec<Vec<(usize, W)>> = graph
+        .iter()
+        .zip(blocked)
+        .map(|(edges, &b)| {
+            if b {
+                Vec::new()
+            } else {
+                edges
+                    .iter()
+                    .filter(|&&(v, _)| !blocked[v])
+                    .cloned()
+                    .collect()
+            }
+        })
+        .collect();
+    if blocked[source] {
+        dijkstra_multi(&open, &[])
+    } else {
+        dijkstra(&open, source)
+    }
+}
+
+/// Returns the shortest distance from `source` to every node, as
+/// `dijkstra` does, together with the number of different shortest paths
+/// to it. The count is 0 for nodes that cannot be reached, and `None` if it
+/// does not fit in a `u64`. Panics if an edge has weight 0, since a cycle
+/// of such edges would make for infinitely many shortest paths, or if a
+/// distance does not fit in `W`.
+pub fn count_shortest_paths<W: Weight>(
+    graph: &[Vec<(usize, W)>],
+    source: usize,
+) -> (Vec<Option<W>>, Vec<Option<u64>>) {
+    let mut dist = vec![None; graph.len()];
+    let mut count = vec![Some(0u64); graph.len()];
+    let mut done = vec![false; graph.len()];
+    let mut heap = HeapFrontier::default();
+    dist[source] = Some(W::zero());
+    count[source] = Some(1);
+    heap.push(source, W::zero());
+
+    while let Some((u, d)) = heap.pop_min() {
+        if done[u] {
+            continue;
+        }
+        done[u] = true;
+        for &(v, w) in &graph[u] {
+            assert!(w > W::zero(), "edge weights must be positive");
+            let candidate = d
+                .checked_add(w)
+                .expect("distance overflows the weight type");
+            if dist[v].is_none() || Some(candidate) < dist[v] {
+                dist[v] = Some(candidate);
+                count[v] = count[u];
+                heap.push(v, candidate);
+            } else if dist[v] == Some(candidate) {
+                count[v] = match (count[v], count[u]) {
+                    (Some(a), Some(b)) => a.checked_add(b),
+                    _ => None,
+                };
+            }
+        }
+    }
+    (dist, count)
+}
+
+/// `dijkstra_multi` with the frontier backend `F`.
+pub fn dijkstra_with<F, W>(graph: &[Vec<(usize, W)>], sources: &[usize]) -> Vec<Option<W>>
+where
+    F: Frontier<W>,
+    W: Weight,
+{
+    run::<F, W>(graph, sources).expect("distance overflows the weight type")
+}
+
+fn run<F, W>(graph: &[Vec<(usize, W)>], sources: &[usize]) -> Option<Vec<Option<W>>>
+where
+    F: Frontier<W>,
+    W: Weight,
+{
+    let mut dist = vec![None; graph.len()];
+    let mut done = vec![false; graph.len()];
+    let mut overflowed = vec![false; graph.len()];
+    let mut frontier = F::default();
+    for &s in sources {
+        if dist[s].is_none() {
+            dist[s] = Some(W::zero());
+            frontier.push(s, W::zero());
+        }
+    }
+
+    while let Some((u, d)) = frontier.pop_min() {
+        if done[u] {
+            continue;
+        }
+        done[u] = true;
+        for &(v, w) in &graph[u] {
+            let candidate = match d.checked_add(w) {
+                Some(candidate) => candidate,
+                None => {
+                    overflowed[v] = true;
+                    continue;
+                }
+            };
+            match dist[v] {
+                None => frontier.push(v, candidate),
+                Some(old) if candidate < old => frontier.decrease_key(v, candidate),
+                _ => continue,
+            }
+            dist[v] = Some(candidate);
+        }
+    }
+
+    if dist.iter().zip(&overflowed).any(|(d, &o)| o && d.is_none()) {
+        None
+    } else {
+        Some(dist)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn random_graph(
+        rng: &mut XorShift,
+        n: usize,
+        m: usize,
+        max_weight: u64,
+    ) -> Vec<Vec<(usize, u64)>> {
+        let mut graph = vec![Vec::new(); n];
+        for _ in 0..m {
+            let u = rng.gen_range(0, n as u64) as usize;
+            let v = rng.gen_range(0, n as u64) as usize;
+            graph[u].push((v, rng.gen_range(0, max_weight + 1)));
+        }
+        graph
+    }
+
+    fn example() -> Vec<Vec<(usize, u64)>> {
+        // 0 → 1 (4), 0 → 2 (1), 2 → 1 (2), 1 → 3 (1), 2 → 3 (5),
+        // 4 → 3 (1), 5 alone
+        vec![
+            vec![(1, 4), (2, 1)],
+            vec![(3, 1)],
+            vec![(1, 2), (3, 5)],
+            vec![],
+            vec![(3, 1)],
+            vec![],
+        ]
+    }
+
+    #[test]
+    fn single_source() {
+        assert_eq!(
+            dijkstra(&example(), 0),
+            vec![Some(0), Some(3), Some(1), Some(4), None, None]
+        );
+    }
+
+    #[test]
+    fn nearest_of_several_sources() {
+        let graph = example();
+        let sources = [0, 4];
+        let result = dijkstra_multi(&graph, &sources);
+        assert_eq!(
+            result,
+            vec![Some(0), Some(3), Some(1), Some(1), Some(0), None]
+        );
+
+        // every node gets the minimum of its per-source distances
+        let single: Vec<_> = sources.iter().map(|&s| dijkstra(&graph, s)).collect();
+        for (v, &d) in result.iter().enumerate() {
+            assert_eq!(d, single.iter().filter_map(|dist| dist[v]).min());
+        }
+    }
+
+    #[test]
+    fn no_sources() {
+        assert_eq!(dijkstra_multi(&example(), &[]), vec![None; 6]);
+        assert!(dijkstra_multi::<u64>(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn overflow() {
+        let max = u64::MAX;
+        // 0 → 1 → 2 overflows, but 2 is also reached directly
+        let graph = vec![vec![(1, max), (2, 5)], vec![(2, max)], vec![]];
+        assert_eq!(
+            checked_dijkstra_multi(&graph, &[0]),
+            Some(vec![Some(0), Some(max), Some(5)])
+        );
+
+        // 2 can only be reached through a sum that does not fit
+        let graph = vec![vec![(1, max)], vec![(2, 1)], vec![]];
+        assert_eq!(checked_dijkstra_multi(&graph, &[0]), None);
+        assert_eq!(
+            checked_dijkstra_multi(&graph, &[1]),
+            Some(vec![None, Some(0), Some(1)])
+        );
+    }
+
+    #[test]
+    fn small_integer_weights() {
+        let max = u32::MAX;
+        let graph = vec![vec![(1, max - 1)], vec![(2, 1), (3, 2)], vec![], vec![]];
+        assert_eq!(checked_dijkstra_multi(&graph, &[0]), None);
+        assert_eq!(
+            checked_dijkstra_multi(&graph, &[1]),
+            Some(vec![None, Some(0), Some(1), Some(2)])
+        );
+    }
+
+    #[test]
+    fn float_weights() {
+        let f = |x: f64| OrderedF64::new(x).unwrap();
+        // 0 → 1 (2.5), 0 → 2 (1.0), 2 → 1 (0.75), 1 → 3 (0.1)
+        let graph = vec![
+            vec![(1, f(2.5)), (2, f(1.0))],
+            vec![(3, f(0.1))],
+            vec![(1, f(0.75))],
+            vec![],
+        ];
+        let dist: Vec<Option<f64>> = dijkstra(&graph, 0)
+            .into_iter()
+            .map(|d| d.map(OrderedF64::get))
+            .collect();
+        assert_eq!(dist, vec![Some(0.0), Some(1.75), Some(1.0), Some(1.85)]);
+        assert_eq!(OrderedF64::new(f64::NAN), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "distance overflows the weight type")]
+    fn overflow_panics() {
+        dijkstra(&[vec![(1, u64::MAX)], vec![(2, 1)], vec![]], 0);
+    }
+
+    #[test]
+    fn decrease_key_matches_heap() {
+        assert_eq!(
+            dijkstra_decrease_key(&example(), 0),
+            dijkstra(&example(), 0)
+        );
+
+        let mut rng = XorShift::new(41);
+        for _ in 0..50 {
+            let n = rng.gen_range(1, 40) as usize;
+            let m = rng.gen_range(0, 4 * n as u64) as usize;
+            let graph = random_graph(&mut rng, n, m, 20);
+            let source = rng.gen_range(0, n as u64) as usize;
+            assert_eq!(
+                dijkstra_decrease_key(&graph, source),
+                dijkstra(&graph, source)
+            );
+        }
+    }
+
+    #[test]
+    fn backends_agree() {
+        let mut rng = XorShift::new(43);
+        for _ in 0..50 {
+            let n = rng.gen_range(1, 40) as usize;
+            let m = rng.gen_range(0, 4 * n as u64) as usize;
+            let graph = random_graph(&mut rng, n, m, 20);
+            let sources: Vec<usize> = (0..rng.gen_range(0, 4))
+                .map(|_| rng.gen_range(0, n as u64) as usize)
+                .collect();
+            assert_eq!(
+                dijkstra_with::<HeapFrontier<u64>, u64>(&graph, &sources),
+                dijkstra_with::<PriorityQueue<usize, u64>, u64>(&graph, &sources)
+            );
+        }
+    }
+
+    #[test]
+    fn counts_on_a_grid() {
+        // Moving only right or down in a grid, every path to row r and
+        // column c is shortest, and there are binomial(r + c, r) of them.
+        let size = 35;
+        let mut graph = vec![Vec::new(); size * size];
+        for r in 0..size {
+            for c in 0..size {
+                if r + 1 < size {
+                    graph[r * size + c].push(((r + 1) * size + c, 1u64));
+                }
+                if c + 1 < size {
+                    graph[r * size + c].push((r * size + c + 1, 1));
+                }
+            }
+        }
+        let (dist, count) = count_shortest_paths(&graph, 0);
+        assert_eq!(dist[2 * size + 3], Some(5));
+        assert_eq!(count[2 * size + 3], Some(10));
+        assert_eq!(count[size - 1], Some(1));
+        assert_eq!(count[10 * size + 10], Some(184_756));
+        assert_eq!(count[33 * size + 33], Some(7_219_428_434_016_265_740));
+        // binomial(68, 34) does not fit
+        assert_eq!(count[34 * size + 34], None);
+    }
+
+    #[test]
+    fn counts_equal_cost_paths() {
+        let (dist, count) = count_shortest_paths(&example(), 0);
+        assert_eq!(dist, dijkstra(&example(), 0));
+        // 0 → 2 → 1 is the only shortest way to 1, 4 cannot be reached
+        assert_eq!(
+            count,
+            vec![Some(1), Some(1), Some(1), Some(1), Some(0), Some(0)]
+        );
+
+        // 0 → 1 → 3 and 0 → 2 → 3 both cost 2, and there are two edges 3 → 4
+        let graph = vec![
+            vec![(1, 1u64), (2, 1), (3, 3)],
+            vec![(3, 1)],
+            vec![(3, 1)],
+            vec![(4, 2), (4, 2)],
+            vec![],
+        ];
+        let (_, count) = count_shortest_paths(&graph, 0);
+        assert_eq!(count[3], Some(2));
+        assert_eq!(count[4], Some(4));
+    }
+
+    #[test]
+    fn avoiding_blocked_nodes() {
+        let graph = example();
+        let mut blocked = vec![false; 6];
+        assert_eq!(dijkstra_avoiding(&graph, 0, &blocked), dijkstra(&graph, 0));
+
+        // without 2 the way to 1 and 3 takes the direct edge 0 → 1
+        blocked[2] = true;
+        assert_eq!(
+            dijkstra_avoiding(&graph, 0, &blocked),
+            vec![Some(0), Some(4), None, Some(5), None, None]
+        );
+
+        // a blocked target cannot be reached, the rest is unaffected
+        let mut blocked = vec![false; 6];
+        blocked[3] = true;
+        assert_eq!(
+            dijkstra_avoiding(&graph, 0, &blocked),
+            vec![Some(0), Some(3), Some(1), None, None, None]
+        );
+
+        let mut blocked = vec![false; 6];
+        blocked[0] = true;
+        assert_eq!(dijkstra_avoiding(&graph, 0, &blocked), vec![None; 6]);
+    }
+}
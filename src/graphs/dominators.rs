@@ -0,0 +1,185 @@
+//! Dominator tree
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding the nodes that
+//! `u` has an edge to. A node `d` dominates `v` if every path from the root
+//! to `v` passes through `d`, and the immediate dominator of `v` is the
+//! dominator closest to it. Every node reachable from the root except the
+//! root itself has one, and these form a tree.
+//!
+//! # Algorithm
+//!
+//! This is the iterative algorithm of Cooper, Harvey and Kennedy. Nodes are
+//! numbered in postorder of a depth-first search from the root. Visiting
+//! them in reverse postorder, the immediate dominator of a node is the
+//! nearest common ancestor, in the dominator tree built so far, of all its
+//! predecessors that have one yet. The nearest common ancestor is found by
+//! walking up from both nodes, always moving the one with the smaller
+//! postorder number. Repeating this until nothing changes gives the
+//! dominator tree. It takes O(n + m) per pass, and for graphs from ordinary
+//! programs only a few passes.
+
+/// Returns the immediate dominator of every node with respect to `root`.
+/// The root is its own immediate dominator, and nodes that cannot be reached
+/// from it get `None`.
+pub fn immediate_dominators(graph: &[Vec<usize>], root: usize) -> Vec<Option<usize>> {
+    let n = graph.len();
+    // postorder of a depth-first search, each stack entry being a node and
+    // the number of its edges followed so far
+    let mut postorder = Vec::new();
+    let mut number = vec![usize::MAX; n];
+    let mut visited = vec![false; n];
+    let mut stack = vec![(root, 0)];
+    visited[root] = true;
+    while let Some(&mut (u, ref mut next)) = stack.last_mut() {
+        if let Some(&v) = graph[u].get(*next) {
+            *next += 1;
+            if !visited[v] {
+                visited[v] = true;
+                stack.push((v, 0));
+            }
+        } else {
+            number[u] = postorder.len();
+            postorder.push(u);
+            stack.pop();
+        }
+    }
+
+    let mut predecessors = vec![Vec::new(); n];
+    for &u in &postorder {
+        for &v in &graph[u] {
+            predecessors[v].push(u);
+        }
+    }
+
+    let mut idom = vec![None; n];
+    idom[root] = Some(root);
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &v in postorder.iter().rev().skip(1) {
+            let mut new_idom = None;
+            for &p in &predecessors[v] {
+                if idom[p].is_none() {
+                    continue;
+                }
+                new_idom = Some(match new_idom {
+                    None => p,
+                    Some(d) => intersect(&idom, &number, p, d),
+                });
+            }
+            if new_idom != idom[v] {
+                idom[v] = new_idom;
+                changed = true;
+            }
+        }
+    }
+    idom
+}
+
+fn intersect(idom: &[Option<usize>], number: &[usize], mut a: usize, mut b: usize) -> usize {
+    while a != b {
+        while number[a] < number[b] {
+            a = idom[a].unwrap();
+        }
+        while number[b] < number[a] {
+            b = idom[b].unwrap();
+        }
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::reachable_from;
+    use crate::random::XorShift;
+
+    // `d` dominates `v` iff `v` cannot be reached once `d` is removed. The
+    // immediate dominator is the strict dominator that all others dominate,
+    // i.e. the one with the most dominators itself.
+    fn brute_force(graph: &[Vec<usize>], root: usize) -> Vec<Option<usize>> {
+        let n = graph.len();
+        let reachable = reachable_from(graph, root);
+        let mut dominators = vec![Vec::new(); n];
+        for d in 0..n {
+            let without: Vec<Vec<usize>> = graph
+                .iter()
+                .enumerate()
+                .map(|(u, edges)| if u == d { Vec::new() } else { edges.clone() })
+                .collect();
+            let still = if d == root {
+                vec![false; n]
+            } else {
+                reachable_from(&without, root)
+            };
+            for v in 0..n {
+                if reachable[v] && !still[v] && v != d {
+                    dominators[v].push(d);
+                }
+            }
+        }
+        (0..n)
+            .map(|v| {
+                if v == root {
+                    Some(root)
+                } else if !reachable[v] {
+                    None
+                } else {
+                    dominators[v]
+                        .iter()
+                        .copied()
+                        .max_by_key(|&d| dominators[d].len())
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn diamond() {
+        // 0 → 1 → 3, 0 → 2 → 3, 3 → 4
+        let graph = vec![vec![1, 2], vec![3], vec![3], vec![4], vec![]];
+        assert_eq!(
+            immediate_dominators(&graph, 0),
+            vec![Some(0), Some(0), Some(0), Some(0), Some(3)]
+        );
+    }
+
+    #[test]
+    fn loop_with_exit() {
+        // 0 → 1 → 2 → 3 → 1, with 2 → 4 leaving the loop and 3 → 5 → 4
+        let graph = vec![vec![1], vec![2], vec![3, 4], vec![1, 5], vec![], vec![4]];
+        assert_eq!(
+            immediate_dominators(&graph, 0),
+            vec![Some(0), Some(0), Some(1), Some(2), Some(2), Some(3)]
+        );
+    }
+
+    #[test]
+    fn unreachable_nodes() {
+        // 2 → 1 does not make 2 a predecessor that matters
+        let graph = vec![vec![1], vec![], vec![1, 3], vec![]];
+        assert_eq!(
+            immediate_dominators(&graph, 0),
+            vec![Some(0), Some(0), None, None]
+        );
+        assert_eq!(
+            immediate_dominators(&graph, 2),
+            vec![None, Some(2), Some(2), Some(2)]
+        );
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = XorShift::new(79);
+        for _ in 0..100 {
+            let n = rng.gen_range(1, 12) as usize;
+            let mut graph = vec![Vec::new(); n];
+            for _ in 0..rng.gen_range(0, 3 * n as u64) {
+                let u = rng.gen_range(0, n as u64) as usize;
+                let v = rng.gen_range(0, n as u64) as usize;
+                graph[u].push(v);
+            }
+            assert_eq!(immediate_dominators(&graph, 0), brute_force(&graph, 0));
+        }
+    }
+}
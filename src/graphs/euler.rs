@@ -0,0 +1,132 @@
+//! Eulerian paths and circuits
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding the nodes that
+//! `u` has an edge to. An Eulerian path uses every edge exactly once.
+//!
+//! # Algorithm
+//!
+//! A directed graph has an Eulerian path iff all its edges are connected,
+//! ignoring direction, and all nodes have as many incoming as outgoing
+//! edges, in which case the path is a circuit and can start anywhere, or
+//! all but two do: the start with one outgoing edge more, and the end with
+//! one incoming edge more.
+//!
+//! Hierholzer's algorithm walks from the start along unused edges until it
+//! gets stuck, which can only happen at the end. Backing up, every node
+//! that still has unused edges starts a detour, a closed walk that is
+//! spliced into the path there. With a stack of the current walk and each
+//! node's next unused edge this takes O(n + m). If the edges were not all
+//! connected, some are left over, which is how connectivity is checked.
+
+/// Returns the nodes of a path that uses every edge of `graph` exactly once,
+/// starting and ending at the same node if possible, or `None` if there is
+/// no such path. A graph without edges has the empty path.
+pub fn eulerian_path(graph: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let n = graph.len();
+    let m: usize = graph.iter().map(Vec::len).sum();
+    if m == 0 {
+        return Some(Vec::new());
+    }
+
+    let mut balance = vec![0i64; n];
+    for (u, edges) in graph.iter().enumerate() {
+        balance[u] += edges.len() as i64;
+        for &v in edges {
+            balance[v] -= 1;
+        }
+    }
+    let starts: Vec<usize> = (0..n).filter(|&u| balance[u] == 1).collect();
+    let unbalanced = balance.iter().filter(|&&b| b != 0).count();
+    let start = match (unbalanced, starts.len()) {
+        (0, _) => (0..n).find(|&u| !graph[u].is_empty()).unwrap(),
+        (2, 1) => starts[0],
+        _ => return None,
+    };
+
+    let mut next = vec![0; n];
+    let mut stack = vec![start];
+    let mut path = Vec::with_capacity(m + 1);
+    while let Some(&u) = stack.last() {
+        if next[u] < graph[u].len() {
+            stack.push(graph[u][next[u]]);
+            next[u] += 1;
+        } else {
+            path.push(u);
+            stack.pop();
+        }
+    }
+
+    if path.len() != m + 1 {
+        return None;
+    }
+    path.reverse();
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Checks that `path` walks along edges and uses each exactly once.
+    fn check(graph: &[Vec<usize>], path: &[usize]) {
+        let mut unused: Vec<Vec<usize>> = graph.to_vec();
+        for step in path.windows(2) {
+            let i = unused[step[0]]
+                .iter()
+                .position(|&v| v == step[1])
+                .expect("edge missing or used twice");
+            unused[step[0]].swap_remove(i);
+        }
+        assert!(unused.iter().all(Vec::is_empty));
+    }
+
+    #[test]
+    fn circuit() {
+        // two triangles sharing node 0, and a loop at 1
+        let graph = vec![vec![1, 3], vec![2, 1], vec![0], vec![4], vec![0]];
+        let path = eulerian_path(&graph).unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!(path.first(), path.last());
+        check(&graph, &path);
+    }
+
+    #[test]
+    fn open_path() {
+        // 0 → 1 → 2 → 0 → 3 with a parallel edge 1 → 2 and 2 → 1 back
+        let graph = vec![vec![1, 3], vec![2, 2], vec![0, 1], vec![]];
+        let path = eulerian_path(&graph).unwrap();
+        assert_eq!(path[0], 0);
+        assert_eq!(*path.last().unwrap(), 3);
+        check(&graph, &path);
+    }
+
+    #[test]
+    fn unbalanced_degrees() {
+        // 0 has two more outgoing than incoming edges
+        assert_eq!(eulerian_path(&[vec![1, 2], vec![], vec![]]), None);
+        // 0 and 2 both have to be the start
+        assert_eq!(eulerian_path(&[vec![1], vec![], vec![1]]), None);
+    }
+
+    #[test]
+    fn disconnected_edges() {
+        // two separate cycles, both balanced
+        let graph = vec![vec![1], vec![0], vec![3], vec![2]];
+        assert_eq!(eulerian_path(&graph), None);
+    }
+
+    #[test]
+    fn no_edges() {
+        assert_eq!(eulerian_path(&[]), Some(vec![]));
+        assert_eq!(eulerian_path(&[vec![], vec![]]), Some(vec![]));
+        assert_eq!(eulerian_path(&[vec![0]]), Some(vec![0, 0]));
+    }
+
+    #[test]
+    fn long_cycle() {
+        let n = 200_000;
+        let graph: Vec<Vec<usize>> = (0..n).map(|u| vec![(u + 1) % n]).collect();
+        let path = eulerian_path(&graph).unwrap();
+        check(&graph, &path);
+    }
+}
@@ -0,0 +1,265 @@
+//! Reading and writing graphs
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding `(v, w)` for
+//! every edge from `u` to `v` with weight `w`.
+//!
+//! # DOT
+//!
+//! `to_dot` writes the graph in the DOT language of Graphviz, so that it can
+//! be drawn with `dot -Tpng`. Every node gets its own line, so that nodes
+//! without edges show up too, followed by one line per edge with the weight
+//! as its label. Labels are quoted, with `"` and `\` escaped, so any
+//! `Display` weight is safe to write.
+//!
+//! # Edge lists
+//!
+//! The edge list format is plain text. The first line holds the number of
+//! nodes `n`, and every other line one edge as `u v w`, separated by
+//! whitespace, with `u` and `v` in `0..n`. Blank lines and lines starting
+//! with `#` are skipped. `parse_edge_list` reads it back, and reports the
+//! first bad line by its number, counted from 1.
+
+use std::error::Error;
+use std::fmt::{self, Display, Write};
+use std::str::FromStr;
+
+/// Returns the graph as a DOT `digraph`, with edge weights as labels.
+pub fn to_dot<W: Display>(graph: &[Vec<(usize, W)>]) -> String {
+    let mut dot = String::from("digraph {\n");
+    for u in 0..graph.len() {
+        writeln!(dot, "    {};", u).unwrap();
+    }
+    for (u, edges) in graph.iter().enumerate() {
+        for (v, w) in edges {
+            let label = escape(&w.to_string());
+            writeln!(dot, "    {} -> {} [label=\"{}\"];", u, v, label).unwrap();
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Returns the graph in the edge list format read by `parse_edge_list`.
+pub fn to_edge_list<W: Display>(graph: &[Vec<(usize, W)>]) -> String {
+    let mut text = format!("{}\n", graph.len());
+    for (u, edges) in graph.iter().enumerate() {
+        for (v, w) in edges {
+            writeln!(text, "{} {} {}", u, v, w).unwrap();
+        }
+    }
+    text
+}
+
+/// What is wrong with a line of an edge list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input has no node count line.
+    MissingNodeCount,
+    /// The node count is not a non-negative integer.
+    InvalidNodeCount,
+    /// An edge line does not have exactly three fields.
+    WrongFieldCount(usize),
+    /// An endpoint is not a non-negative integer.
+    InvalidNode,
+    /// An endpoint is not below the node count.
+    NodeOutOfRange(usize),
+    /// The weight does not parse as the weight type.
+    InvalidWeight,
+}
+
+/// An error in an edge list, with the number of the line it is on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match self.kind {
+            ParseErrorKind::MissingNodeCount => write!(f, "missing node count"),
+            ParseErrorKind::InvalidNodeCount => write!(f, "invalid node count"),
+            ParseErrorKind::WrongFieldCount(count) => {
+                write!(f, "expected 3 fields, found {}", count)
+            }
+            ParseErrorKind::InvalidNode => write!(f, "invalid node"),
+            ParseErrorKind::NodeOutOfRange(node) => write!(f, "node {} out of range", node),
+            ParseErrorKind::InvalidWeight => write!(f, "invalid weight"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a graph in the edge list format, see the module docs.
+pub fn parse_edge_list<W: FromStr>(input: &str) -> Result<Vec<Vec<(usize, W)>>, ParseError> {
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|&(_, line)| !line.is_empty() && !line.starts_with('#'));
+    let error = |line, kind| ParseError { line, kind };
+
+    let (line, header) = match lines.next() {
+        Some(first) => first,
+        None => return Err(error(0, ParseErrorKind::MissingNodeCount)),
+    };
+    let n: usize = header
+        .parse()
+        .map_err(|_| error(line, ParseErrorKind::InvalidNodeCount))?;
+    let mut graph: Vec<Vec<(usize, W)>> = (0..n).map(|_| Vec::new()).collect();
+
+    for (line, text) in lines {
+        let fields: Vec<&str> = text.split_whitespace().collect();
+        if fields.len() != 3 {
+            return Err(error(line, ParseErrorKind::WrongFieldCount(fields.len())));
+        }
+        let node = |field: &str| -> Result<usize, ParseError> {
+            let node: usize = field
+                .parse()
+                .map_err(|_| error(line, ParseErrorKind::InvalidNode))?;
+            if node < n {
+                Ok(node)
+            } else {
+                Err(error(line, ParseErrorKind::NodeOutOfRange(node)))
+            }
+        };
+        let u = node(fields[0])?;
+        let v = node(fields[1])?;
+        let w = fields[2]
+            .parse()
+            .map_err(|_| error(line, ParseErrorKind::InvalidWeight))?;
+        graph[u].push((v, w));
+    }
+    Ok(graph)
+}
+
+// Escapes a string for use between the quotes of a DOT label.
+fn escape(label: &str) -> String {
+    let mut escaped = String::with_capacity(label.len());
+    for c in label.chars() {
+        match c {
+            '"' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn weighted_graph() {
+        let graph = vec![vec![(1, 7u64), (2, 9)], vec![(2, 10)], vec![], vec![]];
+        assert_eq!(
+            to_dot(&graph),
+            "digraph {\n\
+             \x20   0;\n\
+             \x20   1;\n\
+             \x20   2;\n\
+             \x20   3;\n\
+             \x20   0 -> 1 [label=\"7\"];\n\
+             \x20   0 -> 2 [label=\"9\"];\n\
+             \x20   1 -> 2 [label=\"10\"];\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn empty_graph() {
+        let graph: Vec<Vec<(usize, u64)>> = Vec::new();
+        assert_eq!(to_dot(&graph), "digraph {\n}\n");
+    }
+
+    #[test]
+    fn labels_are_escaped() {
+        let graph = vec![vec![(0, "say \"hi\""), (1, "a\\b\nc")], vec![]];
+        assert_eq!(
+            to_dot(&graph),
+            "digraph {\n\
+             \x20   0;\n\
+             \x20   1;\n\
+             \x20   0 -> 0 [label=\"say \\\"hi\\\"\"];\n\
+             \x20   0 -> 1 [label=\"a\\\\b\\nc\"];\n\
+             }\n"
+        );
+    }
+
+    fn parse_error(input: &str) -> ParseError {
+        parse_edge_list::<u64>(input).unwrap_err()
+    }
+
+    #[test]
+    fn parses_edge_list() {
+        let input = "# a small graph\n4\n0 1 7\n\n  0 2 9 \n1\t2\t10\n";
+        let graph: Vec<Vec<(usize, u64)>> = parse_edge_list(input).unwrap();
+        assert_eq!(
+            graph,
+            vec![vec![(1, 7), (2, 9)], vec![(2, 10)], vec![], vec![]]
+        );
+        let graph: Vec<Vec<(usize, i64)>> = parse_edge_list("2\n1 0 -3").unwrap();
+        assert_eq!(graph, vec![vec![], vec![(0, -3)]]);
+    }
+
+    #[test]
+    fn malformed_lines() {
+        let line = |line, kind| ParseError { line, kind };
+        assert_eq!(parse_error(""), line(0, ParseErrorKind::MissingNodeCount));
+        assert_eq!(
+            parse_error("# only a comment"),
+            line(0, ParseErrorKind::MissingNodeCount)
+        );
+        assert_eq!(parse_error("-1"), line(1, ParseErrorKind::InvalidNodeCount));
+        assert_eq!(
+            parse_error("3\n0 1 2\n0 1"),
+            line(3, ParseErrorKind::WrongFieldCount(2))
+        );
+        assert_eq!(
+            parse_error("3\n\n0 1 2 3"),
+            line(3, ParseErrorKind::WrongFieldCount(4))
+        );
+        assert_eq!(
+            parse_error("3\nx 1 2"),
+            line(2, ParseErrorKind::InvalidNode)
+        );
+        assert_eq!(
+            parse_error("3\n0 1 -2"),
+            line(2, ParseErrorKind::InvalidWeight)
+        );
+        assert_eq!(
+            parse_error("3\n0 1 2.5"),
+            line(2, ParseErrorKind::InvalidWeight)
+        );
+        assert_eq!(
+            parse_error("3\n0 1 2\n# comment\n2 3 1"),
+            line(4, ParseErrorKind::NodeOutOfRange(3))
+        );
+        assert_eq!(
+            parse_error("3\n0 1 2\n# comment\n2 3 1").to_string(),
+            "line 4: node 3 out of range"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut rng = XorShift::new(109);
+        for _ in 0..20 {
+            let n = rng.gen_range(1, 20) as usize;
+            let mut graph = vec![Vec::new(); n];
+            for _ in 0..rng.gen_range(0, 3 * n as u64) {
+                let u = rng.gen_range(0, n as u64) as usize;
+                let v = rng.gen_range(0, n as u64) as usize;
+                graph[u].push((v, rng.gen_range(0, 1000)));
+            }
+            assert_eq!(parse_edge_list::<u64>(&to_edge_list(&graph)), Ok(graph));
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Johnson's all-pairs shortest paths
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding `(v, w)` for
+//! every edge from `u` to `v` with weight `w`. Weights may be negative.
+//!
+//! # Algorithm
+//!
+//! Bellman-Ford from a virtual source with a 0-weight edge to every node
+//! finds potentials `h`, the shortest distance to each node from anywhere.
+//! Since `h[v] <= h[u] + w` for every edge, the reweighted edge
+//! `w + h[u] - h[v]` is never negative, while every `s`-`t` path changes by
+//! the same `h[s] - h[t]`, so shortest paths stay shortest. Dijkstra from
+//! every node on the reweighted graph then gives all distances. This takes
+//! O(n * m) for Bellman-Ford and O(n * (n + m) log n) for the Dijkstras,
+//! which beats the O(n^3) of Floyd-Warshall on sparse graphs.
+
+use super::dijkstra;
+
+/// Returns `dist` with `dist[s][t]` the length of the shortest path from
+/// `s` to `t`, or `None` if `t` cannot be reached from `s`. Returns `None`
+/// if the graph has a negative cycle. Panics if a distance does not fit in
+/// an `i64`.
+pub fn johnson(graph: &[Vec<(usize, i64)>]) -> Option<Vec<Vec<Option<i64>>>> {
+    let h = potentials(graph)?;
+    let reweighted: Vec<Vec<(usize, i64)>> = graph
+        .iter()
+        .enumerate()
+        .map(|(u, edges)| {
+            edges
+                .iter()
+                .map(|&(v, w)| (v, add(add(w, h[u]), -h[v])))
+                .collect()
+        })
+        .collect();
+
+    let dist = (0..graph.len())
+        .map(|s| {
+            dijkstra(&reweighted, s)
+                .into_iter()
+                .enumerate()
+                .map(|(t, d)| d.map(|d| add(add(d, -h[s]), h[t])))
+                .collect()
+        })
+        .collect();
+    Some(dist)
+}
+
+// Bellman-Ford from the virtual source. With all `h` starting at 0 the
+// distances settle after at most n - 1 rounds, so a change in round n means
+// a negative cycle.
+fn potentials(graph: &[Vec<(usize, i64)>]) -> Option<Vec<i64>> {
+    let n = graph.len();
+    let mut h = vec![0; n];
+    for _ in 0..n {
+        let mut changed = false;
+        for (u, edges) in graph.iter().enumerate() {
+            for &(v, w) in edges {
+                let candidate = add(h[u], w);
+                if candidate < h[v] {
+                    h[v] = candidate;
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            return Some(h);
+        }
+    }
+    if n == 0 {
+        Some(h)
+    } else {
+        None
+    }
+}
+
+fn add(a: i64, b: i64) -> i64 {
+    a.checked_add(b).expect("distance overflows i64")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn floyd_warshall(graph: &[Vec<(usize, i64)>]) -> Vec<Vec<Option<i64>>> {
+        let n = graph.len();
+        let mut dist = vec![vec![None; n]; n];
+        for (u, edges) in graph.iter().enumerate() {
+            dist[u][u] = Some(0);
+            for &(v, w) in edges {
+                if dist[u][v].is_none() || Some(w) < dist[u][v] {
+                    dist[u][v] = Some(w);
+                }
+            }
+        }
+        for k in 0..n {
+            for u in 0..n {
+                for v in 0..n {
+                    if let (Some(a), Some(b)) = (dist[u][k], dist[k][v]) {
+                        if dist[u][v].is_none() || Some(a + b) < dist[u][v] {
+                            dist[u][v] = Some(a + b);
+                        }
+                    }
+                }
+            }
+        }
+        dist
+    }
+
+    #[test]
+    fn negative_edge() {
+        // 0 → 1 (4), 0 → 2 (5), 2 → 1 (-3), 1 → 3 (1)
+        let graph = vec![vec![(1, 4), (2, 5)], vec![(3, 1)], vec![(1, -3)], vec![]];
+        let dist = johnson(&graph).unwrap();
+        assert_eq!(dist[0], vec![Some(0), Some(2), Some(5), Some(3)]);
+        assert_eq!(dist[2], vec![None, Some(-3), Some(0), Some(-2)]);
+        assert_eq!(dist[3], vec![None, None, None, Some(0)]);
+    }
+
+    #[test]
+    fn negative_cycle() {
+        // 1 → 2 → 1 has weight -1, even though 0 cannot reach it
+        let graph = vec![vec![], vec![(2, 1)], vec![(1, -2)]];
+        assert_eq!(johnson(&graph), None);
+        assert_eq!(johnson(&[vec![(0, -1)]]), None);
+        assert_eq!(johnson(&[]), Some(vec![]));
+    }
+
+    #[test]
+    fn matches_floyd_warshall() {
+        let mut rng = XorShift::new(47);
+        for _ in 0..30 {
+            let n = rng.gen_range(1, 20) as usize;
+            // Edges w + p[u] - p[v] with w >= 0 are often negative, but every
+            // cycle keeps the non-negative sum of its w.
+            let p: Vec<i64> = (0..n).map(|_| rng.gen_range(0, 50) as i64).collect();
+            let mut graph = vec![Vec::new(); n];
+            for _ in 0..rng.gen_range(0, 4 * n as u64) {
+                let u = rng.gen_range(0, n as u64) as usize;
+                let v = rng.gen_range(0, n as u64) as usize;
+                let w = rng.gen_range(0, 20) as i64 + p[u] - p[v];
+                graph[u].push((v, w));
+            }
+            assert_eq!(johnson(&graph), Some(floyd_warshall(&graph)));
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Shortest path with at most k edges
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding `(v, w)` for
+//! every edge from `u` to `v` with weight `w`. Weights may be negative.
+//!
+//! # Algorithm
+//!
+//! This is Bellman-Ford cut off after `k` rounds. After round `i`,
+//! `dist[v]` is the length of the shortest walk from the source to `v` with
+//! at most `i` edges. Each round relaxes from a copy of the previous
+//! distances, so an edge relaxed in this round cannot be extended by another
+//! edge in the same round, and the hop limit holds even with cycles. A walk
+//! whose length leaves the range of `i64` is skipped, as if the last edge
+//! were missing. This takes O(k * (n + m)).
+
+/// Returns the length of the shortest walk from `source` to `target` that
+/// uses at most `k` edges, or `None` if there is no such walk.
+pub fn shortest_path_k_edges(
+    graph: &[Vec<(usize, i64)>],
+    source: usize,
+    target: usize,
+    k: usize,
+) -> Option<i64> {
+    let mut dist: Vec<Option<i64>> = vec![None; graph.len()];
+    dist[source] = Some(0);
+    for _ in 0..k {
+        let mut next = dist.clone();
+        let mut changed = false;
+        for (u, edges) in graph.iter().enumerate() {
+            let du = match dist[u] {
+                Some(du) => du,
+                None => continue,
+            };
+            for &(v, w) in edges {
+                let candidate = match du.checked_add(w) {
+                    Some(candidate) => candidate,
+                    None => continue,
+                };
+                if next[v].is_none() || Some(candidate) < next[v] {
+                    next[v] = Some(candidate);
+                    changed = true;
+                }
+            }
+        }
+        dist = next;
+        if !changed {
+            break;
+        }
+    }
+    dist[target]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flights() -> Vec<Vec<(usize, i64)>> {
+        // 0 -> 3 directly costs 500, through 1 costs 200, through 1 and 2
+        // costs 150.
+        vec![
+            vec![(1, 100), (3, 500)],
+            vec![(2, 25), (3, 100)],
+            vec![(3, 25)],
+            vec![],
+        ]
+    }
+
+    #[test]
+    fn limit_is_respected() {
+        let graph = flights();
+        assert_eq!(shortest_path_k_edges(&graph, 0, 3, 0), None);
+        assert_eq!(shortest_path_k_edges(&graph, 0, 3, 1), Some(500));
+        assert_eq!(shortest_path_k_edges(&graph, 0, 3, 2), Some(200));
+        assert_eq!(shortest_path_k_edges(&graph, 0, 3, 3), Some(150));
+        assert_eq!(shortest_path_k_edges(&graph, 0, 3, 10), Some(150));
+        assert_eq!(shortest_path_k_edges(&graph, 0, 0, 0), Some(0));
+        assert_eq!(shortest_path_k_edges(&graph, 3, 0, 10), None);
+    }
+
+    #[test]
+    fn negative_cycle() {
+        // Every trip around 1 -> 2 -> 1 lowers the cost by 1, so the answer
+        // keeps shrinking with k instead of diverging within a round.
+        let graph = vec![vec![(1, 1)], vec![(2, -2), (3, 0)], vec![(1, 1)], vec![]];
+        assert_eq!(shortest_path_k_edges(&graph, 0, 3, 2), Some(1));
+        assert_eq!(shortest_path_k_edges(&graph, 0, 3, 3), Some(1));
+        assert_eq!(shortest_path_k_edges(&graph, 0, 3, 4), Some(0));
+        assert_eq!(shortest_path_k_edges(&graph, 0, 3, 6), Some(-1));
+    }
+
+    #[test]
+    fn overflowing_walks_are_skipped() {
+        let big = i64::MAX - 1;
+        // 0 -> 1 -> 2 would overflow, and wrapped around it would look like
+        // the shortest walk.
+        let graph = vec![
+            vec![(1, big), (3, 5)],
+            vec![(2, big)],
+            vec![],
+            vec![(2, big - 10)],
+        ];
+        assert_eq!(shortest_path_k_edges(&graph, 0, 1, 2), Some(big));
+        assert_eq!(shortest_path_k_edges(&graph, 0, 2, 2), Some(big - 5));
+        let graph = vec![vec![(1, big)], vec![(2, big)], vec![]];
+        assert_eq!(shortest_path_k_edges(&graph, 0, 2, 2), None);
+        let graph = vec![vec![(1, i64::MIN)], vec![(2, -1)], vec![]];
+        assert_eq!(shortest_path_k_edges(&graph, 0, 1, 2), Some(i64::MIN));
+        assert_eq!(shortest_path_k_edges(&graph, 0, 2, 2), None);
+    }
+}
@@ -0,0 +1,201 @@
+use super::dijkstra::{dijkstra, Weight};
+use super::scc::tarjan_scc;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A directed graph whose nodes are labels of any hashable type, such as
+/// city names, rather than the indices `0..n`.
+///
+/// Each label gets the next free index when it is first seen, and the edges
+/// are kept as adjacency lists over these indices, `adjacency()[u]` holding
+/// `(v, w)` for every edge from `u` to `v`. So every algorithm in `graphs`
+/// runs on `adjacency()` as it is, and `index` and `label` translate its
+/// input and output; `dijkstra` and `strongly_connected_components` do it
+/// for two of them.
+#[derive(Debug, Clone)]
+pub struct LabeledGraph<N: Hash + Eq, W> {
+    indices: HashMap<N, usize>,
+    labels: Vec<N>,
+    adjacency: Vec<Vec<(usize, W)>>,
+}
+
+impl<N: Hash + Eq + Clone, W> LabeledGraph<N, W> {
+    pub fn new() -> Self {
+        LabeledGraph {
+            indices: HashMap::new(),
+            labels: Vec::new(),
+            adjacency: Vec::new(),
+        }
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Adds a node without edges, if there is none with this label yet, and
+    /// returns its index.
+    pub fn add_node(&mut self, label: N) -> usize {
+        if let Some(&index) = self.indices.get(&label) {
+            return index;
+        }
+        let index = self.labels.len();
+        self.indices.insert(label.clone(), index);
+        self.labels.push(label);
+        self.adjacency.push(Vec::new());
+        index
+    }
+
+    /// Adds an edge from `from` to `to`, adding the nodes as needed.
+    pub fn add_edge(&mut self, from: N, to: N, w: W) {
+        let u = self.add_node(from);
+        let v = self.add_node(to);
+        self.adjacency[u].push((v, w));
+    }
+
+    /// Returns the index of the node with this label.
+    pub fn index(&self, label: &N) -> Option<usize> {
+        self.indices.get(label).cloned()
+    }
+
+    /// Returns the label of the node with this index.
+    pub fn label(&self, index: usize) -> &N {
+        &self.labels[index]
+    }
+
+    /// Returns the adjacency lists over the node indices.
+    pub fn adjacency(&self) -> &[Vec<(usize, W)>] {
+        &self.adjacency
+    }
+
+    /// Returns the length of the shortest path from `source` to every node
+    /// it reaches, or `None` if there is no node `source`.
+    pub fn dijkstra(&self, source: &N) -> Option<HashMap<N, W>>
+    where
+        W: Weight,
+    {
+        let source = self.index(source)?;
+        let distances = dijkstra(&self.adjacency, source);
+        Some(
+            distances
+                .into_iter()
+                .enumerate()
+                .filter_map(|(u, d)| d.map(|d| (self.labels[u].clone(), d)))
+                .collect(),
+        )
+    }
+
+    /// Returns the strongly connected components, in the order of
+    /// `tarjan_scc`.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<&N>> {
+        let graph: Vec<Vec<usize>> = self
+            .adjacency
+            .iter()
+            .map(|edges| edges.iter().map(|&(v, _)| v).collect())
+            .collect();
+        tarjan_scc(&graph)
+            .into_iter()
+            .map(|component| component.into_iter().map(|u| &self.labels[u]).collect())
+            .collect()
+    }
+}
+
+impl<N: Hash + Eq + Clone, W> Default for LabeledGraph<N, W> {
+    fn default() -> Self {
+        LabeledGraph::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cities() -> LabeledGraph<&'static str, u64> {
+        let mut graph = LabeledGraph::new();
+        for &(from, to, km) in &[
+            ("Berlin", "Hamburg", 289),
+            ("Hamburg", "Berlin", 289),
+            ("Berlin", "Leipzig", 190),
+            ("Leipzig", "Munich", 430),
+            ("Berlin", "Munich", 585),
+            ("Munich", "Leipzig", 430),
+            ("Hamburg", "Bremen", 125),
+        ] {
+            graph.add_edge(from, to, km);
+        }
+        graph.add_node("Cologne");
+        graph
+    }
+
+    #[test]
+    fn labels_and_indices() {
+        let graph = cities();
+        assert_eq!(graph.len(), 6);
+        assert_eq!(graph.index(&"Berlin"), Some(0));
+        assert_eq!(graph.index(&"Cologne"), Some(5));
+        assert_eq!(graph.index(&"Paris"), None);
+        for u in 0..graph.len() {
+            assert_eq!(graph.index(graph.label(u)), Some(u));
+        }
+        let mut graph = graph;
+        assert_eq!(graph.add_node("Munich"), 3);
+        assert_eq!(graph.len(), 6);
+    }
+
+    #[test]
+    fn shortest_distances() {
+        let graph = cities();
+        let from_berlin = graph.dijkstra(&"Berlin").unwrap();
+        assert_eq!(from_berlin.len(), 5);
+        assert_eq!(from_berlin["Berlin"], 0);
+        assert_eq!(from_berlin["Hamburg"], 289);
+        assert_eq!(from_berlin["Bremen"], 289 + 125);
+        assert_eq!(from_berlin["Leipzig"], 190);
+        assert_eq!(from_berlin["Munich"], 585);
+        assert!(!from_berlin.contains_key("Cologne"));
+
+        let from_munich = graph.dijkstra(&"Munich").unwrap();
+        assert_eq!(from_munich.len(), 2);
+        assert_eq!(from_munich["Leipzig"], 430);
+
+        assert_eq!(graph.dijkstra(&"Paris"), None);
+    }
+
+    #[test]
+    fn components() {
+        let graph = cities();
+        let mut components: Vec<Vec<&str>> = graph
+            .strongly_connected_components()
+            .into_iter()
+            .map(|component| {
+                let mut cities: Vec<&str> = component.into_iter().cloned().collect();
+                cities.sort_unstable();
+                cities
+            })
+            .collect();
+        components.sort();
+        assert_eq!(
+            components,
+            vec![
+                vec!["Berlin", "Hamburg"],
+                vec!["Bremen"],
+                vec!["Cologne"],
+                vec!["Leipzig", "Munich"],
+            ]
+        );
+    }
+
+    #[test]
+    fn string_labels() {
+        let mut graph: LabeledGraph<String, u64> = LabeledGraph::default();
+        graph.add_edge("a".to_string(), "b".to_string(), 2);
+        graph.add_edge("b".to_string(), "c".to_string(), 3);
+        let distances = graph.dijkstra(&"a".to_string()).unwrap();
+        assert_eq!(distances["c"], 5);
+        assert_eq!(graph.adjacency(), &[vec![(1, 2)], vec![(2, 3)], vec![]][..]);
+    }
+}
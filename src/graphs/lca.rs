@@ -0,0 +1,200 @@
+//! Lowest common ancestor by binary lifting
+//!
+//! # Algorithm
+//!
+//! For every node store its ancestors `1, 2, 4, ..., 2^k` levels up, which
+//! takes O(n log n) time and memory since the `2^(k+1)`-th ancestor is the
+//! `2^k`-th ancestor of the `2^k`-th ancestor. To answer a query, first lift
+//! the deeper node to the depth of the other one, jumping by the binary
+//! representation of the difference. Then lift both nodes together by
+//! decreasing powers of two as long as their ancestors differ; afterwards
+//! their parent is the lowest common ancestor. Each query takes O(log n).
+
+use std::collections::VecDeque;
+
+/// Answers lowest-common-ancestor and distance queries on a rooted tree.
+pub struct LcaBinaryLifting {
+    depth: Vec<usize>,
+    // up[k][u] is the 2^k-th ancestor of u, or the root if there is none
+    up: Vec<Vec<usize>>,
+}
+
+impl LcaBinaryLifting {
+    /// Build from a parent array, where `parents[u]` is the parent of node
+    /// `u` and exactly one node, the root, has no parent.
+    pub fn from_parents(parents: &[Option<usize>]) -> Self {
+        let n = parents.len();
+        let mut children = vec![Vec::new(); n];
+        let mut roots = Vec::new();
+        for (u, parent) in parents.iter().enumerate() {
+            match *parent {
+                Some(p) => children[p].push(u),
+                None => roots.push(u),
+            }
+        }
+        assert!(roots.len() == 1, "tree must have exactly one root");
+        let root = roots[0];
+
+        // Breadth-first from the root, so that every parent is processed
+        // before its children.
+        let mut depth = vec![usize::MAX; n];
+        let mut parent = vec![root; n];
+        depth[root] = 0;
+        let mut queue = VecDeque::new();
+        queue.push_back(root);
+        let mut visited = 0;
+        while let Some(u) = queue.pop_front() {
+            visited += 1;
+            for &v in &children[u] {
+                depth[v] = depth[u] + 1;
+                parent[v] = u;
+                queue.push_back(v);
+            }
+        }
+        assert!(visited == n, "parent array contains a cycle");
+
+        let levels = (usize::BITS - n.leading_zeros()).max(1) as usize;
+        let mut up = vec![parent];
+        for k in 1..levels {
+            let prev = &up[k - 1];
+            let next = (0..n).map(|u| prev[prev[u]]).collect();
+            up.push(next);
+        }
+        LcaBinaryLifting { depth, up }
+    }
+
+    /// Build from the adjacency lists of an undirected tree rooted at
+    /// `root`.
+    pub fn new(adjacency: &[Vec<usize>], root: usize) -> Self {
+        let mut parents = vec![None; adjacency.len()];
+        let mut visited = vec![false; adjacency.len()];
+        visited[root] = true;
+        let mut stack = vec![root];
+        while let Some(u) = stack.pop() {
+            for &v in &adjacency[u] {
+                if !visited[v] {
+                    visited[v] = true;
+                    parents[v] = Some(u);
+                    stack.push(v);
+                }
+            }
+        }
+        Self::from_parents(&parents)
+    }
+
+    /// Returns the number of edges between `u` and the root.
+    pub fn depth(&self, u: usize) -> usize {
+        self.depth[u]
+    }
+
+    /// Returns the deepest node that is an ancestor of both `u` and `v`. A
+    /// node counts as its own ancestor.
+    pub fn lca(&self, u: usize, v: usize) -> usize {
+        let (mut u, mut v) = if self.depth[u] >= self.depth[v] {
+            (u, v)
+        } else {
+            (v, u)
+        };
+
+        let diff = self.depth[u] - self.depth[v];
+        for (k, up) in self.up.iter().enumerate() {
+            if diff >> k & 1 == 1 {
+                u = up[u];
+            }
+        }
+        if u == v {
+            return u;
+        }
+
+        for up in self.up.iter().rev() {
+            if up[u] != up[v] {
+                u = up[u];
+                v = up[v];
+            }
+        }
+        self.up[0][u]
+    }
+
+    /// Returns the number of edges on the path between `u` and `v`.
+    pub fn distance(&self, u: usize, v: usize) -> usize {
+        self.depth[u] + self.depth[v] - 2 * self.depth[self.lca(u, v)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //         0
+    //       / | \
+    //      1  2  3
+    //     / \     \
+    //    4   5     6
+    //   /           \
+    //  7             8
+    fn sample() -> LcaBinaryLifting {
+        let parents = [
+            None,
+            Some(0),
+            Some(0),
+            Some(0),
+            Some(1),
+            Some(1),
+            Some(3),
+            Some(4),
+            Some(6),
+        ];
+        LcaBinaryLifting::from_parents(&parents)
+    }
+
+    #[test]
+    fn lca() {
+        let tree = sample();
+        assert_eq!(tree.lca(7, 5), 1);
+        assert_eq!(tree.lca(7, 8), 0);
+        assert_eq!(tree.lca(2, 6), 0);
+        assert_eq!(tree.lca(4, 5), 1);
+        assert_eq!(tree.lca(8, 8), 8);
+    }
+
+    #[test]
+    fn ancestor_of_the_other() {
+        let tree = sample();
+        assert_eq!(tree.lca(1, 7), 1);
+        assert_eq!(tree.lca(7, 1), 1);
+        assert_eq!(tree.lca(0, 8), 0);
+        assert_eq!(tree.lca(3, 8), 3);
+    }
+
+    #[test]
+    fn distance() {
+        let tree = sample();
+        assert_eq!(tree.distance(7, 8), 6);
+        assert_eq!(tree.distance(7, 5), 3);
+        assert_eq!(tree.distance(1, 7), 2);
+        assert_eq!(tree.distance(2, 2), 0);
+        assert_eq!(tree.depth(8), 3);
+    }
+
+    #[test]
+    fn long_path() {
+        // 0 - 1 - ... - 999, rooted at 500
+        let n = 1000;
+        let mut adjacency = vec![Vec::new(); n];
+        for u in 1..n {
+            adjacency[u - 1].push(u);
+            adjacency[u].push(u - 1);
+        }
+        let tree = LcaBinaryLifting::new(&adjacency, 500);
+        assert_eq!(tree.lca(0, 499), 499);
+        assert_eq!(tree.lca(0, 999), 500);
+        assert_eq!(tree.distance(0, 999), 999);
+        assert_eq!(tree.distance(600, 999), 399);
+    }
+
+    #[test]
+    #[should_panic]
+    fn two_roots() {
+        LcaBinaryLifting::from_parents(&[None, None]);
+    }
+}
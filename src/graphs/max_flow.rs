@@ -0,0 +1,343 @@
+//! Maximum flow
+//!
+//! # Edmonds-Karp
+//!
+//! Keep a residual graph holding, for every pair of nodes, how much more flow
+//! could still be pushed from one to the other. Repeatedly find a shortest
+//! source-sink path with positive residual capacity by breadth-first search,
+//! push as much flow along it as its narrowest edge allows, and update the
+//! residual capacities in both directions. Always augmenting along shortest
+//! paths bounds the number of rounds by O(V * E), for O(V * E^2) overall.
+//!
+//! # Dinic
+//!
+//! Instead of one path per breadth-first search, label every node with its
+//! BFS distance from the source (the level graph) and saturate all shortest
+//! paths at once with depth-first searches that only step from one level to
+//! the next. Each node remembers which of its edges it has already exhausted
+//! in the current phase (the current arc), so no edge is retried in vain.
+//! There are at most V phases, giving O(V^2 * E) in general and
+//! O(E * sqrt(V)) on unit-capacity networks.
+
+use std::cmp::min;
+use std::collections::VecDeque;
+
+/// Returns the value of a maximum flow from `source` to `sink`, where
+/// `capacity[u][v]` is the capacity of the edge from `u` to `v`.
+pub fn max_flow(capacity: &[Vec<u64>], source: usize, sink: usize) -> u64 {
+    edmonds_karp(capacity, source, sink).0
+}
+
+/// Like `max_flow`, but also returns one maximum flow: `flow[u][v]` is the
+/// amount sent along the edge from `u` to `v`.
+pub fn edmonds_karp(capacity: &[Vec<u64>], source: usize, sink: usize) -> (u64, Vec<Vec<u64>>) {
+    let n = capacity.len();
+    assert!(
+        capacity.iter().all(|row| row.len() == n),
+        "matrix not square"
+    );
+    assert!(source < n && sink < n, "node out of bounds");
+
+    let mut residual = capacity.to_vec();
+    let mut total = 0;
+    if source != sink {
+        while let Some(parent) = augmenting_path(&residual, source, sink) {
+            // Find the bottleneck along the path, then push that much flow.
+            let mut bottleneck = u64::MAX;
+            let mut v = sink;
+            while v != source {
+                let u = parent[v];
+                bottleneck = min(bottleneck, residual[u][v]);
+                v = u;
+            }
+
+            let mut v = sink;
+            while v != source {
+                let u = parent[v];
+                residual[u][v] -= bottleneck;
+                residual[v][u] += bottleneck;
+                v = u;
+            }
+            total += bottleneck;
+        }
+    }
+
+    // Whatever capacity an edge lost in the residual graph is its flow. If
+    // flow went both ways between two nodes, only the net amount remains.
+    let flow = (0..n)
+        .map(|u| {
+            (0..n)
+                .map(|v| capacity[u][v].saturating_sub(residual[u][v]))
+                .collect()
+        })
+        .collect();
+    (total, flow)
+}
+
+// Breadth-first search for a source-sink path in the residual graph. On
+// success returns the BFS parent of each node on the way.
+fn augmenting_path(residual: &[Vec<u64>], source: usize, sink: usize) -> Option<Vec<usize>> {
+    let n = residual.len();
+    let mut parent = vec![usize::MAX; n];
+    parent[source] = source;
+
+    let mut queue = VecDeque::new();
+    queue.push_back(source);
+    while let Some(u) = queue.pop_front() {
+        for v in 0..n {
+            if parent[v] == usize::MAX && residual[u][v] > 0 {
+                parent[v] = u;
+                if v == sink {
+                    return Some(parent);
+                }
+                queue.push_back(v);
+            }
+        }
+    }
+    None
+}
+
+/// A directed network with edge capacities, stored as adjacency lists.
+///
+/// Every edge is stored next to its reverse edge of capacity 0, so edge `i`
+/// and edge `i ^ 1` always form a residual pair.
+#[derive(Debug, Clone)]
+pub struct FlowGraph {
+    adjacency: Vec<Vec<usize>>,
+    edges: Vec<FlowEdge>,
+}
+
+#[derive(Debug, Clone)]
+struct FlowEdge {
+    to: usize,
+    capacity: u64,
+}
+
+impl FlowGraph {
+    /// A network with nodes `0..n` and no edges.
+    pub fn new(n: usize) -> Self {
+        FlowGraph {
+            adjacency: vec![Vec::new(); n],
+            edges: Vec::new(),
+        }
+    }
+
+    /// A network with an edge `u -> v` for every `capacity[u][v] > 0`.
+    pub fn from_capacity_matrix(capacity: &[Vec<u64>]) -> Self {
+        let mut graph = FlowGraph::new(capacity.len());
+        for (u, row) in capacity.iter().enumerate() {
+            for (v, &c) in row.iter().enumerate() {
+                if c > 0 {
+                    graph.add_edge(u, v, c);
+                }
+            }
+        }
+        graph
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Add an edge from `u` to `v`. Parallel edges are allowed.
+    pub fn add_edge(&mut self, u: usize, v: usize, capacity: u64) {
+        assert!(u < self.node_count() && v < self.node_count());
+        self.adjacency[u].push(self.edges.len());
+        self.edges.push(FlowEdge { to: v, capacity });
+        self.adjacency[v].push(self.edges.len());
+        self.edges.push(FlowEdge { to: u, capacity: 0 });
+    }
+}
+
+/// Returns the value of a maximum flow from `source` to `sink` in `graph`,
+/// computed with Dinic's algorithm.
+pub fn dinic_max_flow(graph: &FlowGraph, source: usize, sink: usize) -> u64 {
+    let n = graph.node_count();
+    assert!(source < n && sink < n, "node out of bounds");
+    if source == sink {
+        return 0;
+    }
+
+    let mut residual: Vec<u64> = graph.edges.iter().map(|edge| edge.capacity).collect();
+    let mut total = 0;
+    while let Some(level) = levels(graph, &residual, source, sink) {
+        let mut current_arc = vec![0; n];
+        loop {
+            let pushed = blocking_flow(
+                graph,
+                &mut residual,
+                &level,
+                &mut current_arc,
+                source,
+                sink,
+                u64::MAX,
+            );
+            if pushed == 0 {
+                break;
+            }
+            total += pushed;
+        }
+    }
+    total
+}
+
+// BFS distances from the source over edges with residual capacity, or None
+// once the sink is unreachable.
+fn levels(graph: &FlowGraph, residual: &[u64], source: usize, sink: usize) -> Option<Vec<usize>> {
+    let mut level = vec![usize::MAX; graph.node_count()];
+    level[source] = 0;
+
+    let mut queue = VecDeque::new();
+    queue.push_back(source);
+    while let Some(u) = queue.pop_front() {
+        for &e in &graph.adjacency[u] {
+            let v = graph.edges[e].to;
+            if residual[e] > 0 && level[v] == usize::MAX {
+                level[v] = level[u] + 1;
+                queue.push_back(v);
+            }
+        }
+    }
+
+    if level[sink] == usize::MAX {
+        None
+    } else {
+        Some(level)
+    }
+}
+
+// Push up to `limit` units from `u` towards the sink along the level graph
+// and return how much was pushed.
+fn blocking_flow(
+    graph: &FlowGraph,
+    residual: &mut [u64],
+    level: &[usize],
+    current_arc: &mut [usize],
+    u: usize,
+    sink: usize,
+    limit: u64,
+) -> u64 {
+    if u == sink {
+        return limit;
+    }
+
+    while current_arc[u] < graph.adjacency[u].len() {
+        let e = graph.adjacency[u][current_arc[u]];
+        let v = graph.edges[e].to;
+        if residual[e] > 0 && level[v] == level[u] + 1 {
+            let pushed = blocking_flow(
+                graph,
+                residual,
+                level,
+                current_arc,
+                v,
+                sink,
+                min(limit, residual[e]),
+            );
+            if pushed > 0 {
+                residual[e] -= pushed;
+                residual[e ^ 1] += pushed;
+                return pushed;
+            }
+        }
+        // This edge cannot carry more flow in the current phase.
+        current_arc[u] += 1;
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    // The example network from CLRS, Figure 26.1.
+    fn clrs_network() -> Vec<Vec<u64>> {
+        vec![
+            vec![0, 16, 13, 0, 0, 0],
+            vec![0, 0, 10, 12, 0, 0],
+            vec![0, 4, 0, 0, 14, 0],
+            vec![0, 0, 9, 0, 0, 20],
+            vec![0, 0, 0, 7, 0, 4],
+            vec![0, 0, 0, 0, 0, 0],
+        ]
+    }
+
+    #[test]
+    fn classic_network() {
+        assert_eq!(max_flow(&clrs_network(), 0, 5), 23);
+    }
+
+    #[test]
+    fn flow_is_valid() {
+        let capacity = clrs_network();
+        let (total, flow) = edmonds_karp(&capacity, 0, 5);
+        let n = capacity.len();
+
+        for (flow_row, capacity_row) in flow.iter().zip(capacity.iter()) {
+            for (f, c) in flow_row.iter().zip(capacity_row.iter()) {
+                assert!(f <= c);
+            }
+        }
+        // Flow is conserved at every node but the source and the sink.
+        for (v, outgoing) in flow.iter().enumerate().take(n - 1).skip(1) {
+            let incoming: u64 = flow.iter().map(|row| row[v]).sum();
+            assert_eq!(incoming, outgoing.iter().sum());
+        }
+        assert_eq!(flow[0].iter().sum::<u64>(), total);
+    }
+
+    #[test]
+    fn source_is_sink() {
+        assert_eq!(max_flow(&clrs_network(), 2, 2), 0);
+    }
+
+    #[test]
+    fn dinic_classic_network() {
+        let graph = FlowGraph::from_capacity_matrix(&clrs_network());
+        assert_eq!(dinic_max_flow(&graph, 0, 5), 23);
+        assert_eq!(dinic_max_flow(&graph, 3, 3), 0);
+    }
+
+    #[test]
+    fn dinic_parallel_edges() {
+        let mut graph = FlowGraph::new(2);
+        graph.add_edge(0, 1, 3);
+        graph.add_edge(0, 1, 4);
+        assert_eq!(dinic_max_flow(&graph, 0, 1), 7);
+        assert_eq!(dinic_max_flow(&graph, 1, 0), 0);
+    }
+
+    #[test]
+    fn dinic_matches_edmonds_karp() {
+        let mut rng = XorShift::new(2024);
+        for _ in 0..20 {
+            let n = rng.gen_range(2, 12) as usize;
+            let capacity: Vec<Vec<u64>> = (0..n)
+                .map(|u| {
+                    (0..n)
+                        .map(|v| {
+                            if u != v && rng.gen_range(0, 3) == 0 {
+                                rng.gen_range(1, 20)
+                            } else {
+                                0
+                            }
+                        })
+                        .collect()
+                })
+                .collect();
+
+            let graph = FlowGraph::from_capacity_matrix(&capacity);
+            assert_eq!(
+                dinic_max_flow(&graph, 0, n - 1),
+                max_flow(&capacity, 0, n - 1)
+            );
+        }
+    }
+
+    #[test]
+    fn disconnected() {
+        let capacity = vec![vec![0, 5, 0], vec![0, 0, 0], vec![0, 0, 0]];
+        assert_eq!(max_flow(&capacity, 0, 2), 0);
+    }
+}
@@ -0,0 +1,130 @@
+//! Stoer-Wagner global minimum cut
+//!
+//! # Algorithm
+//!
+//! Each phase grows a set `A` from an arbitrary node, always adding the
+//! node most tightly connected to `A`, i.e. with the largest total weight of
+//! edges into `A`. If `s` and `t` are the last two nodes added, the weight
+//! of the edges between `t` and everything else is a minimum `s`-`t` cut.
+//! The global minimum cut either separates `s` and `t`, and is then found in
+//! this phase, or it does not, and then `s` and `t` can be merged into one
+//! node. After `n - 1` phases a single node is left and the smallest cut of
+//! any phase is the answer. With an adjacency matrix this takes O(n^3).
+
+/// Returns the total weight of a minimum cut of the undirected graph with
+/// the symmetric adjacency matrix `weights`, i.e. the least weight of edges
+/// whose removal disconnects the graph. Panics if there are fewer than two
+/// nodes.
+pub fn global_min_cut(weights: &[Vec<u64>]) -> u64 {
+    let n = weights.len();
+    assert!(n >= 2, "a cut needs at least two nodes");
+    assert!(
+        weights.iter().all(|row| row.len() == n),
+        "matrix not square"
+    );
+
+    let mut w = weights.to_vec();
+    // the nodes that have not been merged into another one
+    let mut active: Vec<usize> = (0..n).collect();
+    let mut best = u64::MAX;
+
+    while active.len() > 1 {
+        let mut in_a = vec![false; n];
+        // connection[v] is the weight of the edges from v into A
+        let mut connection = vec![0u64; n];
+        let (mut s, mut t) = (active[0], active[0]);
+        for _ in 0..active.len() {
+            let next = *active
+                .iter()
+                .filter(|&&v| !in_a[v])
+                .max_by_key(|&&v| connection[v])
+                .unwrap();
+            in_a[next] = true;
+            s = t;
+            t = next;
+            for &v in &active {
+                connection[v] += w[next][v];
+            }
+        }
+
+        // The diagonal is never added since `t` joined A last.
+        best = best.min(connection[t] - w[t][t]);
+
+        // Merge t into s.
+        for &v in &active {
+            w[s][v] += w[t][v];
+            w[v][s] = w[s][v];
+        }
+        active.retain(|&v| v != t);
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(n: usize, edges: &[(usize, usize, u64)]) -> Vec<Vec<u64>> {
+        let mut w = vec![vec![0; n]; n];
+        for &(u, v, weight) in edges {
+            w[u][v] += weight;
+            w[v][u] += weight;
+        }
+        w
+    }
+
+    #[test]
+    fn stoer_wagner_paper_example() {
+        // The example graph from the original paper, with nodes numbered
+        // from 0. The minimum cut {2, 3, 6, 7} has weight 4.
+        let edges = [
+            (0, 1, 2),
+            (0, 4, 3),
+            (1, 2, 3),
+            (1, 4, 2),
+            (1, 5, 2),
+            (2, 3, 4),
+            (2, 6, 2),
+            (3, 6, 2),
+            (3, 7, 2),
+            (4, 5, 3),
+            (5, 6, 1),
+            (6, 7, 3),
+        ];
+        assert_eq!(global_min_cut(&matrix(8, &edges)), 4);
+    }
+
+    #[test]
+    fn two_clusters() {
+        // two triangles of heavy edges joined by a single light edge
+        let edges = [
+            (0, 1, 10),
+            (1, 2, 10),
+            (0, 2, 10),
+            (3, 4, 10),
+            (4, 5, 10),
+            (3, 5, 10),
+            (2, 3, 1),
+        ];
+        assert_eq!(global_min_cut(&matrix(6, &edges)), 1);
+    }
+
+    #[test]
+    fn single_vertex_cut() {
+        // Cutting off node 3, attached by weight 2, is cheapest.
+        let edges = [(0, 1, 5), (1, 2, 5), (0, 2, 5), (2, 3, 2)];
+        assert_eq!(global_min_cut(&matrix(4, &edges)), 2);
+    }
+
+    #[test]
+    fn disconnected() {
+        assert_eq!(global_min_cut(&matrix(4, &[(0, 1, 3), (2, 3, 4)])), 0);
+        assert_eq!(global_min_cut(&matrix(2, &[(0, 1, 7)])), 7);
+    }
+
+    #[test]
+    #[should_panic]
+    fn single_node() {
+        global_min_cut(&[vec![0]]);
+    }
+}
@@ -0,0 +1,89 @@
+mod adjacency_matrix;
+mod arborescence;
+mod bidirectional_dijkstra;
+mod bipartite_matching;
+mod boruvka;
+mod centroid;
+mod coloring;
+mod components;
+mod dag_longest_path;
+mod dijkstra;
+mod dominators;
+mod euler;
+mod graph_io;
+mod johnson;
+mod k_edge_path;
+mod labeled_graph;
+mod lca;
+mod max_flow;
+mod min_cut;
+mod mst;
+mod pagerank;
+mod random_graph;
+mod reachability;
+mod scc;
+mod second_shortest;
+mod spfa;
+mod two_sat;
+mod widest_path;
+mod yen;
+mod zero_one_bfs;
+
+pub use self::adjacency_matrix::from_adjacency_matrix;
+pub use self::adjacency_matrix::to_adjacency_matrix;
+pub use self::adjacency_matrix::AdjacencyMatrix;
+pub use self::arborescence::min_arborescence;
+pub use self::bidirectional_dijkstra::bidirectional_dijkstra;
+pub use self::bipartite_matching::maximum_matching;
+pub use self::boruvka::boruvka_mst;
+pub use self::centroid::centroid_decomposition;
+pub use self::coloring::dsatur_coloring;
+pub use self::coloring::greedy_coloring;
+pub use self::coloring::welsh_powell_coloring;
+pub use self::components::weakly_connected_components;
+pub use self::dag_longest_path::longest_path_dag;
+pub use self::dijkstra::checked_dijkstra_multi;
+pub use self::dijkstra::count_shortest_paths;
+pub use self::dijkstra::dijkstra;
+pub use self::dijkstra::dijkstra_avoiding;
+pub use self::dijkstra::dijkstra_decrease_key;
+pub use self::dijkstra::dijkstra_multi;
+pub use self::dijkstra::dijkstra_with;
+pub use self::dijkstra::Frontier;
+pub use self::dijkstra::HeapFrontier;
+pub use self::dijkstra::Weight;
+pub use self::dominators::immediate_dominators;
+pub use self::euler::eulerian_path;
+pub use self::graph_io::parse_edge_list;
+pub use self::graph_io::to_dot;
+pub use self::graph_io::to_edge_list;
+pub use self::graph_io::ParseError;
+pub use self::graph_io::ParseErrorKind;
+pub use self::johnson::johnson;
+pub use self::k_edge_path::shortest_path_k_edges;
+pub use self::labeled_graph::LabeledGraph;
+pub use self::lca::LcaBinaryLifting;
+pub use self::max_flow::dinic_max_flow;
+pub use self::max_flow::edmonds_karp;
+pub use self::max_flow::max_flow;
+pub use self::max_flow::FlowGraph;
+pub use self::min_cut::global_min_cut;
+pub use self::mst::kruskal_mst;
+pub use self::mst::min_bottleneck_spanning_tree;
+pub use self::mst::prim_mst;
+pub use self::mst::total_weight;
+pub use self::pagerank::pagerank;
+pub use self::random_graph::gnm_random_graph;
+pub use self::random_graph::gnp_random_graph;
+pub use self::reachability::reachable_from;
+pub use self::reachability::transitive_closure;
+pub use self::scc::kosaraju_scc;
+pub use self::scc::tarjan_scc;
+pub use self::scc::transpose;
+pub use self::second_shortest::second_shortest_path;
+pub use self::second_shortest::strictly_second_shortest_path;
+pub use self::spfa::spfa;
+pub use self::two_sat::TwoSat;
+pub use self::widest_path::widest_path;
+pub use self::yen::k_shortest_paths;
+pub use self::zero_one_bfs::zero_one_bfs;
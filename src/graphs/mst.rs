@@ -0,0 +1,227 @@
+//! Minimum spanning trees
+//!
+//! The undirected graph on the nodes `0..n` is given as a list of edges
+//! `(u, v, w)`. If it is not connected, the result is a minimum spanning
+//! forest, with a tree for every component.
+//!
+//! # Kruskal's algorithm
+//!
+//! The edges are taken in order of weight, and every edge that joins two
+//! different trees is kept. A disjoint set tracks the trees. Sorting the
+//! edges dominates, for O(m log m).
+//!
+//! Every minimum spanning tree also minimizes its heaviest edge, the
+//! bottleneck: an edge of weight `b` is only taken by Kruskal if the
+//! lighter edges do not connect its endpoints, so no spanning tree can get
+//! by with edges lighter than `b` alone. `min_bottleneck_spanning_tree`
+//! therefore runs Kruskal until the tree is complete.
+//!
+//! # Prim's algorithm
+//!
+//! A single tree grows from a start node, always adding the cheapest edge
+//! that leaves it, found with a heap of the edges seen so far. If nodes are
+//! left when the heap runs dry, another tree starts from one of them. This
+//! takes O(m log m).
+
+use crate::data_structures::DisjointSet;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+// an undirected edge `(u, v, w)`
+type Edge = (usize, usize, u64);
+
+/// Returns the edges of a minimum spanning forest found by Kruskal's
+/// algorithm, in increasing order of weight.
+pub fn kruskal_mst(n: usize, edges: &[Edge]) -> Vec<Edge> {
+    let mut sorted = edges.to_vec();
+    sorted.sort_by_key(|&(_, _, w)| w);
+    let mut sets = DisjointSet::new(n);
+    sorted
+        .into_iter()
+        .filter(|&(u, v, _)| sets.union(u, v))
+        .collect()
+}
+
+/// Returns a spanning tree whose heaviest edge is as light as possible,
+/// in increasing order of weight, together with that heaviest edge. Returns
+/// `None` if the graph is not connected or has fewer than two nodes.
+pub fn min_bottleneck_spanning_tree(n: usize, edges: &[Edge]) -> Option<(Vec<Edge>, Edge)> {
+    let mut sorted = edges.to_vec();
+    sorted.sort_by_key(|&(_, _, w)| w);
+    let mut sets = DisjointSet::new(n);
+    let mut tree = Vec::new();
+    for (u, v, w) in sorted {
+        if tree.len() + 1 >= n {
+            break;
+        }
+        if sets.union(u, v) {
+            tree.push((u, v, w));
+        }
+    }
+    if n < 2 || tree.len() + 1 < n {
+        return None;
+    }
+    let bottleneck = *tree.last().unwrap();
+    Some((tree, bottleneck))
+}
+
+/// Returns the edges of a minimum spanning forest found by Prim's
+/// algorithm, in the order they were added.
+pub fn prim_mst(n: usize, edges: &[Edge]) -> Vec<Edge> {
+    let mut adjacency = vec![Vec::new(); n];
+    for &(u, v, w) in edges {
+        adjacency[u].push((v, w));
+        adjacency[v].push((u, w));
+    }
+
+    let mut in_tree = vec![false; n];
+    let mut tree = Vec::new();
+    for start in 0..n {
+        if in_tree[start] {
+            continue;
+        }
+        in_tree[start] = true;
+        let mut heap: BinaryHeap<_> = adjacency[start]
+            .iter()
+            .map(|&(v, w)| Reverse((w, start, v)))
+            .collect();
+        while let Some(Reverse((w, u, v))) = heap.pop() {
+            if in_tree[v] {
+                continue;
+            }
+            in_tree[v] = true;
+            tree.push((u, v, w));
+            for &(x, wx) in &adjacency[v] {
+                if !in_tree[x] {
+                    heap.push(Reverse((wx, v, x)));
+                }
+            }
+        }
+    }
+    tree
+}
+
+/// Total weight of a list of edges.
+pub fn total_weight(edges: &[Edge]) -> u64 {
+    edges.iter().map(|&(_, _, w)| w).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    // Checks that `tree` uses edges of the graph and has no cycle.
+    fn is_forest(n: usize, edges: &[(usize, usize, u64)], tree: &[(usize, usize, u64)]) -> bool {
+        let mut sets = DisjointSet::new(n);
+        tree.iter().all(|&(u, v, w)| {
+            let known = edges.iter().any(|&e| e == (u, v, w) || e == (v, u, w));
+            known && sets.union(u, v)
+        })
+    }
+
+    #[test]
+    fn small_graph() {
+        let edges = [
+            (0, 1, 4),
+            (0, 2, 1),
+            (1, 2, 2),
+            (1, 3, 5),
+            (2, 3, 8),
+            (3, 4, 3),
+        ];
+        let kruskal = kruskal_mst(5, &edges);
+        assert_eq!(kruskal, vec![(0, 2, 1), (1, 2, 2), (3, 4, 3), (1, 3, 5)]);
+        assert_eq!(total_weight(&prim_mst(5, &edges)), 11);
+    }
+
+    #[test]
+    fn forest() {
+        // 0 - 1 and 2 - 3 - 4, with 5 alone
+        let edges = [(0, 1, 7), (2, 3, 1), (3, 4, 2), (2, 4, 3)];
+        for tree in &[kruskal_mst(6, &edges), prim_mst(6, &edges)] {
+            assert_eq!(tree.len(), 3);
+            assert_eq!(total_weight(tree), 10);
+            assert!(is_forest(6, &edges, tree));
+        }
+    }
+
+    // The smallest `t` such that the edges of weight at most `t` connect
+    // the graph.
+    fn brute_force_bottleneck(n: usize, edges: &[(usize, usize, u64)]) -> Option<u64> {
+        let mut thresholds: Vec<u64> = edges.iter().map(|&(_, _, w)| w).collect();
+        thresholds.sort_unstable();
+        thresholds.into_iter().find(|&t| {
+            let mut sets = DisjointSet::new(n);
+            for &(u, v, w) in edges {
+                if w <= t {
+                    sets.union(u, v);
+                }
+            }
+            sets.set_count() == 1
+        })
+    }
+
+    #[test]
+    fn bottleneck() {
+        let edges = [
+            (0, 1, 4),
+            (0, 2, 1),
+            (1, 2, 2),
+            (1, 3, 5),
+            (2, 3, 8),
+            (3, 4, 3),
+        ];
+        let (tree, bottleneck) = min_bottleneck_spanning_tree(5, &edges).unwrap();
+        assert_eq!(tree.len(), 4);
+        assert_eq!(bottleneck, (1, 3, 5));
+        assert_eq!(min_bottleneck_spanning_tree(3, &[(0, 1, 1)]), None);
+        assert_eq!(min_bottleneck_spanning_tree(1, &[]), None);
+    }
+
+    #[test]
+    fn bottleneck_matches_brute_force() {
+        let mut rng = XorShift::new(101);
+        for _ in 0..100 {
+            let n = rng.gen_range(2, 8) as usize;
+            let edges: Vec<(usize, usize, u64)> = (0..rng.gen_range(0, 3 * n as u64))
+                .map(|_| {
+                    let u = rng.gen_range(0, n as u64) as usize;
+                    let v = rng.gen_range(0, n as u64) as usize;
+                    (u, v, rng.gen_range(0, 20))
+                })
+                .collect();
+            let result = min_bottleneck_spanning_tree(n, &edges);
+            assert_eq!(
+                result.as_ref().map(|&(_, (_, _, w))| w),
+                brute_force_bottleneck(n, &edges)
+            );
+            if let Some((tree, bottleneck)) = result {
+                assert_eq!(tree.len(), n - 1);
+                assert!(is_forest(n, &edges, &tree));
+                assert!(tree.iter().all(|&(_, _, w)| w <= bottleneck.2));
+            }
+        }
+    }
+
+    #[test]
+    fn kruskal_matches_prim() {
+        let mut rng = XorShift::new(89);
+        for _ in 0..50 {
+            let n = rng.gen_range(1, 30) as usize;
+            let edges: Vec<(usize, usize, u64)> = (0..rng.gen_range(0, 3 * n as u64))
+                .map(|_| {
+                    let u = rng.gen_range(0, n as u64) as usize;
+                    let v = rng.gen_range(0, n as u64) as usize;
+                    (u, v, rng.gen_range(0, 10))
+                })
+                .collect();
+            let kruskal = kruskal_mst(n, &edges);
+            let prim = prim_mst(n, &edges);
+            assert_eq!(kruskal.len(), prim.len());
+            assert_eq!(total_weight(&kruskal), total_weight(&prim));
+            assert!(is_forest(n, &edges, &kruskal));
+            assert!(is_forest(n, &edges, &prim));
+        }
+    }
+}
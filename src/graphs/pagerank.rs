@@ -0,0 +1,106 @@
+//! PageRank
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding the nodes that
+//! `u` links to. Parallel edges count as several links.
+//!
+//! # Algorithm
+//!
+//! A random surfer follows one of the links of the current page, chosen
+//! uniformly, with probability `damping`, and jumps to a uniformly random
+//! page otherwise. A page without links sends the surfer to a random page
+//! every time. The rank of a page is the long-run fraction of time spent on
+//! it, the stationary distribution of this Markov chain. Power iteration
+//! finds it by applying one step of the chain to the uniform distribution
+//! over and over, until the ranks change by less than `tolerance` in total.
+//! Each step takes O(n + m) and the error shrinks by a factor of `damping`.
+
+/// Returns the PageRank of every node. The ranks add up to 1. Stops after
+/// `max_iterations` steps even if the ranks have not settled yet.
+pub fn pagerank(
+    graph: &[Vec<usize>],
+    damping: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Vec<f64> {
+    assert!((0.0..=1.0).contains(&damping), "damping must be in [0, 1]");
+    let n = graph.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut rank = vec![1.0 / n as f64; n];
+    for _ in 0..max_iterations {
+        // rank of the pages without links, spread over all pages
+        let dangling: f64 = graph
+            .iter()
+            .zip(&rank)
+            .filter(|(edges, _)| edges.is_empty())
+            .map(|(_, &r)| r)
+            .sum();
+        let base = (1.0 - damping + damping * dangling) / n as f64;
+        let mut next = vec![base; n];
+        for (edges, &r) in graph.iter().zip(&rank) {
+            let share = damping * r / edges.len() as f64;
+            for &v in edges {
+                next[v] += share;
+            }
+        }
+
+        let change: f64 = next.iter().zip(&rank).map(|(a, b)| (a - b).abs()).sum();
+        rank = next;
+        if change < tolerance {
+            break;
+        }
+    }
+    rank
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn cycle_is_uniform() {
+        let n = 5;
+        let graph: Vec<Vec<usize>> = (0..n).map(|u| vec![(u + 1) % n]).collect();
+        for r in pagerank(&graph, 0.85, 1e-12, 100) {
+            assert_close(r, 0.2);
+        }
+    }
+
+    #[test]
+    fn star() {
+        // Every leaf links to the center, which links nowhere. Then every
+        // leaf gets l = (1 - d) / n + d * c / n from the random jumps, and
+        // the center the same plus what the leaves pass on, c = l + d * k * l.
+        let (k, d) = (4, 0.85);
+        let mut graph = vec![vec![0]; k + 1];
+        graph[0].clear();
+        let rank = pagerank(&graph, d, 1e-12, 1000);
+        assert_close(rank.iter().sum(), 1.0);
+        let (c, l) = (rank[0], rank[1]);
+        for &r in &rank[1..] {
+            assert_close(r, l);
+        }
+        assert_close(l, (1.0 - d) / 5.0 + d * c / 5.0);
+        assert_close(c, l + d * k as f64 * l);
+        assert!(c > l);
+    }
+
+    #[test]
+    fn ranks_sum_to_one() {
+        // 0 ⇄ 1 → 2, 3 → 2, 2 dangling, with a parallel edge 3 → 2
+        let graph = vec![vec![1], vec![0, 2], vec![], vec![2, 2]];
+        let rank = pagerank(&graph, 0.85, 1e-12, 1000);
+        assert_close(rank.iter().sum(), 1.0);
+        assert!(rank[2] > rank[0] && rank[0] > rank[3]);
+        // without damping every page is equally likely
+        for r in pagerank(&graph, 0.0, 1e-12, 1000) {
+            assert_close(r, 0.25);
+        }
+        assert!(pagerank(&[], 0.85, 1e-12, 10).is_empty());
+    }
+}
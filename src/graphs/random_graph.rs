@@ -0,0 +1,146 @@
+//! Random graphs
+//!
+//! Both generators return directed graphs as adjacency lists, `graph[u]`
+//! holding `(v, w)` for every edge from `u` to `v` with weight `w`. There are
+//! no self-loops and no parallel edges, and the weights are uniform in
+//! `1..=max_weight`. The same seed always gives the same graph, which makes
+//! them useful for tests and benchmarks.
+//!
+//! # Algorithm
+//!
+//! G(n, p) flips a coin with probability `p` for each of the `n (n - 1)`
+//! ordered pairs, in O(n^2).
+//!
+//! G(n, m) draws exactly `m` distinct pairs with Floyd's sampling: for each
+//! `j` in the last `m` indices it picks a random index up to `j`, and takes
+//! `j` itself if that one was already picked. Every `m`-subset is equally
+//! likely, and this takes O(n + m) expected time.
+
+use crate::random::XorShift;
+use std::collections::HashSet;
+
+/// Returns a random G(n, p) graph, with every ordered pair of distinct nodes
+/// joined with probability `p`.
+pub fn gnp_random_graph(n: usize, p: f64, max_weight: u64, seed: u64) -> Vec<Vec<(usize, u64)>> {
+    assert!((0.0..=1.0).contains(&p), "probability must be in [0, 1]");
+    assert!(max_weight >= 1, "max_weight must be at least 1");
+    let mut rng = XorShift::new(seed);
+    let mut graph = vec![Vec::new(); n];
+    for (u, edges) in graph.iter_mut().enumerate() {
+        for v in (0..n).filter(|&v| v != u) {
+            if rng.gen_f64() < p {
+                edges.push((v, rng.gen_range(1, max_weight + 1)));
+            }
+        }
+    }
+    graph
+}
+
+/// Returns a random G(n, m) graph, with `m` edges chosen uniformly among the
+/// ordered pairs of distinct nodes.
+pub fn gnm_random_graph(n: usize, m: usize, max_weight: u64, seed: u64) -> Vec<Vec<(usize, u64)>> {
+    let pairs = n * n.saturating_sub(1);
+    assert!(m <= pairs, "too many edges for {} nodes", n);
+    assert!(max_weight >= 1, "max_weight must be at least 1");
+    let mut rng = XorShift::new(seed);
+    let mut chosen = HashSet::with_capacity(m);
+    for j in pairs - m..pairs {
+        let k = rng.gen_range(0, j as u64 + 1) as usize;
+        if !chosen.insert(k) {
+            chosen.insert(j);
+        }
+    }
+    // The set iterates in no fixed order, so sort before assigning weights.
+    let mut chosen: Vec<usize> = chosen.into_iter().collect();
+    chosen.sort_unstable();
+
+    let mut graph = vec![Vec::new(); n];
+    for k in chosen {
+        // Pair k has source k / (n - 1), and skips the source among targets.
+        let u = k / (n - 1);
+        let r = k % (n - 1);
+        let v = if r < u { r } else { r + 1 };
+        graph[u].push((v, rng.gen_range(1, max_weight + 1)));
+    }
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edge_count(graph: &[Vec<(usize, u64)>]) -> usize {
+        graph.iter().map(Vec::len).sum()
+    }
+
+    // No self-loops, no parallel edges, and weights in range.
+    fn is_simple(graph: &[Vec<(usize, u64)>], max_weight: u64) -> bool {
+        graph.iter().enumerate().all(|(u, edges)| {
+            let targets: HashSet<usize> = edges.iter().map(|&(v, _)| v).collect();
+            targets.len() == edges.len()
+                && !targets.contains(&u)
+                && edges
+                    .iter()
+                    .all(|&(v, w)| v < graph.len() && 1 <= w && w <= max_weight)
+        })
+    }
+
+    #[test]
+    fn same_seed_same_graph() {
+        assert_eq!(
+            gnp_random_graph(30, 0.2, 10, 7),
+            gnp_random_graph(30, 0.2, 10, 7)
+        );
+        assert_ne!(
+            gnp_random_graph(30, 0.2, 10, 7),
+            gnp_random_graph(30, 0.2, 10, 8)
+        );
+        assert_eq!(
+            gnm_random_graph(30, 100, 10, 7),
+            gnm_random_graph(30, 100, 10, 7)
+        );
+        assert_ne!(
+            gnm_random_graph(30, 100, 10, 7),
+            gnm_random_graph(30, 100, 10, 8)
+        );
+    }
+
+    #[test]
+    fn gnp_density() {
+        let graph = gnp_random_graph(100, 0.3, 5, 113);
+        assert!(is_simple(&graph, 5));
+        // 9900 pairs, so about 2970 edges, with a standard deviation of 46.
+        let m = edge_count(&graph);
+        assert!(2800 < m && m < 3140, "{} edges", m);
+
+        assert_eq!(edge_count(&gnp_random_graph(20, 0.0, 5, 1)), 0);
+        assert_eq!(edge_count(&gnp_random_graph(20, 1.0, 5, 1)), 20 * 19);
+    }
+
+    #[test]
+    fn gnm_edge_count() {
+        for (seed, &(n, m)) in [
+            (0, 0),
+            (1, 0),
+            (2, 2),
+            (10, 1),
+            (10, 45),
+            (10, 90),
+            (50, 1000),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let graph = gnm_random_graph(n, m, 3, seed as u64);
+            assert_eq!(graph.len(), n);
+            assert_eq!(edge_count(&graph), m);
+            assert!(is_simple(&graph, 3));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "too many edges")]
+    fn gnm_too_many_edges() {
+        gnm_random_graph(3, 7, 1, 1);
+    }
+}
@@ -0,0 +1,100 @@
+//! Reachability
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding the nodes that
+//! `u` has an edge to.
+//!
+//! # Algorithm
+//!
+//! A flood fill from the source marks every node it reaches and pushes it
+//! on a stack, then keeps expanding nodes from the stack until it is empty.
+//! Every node and edge is looked at most once, so this takes O(n + m).
+//! The transitive closure runs one flood fill per node, for O(n * (n + m)),
+//! which beats the O(n^3) of Floyd-Warshall on sparse graphs.
+
+/// Returns a mask of the nodes reachable from `source` along directed
+/// edges, including `source` itself.
+pub fn reachable_from(graph: &[Vec<usize>], source: usize) -> Vec<bool> {
+    let mut reached = vec![false; graph.len()];
+    reached[source] = true;
+    let mut stack = vec![source];
+    while let Some(u) = stack.pop() {
+        for &v in &graph[u] {
+            if !reached[v] {
+                reached[v] = true;
+                stack.push(v);
+            }
+        }
+    }
+    reached
+}
+
+/// Returns the reachability matrix of `graph`: entry `[u][v]` is true iff
+/// `v` is reachable from `u`. Every node reaches itself.
+pub fn transitive_closure(graph: &[Vec<usize>]) -> Vec<Vec<bool>> {
+    (0..graph.len()).map(|u| reachable_from(graph, u)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 → 1 → 2 → 4, 0 → 3 → 1, 4 → 3, 5 → 0
+    fn example() -> Vec<Vec<usize>> {
+        vec![vec![1, 3], vec![2], vec![4], vec![1], vec![3], vec![0]]
+    }
+
+    #[test]
+    fn from_source() {
+        let reached = reachable_from(&example(), 0);
+        assert_eq!(reached, vec![true, true, true, true, true, false]);
+    }
+
+    #[test]
+    fn edges_are_directed() {
+        let reached = reachable_from(&example(), 3);
+        assert_eq!(reached, vec![false, true, true, true, true, false]);
+        assert!(reachable_from(&example(), 5).iter().all(|&r| r));
+    }
+
+    #[test]
+    fn isolated_node() {
+        let graph = vec![vec![], vec![0]];
+        assert_eq!(reachable_from(&graph, 0), vec![true, false]);
+    }
+
+    #[test]
+    fn closure() {
+        let closure = transitive_closure(&example());
+        assert!((1..=4).all(|v| closure[0][v]));
+        assert!(!closure[0][5]);
+        assert!(closure[5].iter().all(|&r| r));
+        // 1, 2, 3 and 4 lie on a cycle and reach each other, but not 0
+        for row in &closure[1..=4] {
+            assert_eq!(*row, vec![false, true, true, true, true, false]);
+        }
+    }
+
+    #[test]
+    fn matches_warshall() {
+        let graph: Vec<Vec<usize>> = (0..10).map(|u| vec![(u * 3 + 1) % 10, u / 2]).collect();
+
+        let mut expected = vec![vec![false; 10]; 10];
+        for (u, edges) in graph.iter().enumerate() {
+            expected[u][u] = true;
+            for &v in edges {
+                expected[u][v] = true;
+            }
+        }
+        for k in 0..10 {
+            let through = expected[k].clone();
+            for row in expected.iter_mut() {
+                if row[k] {
+                    for (reached, &via) in row.iter_mut().zip(&through) {
+                        *reached |= via;
+                    }
+                }
+            }
+        }
+        assert_eq!(transitive_closure(&graph), expected);
+    }
+}
@@ -0,0 +1,229 @@
+//! Strongly connected components
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding the nodes that
+//! `u` has an edge to. Both algorithms run in O(n + m) time.
+//!
+//! # Tarjan's algorithm
+//!
+//! A single depth-first search numbers the nodes in discovery order and
+//! tracks the smallest number reachable from each subtree through at most
+//! one back edge. A node whose subtree cannot reach anything older is the
+//! root of a component, and that component is exactly what sits above it on
+//! the stack of visited nodes.
+//!
+//! # Kosaraju's algorithm
+//!
+//! A first depth-first search records the order in which nodes finish. The
+//! node finishing last lies in a source component of the condensation, so a
+//! second search on the transposed graph, which cannot leave a source
+//! component, started from it finds exactly that component. Repeating in
+//! decreasing finish order peels off the components one by one.
+
+/// Returns the strongly connected components of `graph` found by Tarjan's
+/// algorithm, in reverse topological order: no edge leads from a component
+/// to an earlier one.
+pub fn tarjan_scc(graph: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let n = graph.len();
+    // discovery order of each node, usize::MAX if not visited yet
+    let mut index = vec![usize::MAX; n];
+    let mut low = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut counter = 0;
+    let mut components = Vec::new();
+
+    for start in 0..n {
+        if index[start] != usize::MAX {
+            continue;
+        }
+        // the depth-first search itself, each entry being a node and the
+        // number of its edges followed so far
+        let mut search = vec![(start, 0)];
+        while let Some(&mut (u, ref mut next)) = search.last_mut() {
+            if *next == 0 {
+                index[u] = counter;
+                low[u] = counter;
+                counter += 1;
+                stack.push(u);
+                on_stack[u] = true;
+            }
+            if let Some(&v) = graph[u].get(*next) {
+                *next += 1;
+                if index[v] == usize::MAX {
+                    search.push((v, 0));
+                } else if on_stack[v] {
+                    low[u] = low[u].min(index[v]);
+                }
+                continue;
+            }
+
+            search.pop();
+            if let Some(&(parent, _)) = search.last() {
+                low[parent] = low[parent].min(low[u]);
+            }
+            // u is the root of a component: pop it off the stack.
+            if low[u] == index[u] {
+                let mut component = Vec::new();
+                loop {
+                    let v = stack.pop().unwrap();
+                    on_stack[v] = false;
+                    component.push(v);
+                    if v == u {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+        }
+    }
+    components
+}
+
+/// Returns the strongly connected components of `graph` found by
+/// Kosaraju's algorithm, in topological order: no edge leads from a
+/// component to an earlier one.
+pub fn kosaraju_scc(graph: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let n = graph.len();
+
+    let mut visited = vec![false; n];
+    let mut finished = Vec::with_capacity(n);
+    for u in 0..n {
+        if !visited[u] {
+            dfs(graph, u, &mut visited, &mut finished);
+        }
+    }
+
+    let transposed = transpose(graph);
+    let mut visited = vec![false; n];
+    let mut components = Vec::new();
+    for &u in finished.iter().rev() {
+        if !visited[u] {
+            let mut component = Vec::new();
+            dfs(&transposed, u, &mut visited, &mut component);
+            components.push(component);
+        }
+    }
+    components
+}
+
+/// Returns the graph with every edge reversed.
+pub fn transpose(graph: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut transposed = vec![Vec::new(); graph.len()];
+    for (u, edges) in graph.iter().enumerate() {
+        for &v in edges {
+            transposed[v].push(u);
+        }
+    }
+    transposed
+}
+
+// Iterative depth-first search from `start`, appending the nodes to
+// `finished` in the order in which they finish.
+fn dfs(graph: &[Vec<usize>], start: usize, visited: &mut [bool], finished: &mut Vec<usize>) {
+    // each entry is a node and the number of its edges followed so far
+    let mut stack = vec![(start, 0)];
+    visited[start] = true;
+    while let Some(&mut (u, ref mut next)) = stack.last_mut() {
+        match graph[u].get(*next) {
+            Some(&v) => {
+                *next += 1;
+                if !visited[v] {
+                    visited[v] = true;
+                    stack.push((v, 0));
+                }
+            }
+            None => {
+                finished.push(u);
+                stack.pop();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn partition(mut components: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+        for component in &mut components {
+            component.sort_unstable();
+        }
+        components.sort();
+        components
+    }
+
+    // Position of every node's component in `components`.
+    fn component_of(components: &[Vec<usize>], n: usize) -> Vec<usize> {
+        let mut of = vec![usize::MAX; n];
+        for (i, component) in components.iter().enumerate() {
+            for &u in component {
+                assert_eq!(of[u], usize::MAX, "node {} in two components", u);
+                of[u] = i;
+            }
+        }
+        assert!(of.iter().all(|&c| c != usize::MAX));
+        of
+    }
+
+    #[test]
+    fn small_graph() {
+        // 0 → 1 → 2 → 0, 2 → 3 → 4 → 3, 5 alone
+        let graph = vec![vec![1], vec![2], vec![0, 3], vec![4], vec![3], vec![]];
+        let expected = vec![vec![0, 1, 2], vec![3, 4], vec![5]];
+        assert_eq!(partition(tarjan_scc(&graph)), expected);
+        assert_eq!(partition(kosaraju_scc(&graph)), expected);
+    }
+
+    #[test]
+    fn empty_and_self_loop() {
+        assert!(tarjan_scc(&[]).is_empty());
+        assert!(kosaraju_scc(&[]).is_empty());
+        let graph = vec![vec![0]];
+        assert_eq!(kosaraju_scc(&graph), vec![vec![0]]);
+    }
+
+    #[test]
+    fn random_graphs_agree() {
+        let mut rng = XorShift::new(107);
+        for _ in 0..30 {
+            let n = rng.gen_range(1, 40) as usize;
+            let m = rng.gen_range(0, 3 * n as u64) as usize;
+            let mut graph = vec![Vec::new(); n];
+            for _ in 0..m {
+                let u = rng.gen_range(0, n as u64) as usize;
+                let v = rng.gen_range(0, n as u64) as usize;
+                graph[u].push(v);
+            }
+
+            let tarjan = tarjan_scc(&graph);
+            let kosaraju = kosaraju_scc(&graph);
+            let (tarjan_of, kosaraju_of) = (component_of(&tarjan, n), component_of(&kosaraju, n));
+            for (u, edges) in graph.iter().enumerate() {
+                for &v in edges {
+                    assert!(tarjan_of[u] >= tarjan_of[v]);
+                    assert!(kosaraju_of[u] <= kosaraju_of[v]);
+                }
+            }
+            assert_eq!(partition(tarjan), partition(kosaraju));
+        }
+    }
+
+    #[test]
+    fn long_path() {
+        // deep enough to overflow a recursive search
+        let n = 200_000;
+        let graph: Vec<Vec<usize>> = (0..n)
+            .map(|u| if u + 1 < n { vec![u + 1] } else { vec![] })
+            .collect();
+        let components = kosaraju_scc(&graph);
+        assert_eq!(components.len(), n);
+        assert_eq!(components[0], vec![0]);
+    }
+
+    #[test]
+    fn transpose_reverses_edges() {
+        let graph = vec![vec![1, 2], vec![2], vec![]];
+        assert_eq!(transpose(&graph), vec![vec![], vec![0], vec![0, 1]]);
+    }
+}
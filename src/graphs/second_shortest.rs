@@ -0,0 +1,136 @@
+//! Second-shortest paths
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding `(v, w)` for
+//! every edge from `u` to `v` with weight `w`. Paths may revisit nodes, and
+//! two paths are different if they use different edges, so parallel edges
+//! make different paths.
+//!
+//! # Algorithm
+//!
+//! Dijkstra is changed to let every node leave the heap twice. The first
+//! time a node is popped is with its shortest distance, the second time with
+//! the length of its second-shortest path, which may be the same if there
+//! are two shortest paths. Only the first two pops of a node are extended,
+//! since any later one cannot lead to a first or second best path anywhere.
+//!
+//! For the strictly second-shortest path each node keeps the best and the
+//! best strictly larger distance instead, and a relaxed distance replaces
+//! whichever of the two it beats. Both take O((n + m) log n).
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Returns the length of the second-shortest path from `source` to
+/// `target`, which equals the shortest one if there are two shortest paths,
+/// or `None` if there are fewer than two paths. Panics if a length does not
+/// fit in a `u64`.
+pub fn second_shortest_path(
+    graph: &[Vec<(usize, u64)>],
+    source: usize,
+    target: usize,
+) -> Option<u64> {
+    let mut popped = vec![0; graph.len()];
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((0, source)));
+
+    while let Some(Reverse((d, u))) = heap.pop() {
+        if popped[u] == 2 {
+            continue;
+        }
+        popped[u] += 1;
+        if u == target && popped[u] == 2 {
+            return Some(d);
+        }
+        for &(v, w) in &graph[u] {
+            if popped[v] < 2 {
+                heap.push(Reverse((add(d, w), v)));
+            }
+        }
+    }
+    None
+}
+
+/// Returns the length of the shortest path from `source` to `target` that
+/// is strictly longer than the shortest one, or `None` if there is none.
+/// Panics if a length does not fit in a `u64`.
+pub fn strictly_second_shortest_path(
+    graph: &[Vec<(usize, u64)>],
+    source: usize,
+    target: usize,
+) -> Option<u64> {
+    let mut best = vec![None; graph.len()];
+    let mut second = vec![None; graph.len()];
+    let mut heap = BinaryHeap::new();
+    best[source] = Some(0);
+    heap.push(Reverse((0, source)));
+
+    while let Some(Reverse((d, u))) = heap.pop() {
+        if best[u] != Some(d) && second[u] != Some(d) {
+            continue;
+        }
+        for &(v, w) in &graph[u] {
+            let candidate = add(d, w);
+            if best[v].is_none() || Some(candidate) < best[v] {
+                second[v] = best[v];
+                best[v] = Some(candidate);
+            } else if Some(candidate) > best[v]
+                && (second[v].is_none() || Some(candidate) < second[v])
+            {
+                second[v] = Some(candidate);
+            } else {
+                continue;
+            }
+            heap.push(Reverse((candidate, v)));
+        }
+    }
+    second[target]
+}
+
+fn add(a: u64, b: u64) -> u64 {
+    a.checked_add(b).expect("path length overflows u64")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallel_equal_paths() {
+        // 0 → 1 → 3 and 0 → 2 → 3 both cost 2, 0 → 3 costs 5
+        let graph = vec![
+            vec![(1, 1), (2, 1), (3, 5)],
+            vec![(3, 1)],
+            vec![(3, 1)],
+            vec![],
+        ];
+        assert_eq!(second_shortest_path(&graph, 0, 3), Some(2));
+        assert_eq!(strictly_second_shortest_path(&graph, 0, 3), Some(5));
+    }
+
+    #[test]
+    fn parallel_edges() {
+        let graph = vec![vec![(1, 3), (1, 3), (1, 4)], vec![]];
+        assert_eq!(second_shortest_path(&graph, 0, 1), Some(3));
+        assert_eq!(strictly_second_shortest_path(&graph, 0, 1), Some(4));
+    }
+
+    #[test]
+    fn revisiting_nodes() {
+        // The only other way from 0 to 1 goes back and forth once more.
+        let graph = vec![vec![(1, 1)], vec![(0, 1)]];
+        assert_eq!(second_shortest_path(&graph, 0, 1), Some(3));
+        assert_eq!(strictly_second_shortest_path(&graph, 0, 1), Some(3));
+        // and from 0 back to 0 after the empty path
+        assert_eq!(second_shortest_path(&graph, 0, 0), Some(2));
+        assert_eq!(strictly_second_shortest_path(&graph, 0, 0), Some(2));
+    }
+
+    #[test]
+    fn fewer_than_two_paths() {
+        let graph = vec![vec![(1, 1)], vec![(2, 1)], vec![]];
+        assert_eq!(second_shortest_path(&graph, 0, 2), None);
+        assert_eq!(strictly_second_shortest_path(&graph, 0, 2), None);
+        assert_eq!(second_shortest_path(&graph, 2, 0), None);
+        assert_eq!(strictly_second_shortest_path(&graph, 2, 0), None);
+    }
+}
@@ -0,0 +1,121 @@
+//! Shortest Path Faster Algorithm (SPFA)
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding `(v, w)` for
+//! every edge from `u` to `v` with weight `w`. Weights may be negative.
+//!
+//! # Algorithm
+//!
+//! This is Bellman-Ford that only relaxes the edges of nodes whose distance
+//! changed since they were last looked at. Those nodes wait in a FIFO queue,
+//! each at most once at a time. Every relaxation also records the number of
+//! edges on the path that produced it. Without a negative cycle a shortest
+//! path has fewer than n edges, so a path of n edges can only keep getting
+//! shorter by going around a negative cycle. The worst case is the same
+//! O(n * m) as Bellman-Ford, but on sparse graphs far fewer edges are
+//! usually touched.
+
+use std::collections::VecDeque;
+
+/// Returns the shortest distance from `source` to every node, or `None` for
+/// nodes that cannot be reached. Returns `None` if a negative cycle can be
+/// reached from `source`. Panics if a distance does not fit in an `i64`.
+pub fn spfa(graph: &[Vec<(usize, i64)>], source: usize) -> Option<Vec<Option<i64>>> {
+    let n = graph.len();
+    let mut dist = vec![None; n];
+    let mut queued = vec![false; n];
+    // edges on the path behind each node's current distance
+    let mut hops = vec![0; n];
+    let mut queue = VecDeque::new();
+    dist[source] = Some(0);
+    queued[source] = true;
+    queue.push_back(source);
+
+    while let Some(u) = queue.pop_front() {
+        queued[u] = false;
+        let du: i64 = dist[u].unwrap();
+        for &(v, w) in &graph[u] {
+            let candidate = du.checked_add(w).expect("distance overflows i64");
+            if dist[v].is_none() || Some(candidate) < dist[v] {
+                dist[v] = Some(candidate);
+                hops[v] = hops[u] + 1;
+                if hops[v] >= n {
+                    return None;
+                }
+                if !queued[v] {
+                    queued[v] = true;
+                    queue.push_back(v);
+                }
+            }
+        }
+    }
+    Some(dist)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn bellman_ford(graph: &[Vec<(usize, i64)>], source: usize) -> Vec<Option<i64>> {
+        let mut dist = vec![None; graph.len()];
+        dist[source] = Some(0);
+        for _ in 1..graph.len() {
+            for (u, edges) in graph.iter().enumerate() {
+                if let Some(du) = dist[u] {
+                    for &(v, w) in edges {
+                        if dist[v].is_none() || Some(du + w) < dist[v] {
+                            dist[v] = Some(du + w);
+                        }
+                    }
+                }
+            }
+        }
+        dist
+    }
+
+    #[test]
+    fn negative_edges() {
+        // 0 → 1 (4), 0 → 2 (5), 2 → 1 (-3), 1 → 3 (1), 4 alone
+        let graph = vec![
+            vec![(1, 4), (2, 5)],
+            vec![(3, 1)],
+            vec![(1, -3)],
+            vec![],
+            vec![],
+        ];
+        assert_eq!(
+            spfa(&graph, 0),
+            Some(vec![Some(0), Some(2), Some(5), Some(3), None])
+        );
+    }
+
+    #[test]
+    fn negative_cycle() {
+        // 1 → 2 → 3 → 1 sums to -1
+        let graph = vec![vec![(1, 1)], vec![(2, 1)], vec![(3, 1)], vec![(1, -3)]];
+        assert_eq!(spfa(&graph, 0), None);
+        assert_eq!(spfa(&[vec![(0, -1)]], 0), None);
+
+        // the same cycle is harmless if it cannot be reached
+        let graph = vec![vec![], vec![(2, 1)], vec![(3, 1)], vec![(1, -3)]];
+        assert_eq!(spfa(&graph, 0), Some(vec![Some(0), None, None, None]));
+    }
+
+    #[test]
+    fn matches_bellman_ford() {
+        let mut rng = XorShift::new(53);
+        for _ in 0..50 {
+            let n = rng.gen_range(1, 30) as usize;
+            // w + p[u] - p[v] with w >= 0 keeps every cycle non-negative
+            let p: Vec<i64> = (0..n).map(|_| rng.gen_range(0, 50) as i64).collect();
+            let mut graph = vec![Vec::new(); n];
+            for _ in 0..rng.gen_range(0, 4 * n as u64) {
+                let u = rng.gen_range(0, n as u64) as usize;
+                let v = rng.gen_range(0, n as u64) as usize;
+                graph[u].push((v, rng.gen_range(0, 20) as i64 + p[u] - p[v]));
+            }
+            let source = rng.gen_range(0, n as u64) as usize;
+            assert_eq!(spfa(&graph, source), Some(bellman_ford(&graph, source)));
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! 2-SAT
+//!
+//! # Algorithm
+//!
+//! Every clause `a ∨ b` is equivalent to the implications `¬a → b` and
+//! `¬b → a`. Build the implication graph on the `2 * n` literals and find
+//! its strongly connected components with `tarjan_scc`. The formula is
+//! satisfiable iff no variable lies in the same component as its negation.
+//!
+//! Tarjan's algorithm completes the components in reverse topological order.
+//! Setting each variable to whichever of its two literals comes later in
+//! topological order, i.e. whose component completed first, never makes an
+//! implication go from true to false. Everything runs in O(n + m) time for
+//! `n` variables and `m` clauses.
+
+use super::tarjan_scc;
+
+/// A 2-SAT instance over the variables `1..=num_vars`.
+///
+/// Literals are non-zero integers: `i` stands for variable `i` and `-i` for
+/// its negation.
+pub struct TwoSat {
+    num_vars: usize,
+    // implications between literals, see `node`
+    graph: Vec<Vec<usize>>,
+}
+
+impl TwoSat {
+    pub fn new(num_vars: usize) -> Self {
+        TwoSat {
+            num_vars,
+            graph: vec![Vec::new(); 2 * num_vars],
+        }
+    }
+
+    /// Add the clause `a ∨ b`. Pass the same literal twice to force it to be
+    /// true.
+    pub fn add_clause(&mut self, a: i32, b: i32) {
+        let (a, b) = (self.node(a), self.node(b));
+        self.graph[a ^ 1].push(b);
+        self.graph[b ^ 1].push(a);
+    }
+
+    /// Returns a satisfying assignment, where entry `i` is the value of
+    /// variable `i + 1`, or `None` if there is none.
+    pub fn solve(&self) -> Option<Vec<bool>> {
+        // Components are numbered in the order Tarjan's algorithm completes
+        // them.
+        let mut component = vec![0; self.graph.len()];
+        for (i, nodes) in tarjan_scc(&self.graph).iter().enumerate() {
+            for &u in nodes {
+                component[u] = i;
+            }
+        }
+        (0..self.num_vars)
+            .map(|i| {
+                let (positive, negative) = (component[2 * i], component[2 * i + 1]);
+                if positive == negative {
+                    None
+                } else {
+                    Some(positive < negative)
+                }
+            })
+            .collect()
+    }
+
+    // Variable i is node 2 * (i - 1) and its negation the node right after.
+    fn node(&self, literal: i32) -> usize {
+        let var = literal.unsigned_abs() as usize;
+        assert!(
+            var >= 1 && var <= self.num_vars,
+            "invalid literal {}",
+            literal
+        );
+        2 * (var - 1) + usize::from(literal < 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn satisfies(clauses: &[(i32, i32)], assignment: &[bool]) -> bool {
+        let value = |literal: i32| assignment[literal.unsigned_abs() as usize - 1] == (literal > 0);
+        clauses.iter().all(|&(a, b)| value(a) || value(b))
+    }
+
+    fn solve(num_vars: usize, clauses: &[(i32, i32)]) -> Option<Vec<bool>> {
+        let mut two_sat = TwoSat::new(num_vars);
+        for &(a, b) in clauses {
+            two_sat.add_clause(a, b);
+        }
+        two_sat.solve()
+    }
+
+    #[test]
+    fn satisfiable() {
+        let clauses = [(1, 2), (-1, 3), (-2, -3), (2, 4), (-4, -1)];
+        let assignment = solve(4, &clauses).unwrap();
+        assert_eq!(assignment.len(), 4);
+        assert!(satisfies(&clauses, &assignment));
+    }
+
+    #[test]
+    fn forced_values() {
+        // x1, x1 → x2, x2 → ¬x3
+        let clauses = [(1, 1), (-1, 2), (-2, -3)];
+        assert_eq!(solve(3, &clauses), Some(vec![true, true, false]));
+    }
+
+    #[test]
+    fn unsatisfiable() {
+        assert_eq!(solve(1, &[(1, 1), (-1, -1)]), None);
+
+        // x1 ↔ x2 and x1 ↔ ¬x2
+        let clauses = [(-1, 2), (1, -2), (1, 2), (-1, -2)];
+        assert_eq!(solve(2, &clauses), None);
+    }
+
+    #[test]
+    fn exhaustive_small_instances() {
+        // Compare against brute force on every formula of three clauses over
+        // two variables.
+        let literals = [1, -1, 2, -2];
+        let all_clauses: Vec<(i32, i32)> = literals
+            .iter()
+            .flat_map(|&a| literals.iter().map(move |&b| (a, b)))
+            .collect();
+        for &c1 in &all_clauses {
+            for &c2 in &all_clauses {
+                for &c3 in &all_clauses {
+                    let clauses = [c1, c2, c3];
+                    let expected =
+                        (0..4).any(|bits| satisfies(&clauses, &[bits & 1 == 1, bits & 2 == 2]));
+                    match solve(2, &clauses) {
+                        Some(assignment) => assert!(satisfies(&clauses, &assignment)),
+                        None => assert!(!expected, "{:?} is satisfiable", clauses),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_literal() {
+        TwoSat::new(2).add_clause(0, 1);
+    }
+}
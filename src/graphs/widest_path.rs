@@ -0,0 +1,107 @@
+//! Widest path
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding `(v, w)` for
+//! every edge from `u` to `v` with capacity `w`. The width of a path is the
+//! smallest capacity on it, its bottleneck.
+//!
+//! # Algorithm
+//!
+//! This is Dijkstra with the widest path found so far in place of the
+//! shortest one. A max-heap hands out the node with the largest bottleneck,
+//! which is final, since extending some other path can only narrow it. The
+//! path through an edge `u -> v` has width `min(width[u], w)`. This takes
+//! O((n + m) log n).
+
+use std::collections::BinaryHeap;
+
+/// Returns the width of the widest path from `source` to every node, or
+/// `None` for nodes that cannot be reached. The source itself, reached by
+/// the empty path, gets `u64::MAX`.
+pub fn widest_path(graph: &[Vec<(usize, u64)>], source: usize) -> Vec<Option<u64>> {
+    let mut width = vec![None; graph.len()];
+    let mut done = vec![false; graph.len()];
+    let mut heap = BinaryHeap::new();
+    width[source] = Some(u64::MAX);
+    heap.push((u64::MAX, source));
+
+    while let Some((b, u)) = heap.pop() {
+        if done[u] {
+            continue;
+        }
+        done[u] = true;
+        for &(v, w) in &graph[u] {
+            let candidate = b.min(w);
+            if width[v].is_none() || Some(candidate) > width[v] {
+                width[v] = Some(candidate);
+                heap.push((candidate, v));
+            }
+        }
+    }
+    width
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::reachable_from;
+    use crate::random::XorShift;
+
+    // The widest path to `v` has width at least `t` iff `v` can be reached
+    // over edges of capacity at least `t`.
+    fn brute_force(graph: &[Vec<(usize, u64)>], source: usize) -> Vec<Option<u64>> {
+        let mut thresholds: Vec<u64> = graph.iter().flatten().map(|&(_, w)| w).collect();
+        thresholds.sort_unstable();
+        thresholds.dedup();
+        let mut width = vec![None; graph.len()];
+        for &t in &thresholds {
+            let wide: Vec<Vec<usize>> = graph
+                .iter()
+                .map(|edges| {
+                    edges
+                        .iter()
+                        .filter(|&&(_, w)| w >= t)
+                        .map(|&(v, _)| v)
+                        .collect()
+                })
+                .collect();
+            for (v, reached) in reachable_from(&wide, source).into_iter().enumerate() {
+                if reached {
+                    width[v] = Some(t);
+                }
+            }
+        }
+        width[source] = Some(u64::MAX);
+        width
+    }
+
+    #[test]
+    fn small_graph() {
+        // 0 → 1 (5), 0 → 2 (3), 1 → 2 (4), 1 → 3 (2), 2 → 3 (6), 4 alone
+        let graph = vec![
+            vec![(1, 5), (2, 3)],
+            vec![(2, 4), (3, 2)],
+            vec![(3, 6)],
+            vec![],
+            vec![],
+        ];
+        assert_eq!(
+            widest_path(&graph, 0),
+            vec![Some(u64::MAX), Some(5), Some(4), Some(4), None]
+        );
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = XorShift::new(61);
+        for _ in 0..50 {
+            let n = rng.gen_range(1, 10) as usize;
+            let mut graph = vec![Vec::new(); n];
+            for _ in 0..rng.gen_range(0, 3 * n as u64) {
+                let u = rng.gen_range(0, n as u64) as usize;
+                let v = rng.gen_range(0, n as u64) as usize;
+                graph[u].push((v, rng.gen_range(0, 10)));
+            }
+            assert_eq!(widest_path(&graph, 0), brute_force(&graph, 0));
+        }
+    }
+}
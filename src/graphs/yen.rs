@@ -0,0 +1,243 @@
+//! Yen's k shortest loopless paths
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding `(v, w)` for
+//! every edge from `u` to `v` with weight `w`. Paths are sequences of nodes,
+//! so of several parallel edges only the cheapest matters.
+//!
+//! # Algorithm
+//!
+//! The shortest path comes from Dijkstra. Every further path leaves one of
+//! the paths found so far at some spur node, after sharing its root, the
+//! part up to the spur node. For each spur node of the last path found, the
+//! edges that the known paths with the same root take next are removed, as
+//! are the root's other nodes so that the path stays loopless, and Dijkstra
+//! finds the best way on from the spur node. Root plus spur path is a
+//! candidate, and the cheapest candidate is the next path. Each of the k
+//! rounds runs up to n Dijkstras, for O(k * n * (n + m) log n).
+
+use std::cmp::Reverse;
+use std::collections::BTreeSet;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+
+/// Returns up to `k` loopless paths from `source` to `target` with their
+/// costs, cheapest first. There are fewer if the graph has fewer such paths.
+/// Panics if a path cost does not fit in a `u64`.
+pub fn k_shortest_paths(
+    graph: &[Vec<(usize, u64)>],
+    source: usize,
+    target: usize,
+    k: usize,
+) -> Vec<(u64, Vec<usize>)> {
+    let mut paths: Vec<(u64, Vec<usize>)> = Vec::new();
+    if k == 0 {
+        return paths;
+    }
+    let no_nodes = vec![false; graph.len()];
+    match shortest_path(graph, source, target, &no_nodes, &HashSet::new()) {
+        Some(path) => paths.push(path),
+        None => return paths,
+    }
+
+    let mut candidates = BTreeSet::new();
+    while paths.len() < k {
+        let last = paths.last().unwrap().1.clone();
+        let mut blocked = vec![false; graph.len()];
+        let mut root_cost = 0;
+        for i in 0..last.len() - 1 {
+            let root = &last[..=i];
+            let removed: HashSet<(usize, usize)> = paths
+                .iter()
+                .filter(|(_, path)| path.len() > i + 1 && path[..=i] == *root)
+                .map(|(_, path)| (path[i], path[i + 1]))
+                .collect();
+            if let Some((cost, spur)) = shortest_path(graph, last[i], target, &blocked, &removed) {
+                let mut path = root[..i].to_vec();
+                path.extend(spur);
+                candidates.insert((add(root_cost, cost), path));
+            }
+            blocked[last[i]] = true;
+            root_cost = add(root_cost, edge_cost(graph, last[i], last[i + 1]));
+        }
+
+        match candidates.iter().next().cloned() {
+            Some(next) => {
+                candidates.remove(&next);
+                paths.push(next);
+            }
+            None => break,
+        }
+    }
+    paths
+}
+
+// Dijkstra that avoids `blocked` nodes and `removed` edges, returning the
+// cost and nodes of a shortest path.
+fn shortest_path(
+    graph: &[Vec<(usize, u64)>],
+    source: usize,
+    target: usize,
+    blocked: &[bool],
+    removed: &HashSet<(usize, usize)>,
+) -> Option<(u64, Vec<usize>)> {
+    let mut dist = vec![None; graph.len()];
+    let mut parent = vec![usize::MAX; graph.len()];
+    let mut heap = BinaryHeap::new();
+    dist[source] = Some(0);
+    heap.push(Reverse((0, source)));
+
+    while let Some(Reverse((d, u))) = heap.pop() {
+        if dist[u] != Some(d) {
+            continue;
+        }
+        if u == target {
+            let mut path = vec![target];
+            while *path.last().unwrap() != source {
+                path.push(parent[*path.last().unwrap()]);
+            }
+            path.reverse();
+            return Some((d, path));
+        }
+        for &(v, w) in &graph[u] {
+            if blocked[v] || removed.contains(&(u, v)) {
+                continue;
+            }
+            let candidate = add(d, w);
+            if dist[v].is_none() || Some(candidate) < dist[v] {
+                dist[v] = Some(candidate);
+                parent[v] = u;
+                heap.push(Reverse((candidate, v)));
+            }
+        }
+    }
+    None
+}
+
+fn edge_cost(graph: &[Vec<(usize, u64)>], u: usize, v: usize) -> u64 {
+    graph[u]
+        .iter()
+        .filter(|&&(to, _)| to == v)
+        .map(|&(_, w)| w)
+        .min()
+        .unwrap()
+}
+
+fn add(a: u64, b: u64) -> u64 {
+    a.checked_add(b).expect("path cost overflows u64")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    // Every loopless path from `u` to `target`, by depth-first search.
+    fn all_paths(
+        graph: &[Vec<(usize, u64)>],
+        target: usize,
+        path: &mut Vec<usize>,
+        cost: u64,
+        out: &mut Vec<(u64, Vec<usize>)>,
+    ) {
+        let u = *path.last().unwrap();
+        if u == target {
+            out.push((cost, path.clone()));
+            return;
+        }
+        let mut next: Vec<usize> = graph[u].iter().map(|&(v, _)| v).collect();
+        next.sort_unstable();
+        next.dedup();
+        for v in next {
+            if !path.contains(&v) {
+                path.push(v);
+                all_paths(graph, target, path, cost + edge_cost(graph, u, v), out);
+                path.pop();
+            }
+        }
+    }
+
+    fn check(graph: &[Vec<(usize, u64)>], source: usize, target: usize, k: usize) {
+        let mut expected = Vec::new();
+        all_paths(graph, target, &mut vec![source], 0, &mut expected);
+        expected.sort();
+
+        let paths = k_shortest_paths(graph, source, target, k);
+        assert_eq!(paths.len(), k.min(expected.len()));
+        // The costs match, but ties may come out in any order.
+        let costs: Vec<u64> = paths.iter().map(|p| p.0).collect();
+        let expected_costs: Vec<u64> = expected.iter().take(k).map(|p| p.0).collect();
+        assert_eq!(costs, expected_costs);
+        for (i, path) in paths.iter().enumerate() {
+            assert!(expected.contains(path));
+            assert!(!paths[..i].contains(path));
+        }
+    }
+
+    fn wikipedia() -> Vec<Vec<(usize, u64)>> {
+        // C = 0, D = 1, E = 2, F = 3, G = 4, H = 5
+        vec![
+            vec![(1, 3), (2, 2)],
+            vec![(3, 4)],
+            vec![(1, 1), (3, 2), (4, 3)],
+            vec![(4, 2), (5, 1)],
+            vec![(5, 2)],
+            vec![],
+        ]
+    }
+
+    #[test]
+    fn in_order() {
+        let paths = k_shortest_paths(&wikipedia(), 0, 5, 2);
+        assert_eq!(paths, vec![(5, vec![0, 2, 3, 5]), (7, vec![0, 2, 4, 5])]);
+    }
+
+    #[test]
+    fn tied_costs() {
+        // [0, 1, 3, 5], [0, 2, 1, 3, 5] and [0, 2, 3, 4, 5] all cost 8
+        let paths = k_shortest_paths(&wikipedia(), 0, 5, 5);
+        let costs: Vec<u64> = paths.iter().map(|p| p.0).collect();
+        assert_eq!(costs, vec![5, 7, 8, 8, 8]);
+        let mut tied: Vec<Vec<usize>> = paths[2..].iter().map(|p| p.1.clone()).collect();
+        tied.sort();
+        assert_eq!(
+            tied,
+            vec![vec![0, 1, 3, 5], vec![0, 2, 1, 3, 5], vec![0, 2, 3, 4, 5]]
+        );
+        check(&wikipedia(), 0, 5, 100);
+    }
+
+    #[test]
+    fn fewer_than_k_and_loopless() {
+        // Going around 1 → 2 → 1 would cost only 4, but is not a loopless
+        // path, so 0 → 3 is the only other one.
+        let graph = vec![
+            vec![(1, 1), (3, 100)],
+            vec![(2, 1), (3, 1)],
+            vec![(1, 1)],
+            vec![],
+        ];
+        assert_eq!(
+            k_shortest_paths(&graph, 0, 3, 3),
+            vec![(2, vec![0, 1, 3]), (100, vec![0, 3])]
+        );
+        assert_eq!(k_shortest_paths(&graph, 3, 0, 3), vec![]);
+        assert_eq!(k_shortest_paths(&graph, 0, 0, 3), vec![(0, vec![0])]);
+        assert_eq!(k_shortest_paths(&graph, 0, 3, 0), vec![]);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = XorShift::new(59);
+        for _ in 0..40 {
+            let n = rng.gen_range(2, 8) as usize;
+            let mut graph = vec![Vec::new(); n];
+            for _ in 0..rng.gen_range(0, 3 * n as u64) {
+                let u = rng.gen_range(0, n as u64) as usize;
+                let v = rng.gen_range(0, n as u64) as usize;
+                graph[u].push((v, rng.gen_range(0, 5)));
+            }
+            let k = rng.gen_range(1, 10) as usize;
+            check(&graph, 0, n - 1, k);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! 0-1 BFS
+//!
+//! The graph is given as adjacency lists, `graph[u]` holding `(v, w)` for
+//! every edge from `u` to `v` with weight `w`, which must be 0 or 1.
+//!
+//! # Algorithm
+//!
+//! With only two weights Dijkstra's heap holds at most two distinct
+//! distances at a time, `d` and `d + 1`, so a deque can take its place: a
+//! node reached over a 0-edge goes to the front, behind the other nodes at
+//! distance `d`, and one reached over a 1-edge to the back. The front of the
+//! deque is then always a node with the smallest tentative distance. Every
+//! node is settled once, so this takes O(n + m).
+
+use std::collections::VecDeque;
+
+/// Returns the shortest distance from `source` to every node, or `None` for
+/// nodes that cannot be reached. Panics if an edge weight is neither 0 nor
+/// 1.
+pub fn zero_one_bfs(graph: &[Vec<(usize, u64)>], source: usize) -> Vec<Option<u64>> {
+    let mut dist = vec![None; graph.len()];
+    let mut done = vec![false; graph.len()];
+    let mut deque = VecDeque::new();
+    dist[source] = Some(0);
+    deque.push_back(source);
+
+    while let Some(u) = deque.pop_front() {
+        if done[u] {
+            continue;
+        }
+        done[u] = true;
+        let du: u64 = dist[u].unwrap();
+        for &(v, w) in &graph[u] {
+            assert!(w <= 1, "edge weights must be 0 or 1");
+            let candidate = du + w;
+            if dist[v].is_none() || Some(candidate) < dist[v] {
+                dist[v] = Some(candidate);
+                if w == 0 {
+                    deque.push_front(v);
+                } else {
+                    deque.push_back(v);
+                }
+            }
+        }
+    }
+    dist
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::dijkstra;
+    use crate::random::XorShift;
+
+    #[test]
+    fn mixed_weights() {
+        // 0 → 1 (1), 0 → 2 (0), 2 → 1 (0), 1 → 3 (1), 2 → 3 (1), 4 alone
+        let graph = vec![
+            vec![(1, 1), (2, 0)],
+            vec![(3, 1)],
+            vec![(1, 0), (3, 1)],
+            vec![],
+            vec![],
+        ];
+        assert_eq!(
+            zero_one_bfs(&graph, 0),
+            vec![Some(0), Some(0), Some(0), Some(1), None]
+        );
+    }
+
+    #[test]
+    fn matches_dijkstra() {
+        let mut rng = XorShift::new(67);
+        for _ in 0..50 {
+            let n = rng.gen_range(1, 40) as usize;
+            let mut graph = vec![Vec::new(); n];
+            for _ in 0..rng.gen_range(0, 4 * n as u64) {
+                let u = rng.gen_range(0, n as u64) as usize;
+                let v = rng.gen_range(0, n as u64) as usize;
+                graph[u].push((v, rng.gen_range(0, 2)));
+            }
+            let source = rng.gen_range(0, n as u64) as usize;
+            assert_eq!(zero_one_bfs(&graph, source), dijkstra(&graph, source));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "edge weights must be 0 or 1")]
+    fn rejects_other_weights() {
+        zero_one_bfs(&[vec![(1, 2)], vec![]], 0);
+    }
+}
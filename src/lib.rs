@@ -1,6 +1,31 @@
+// Without the `std` feature only the sorting module is available, see
+// Cargo.toml. Tests always use `std`.
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+// `no_std` puts `core` in the crate root by itself. With `std` it has to be
+// declared, so that `core::` paths work in both configurations.
+#[cfg(any(test, feature = "std"))]
+extern crate core;
+
+#[cfg(feature = "std")]
 pub mod ciphers;
+#[cfg(feature = "std")]
 pub mod data_structures;
+#[cfg(feature = "std")]
 pub mod dynamic_programming;
+#[cfg(feature = "std")]
 pub mod general;
+#[cfg(feature = "std")]
+pub mod graphs;
+#[cfg(feature = "std")]
+pub mod math;
+#[cfg(feature = "std")]
 pub mod searching;
 pub mod sorting;
+#[cfg(feature = "std")]
+pub mod strings;
+
+#[cfg(any(test, feature = "std"))]
+mod random;
@@ -0,0 +1,146 @@
+use super::mod_pow;
+
+/// Returns the binomial coefficient `C(n, k)`, the number of ways to choose
+/// `k` of `n` items, or `None` if it does not fit in a `u64`.
+///
+/// The multiplicative formula `C(n, i) = C(n, i - 1) * (n - k + i) / i` is
+/// applied for `i = 1..=k`, where `k` is first replaced by the smaller of
+/// `k` and `n - k`. Each step divides exactly, and the intermediate values
+/// only grow, so the first one beyond `u64::MAX` proves the result is too.
+pub fn n_choose_k(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+
+    let mut result: u128 = 1;
+    for i in 1..=k {
+        result = result * (n - k + i) as u128 / i as u128;
+        if result > u64::MAX as u128 {
+            return None;
+        }
+    }
+    Some(result as u64)
+}
+
+/// Returns `n! / (n - k)!`, the number of ordered selections of `k` of `n`
+/// items, or `None` if it does not fit in a `u64`.
+pub fn permutations(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    (n - k + 1..=n).try_fold(1u64, |acc, factor| acc.checked_mul(factor))
+}
+
+/// Returns `C(n, k) mod p` for a prime `p`.
+///
+/// For `n < p` the numerator and denominator of `n! / (k! (n - k)!)` are
+/// reduced modulo `p` and the denominator is inverted with Fermat's little
+/// theorem, `a^(p - 2) = a^-1 (mod p)`. Larger `n` are split into base-`p`
+/// digits with Lucas' theorem, `C(n, k) = Π C(n_i, k_i) (mod p)`, since the
+/// denominator could otherwise be divisible by `p`.
+pub fn n_choose_k_mod(n: u64, k: u64, p: u64) -> u64 {
+    assert!(p >= 2, "modulus must be prime");
+    let (mut n, mut k) = (n, k);
+    let mut result = 1;
+    while k > 0 {
+        let digit = small_choose_mod(n % p, k % p, p);
+        if digit == 0 {
+            return 0;
+        }
+        result = (result as u128 * digit as u128 % p as u128) as u64;
+        n /= p;
+        k /= p;
+    }
+    result % p
+}
+
+// C(n, k) mod p for n < p
+fn small_choose_mod(n: u64, k: u64, p: u64) -> u64 {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k);
+    let multiply = |a: u64, b: u64| (a as u128 * b as u128 % p as u128) as u64;
+
+    let mut numerator = 1;
+    let mut denominator = 1;
+    for i in 0..k {
+        numerator = multiply(numerator, n - i);
+        denominator = multiply(denominator, i + 1);
+    }
+    multiply(numerator, mod_pow(denominator, p - 2, p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_values() {
+        assert_eq!(n_choose_k(0, 0), Some(1));
+        assert_eq!(n_choose_k(5, 0), Some(1));
+        assert_eq!(n_choose_k(5, 2), Some(10));
+        assert_eq!(n_choose_k(5, 5), Some(1));
+        assert_eq!(n_choose_k(10, 3), Some(120));
+        assert_eq!(n_choose_k(52, 5), Some(2_598_960));
+
+        assert_eq!(permutations(5, 0), Some(1));
+        assert_eq!(permutations(5, 2), Some(20));
+        assert_eq!(permutations(10, 10), Some(3_628_800));
+    }
+
+    #[test]
+    fn pascals_triangle() {
+        for n in 1..60 {
+            for k in 1..n {
+                let expected = n_choose_k(n - 1, k - 1).unwrap() + n_choose_k(n - 1, k).unwrap();
+                assert_eq!(n_choose_k(n, k), Some(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn k_greater_than_n() {
+        assert_eq!(n_choose_k(3, 4), Some(0));
+        assert_eq!(permutations(3, 4), Some(0));
+        assert_eq!(n_choose_k_mod(3, 4, 7), 0);
+    }
+
+    #[test]
+    fn overflow() {
+        // C(68, 34) is the first central coefficient above u64::MAX.
+        assert_eq!(n_choose_k(67, 33), Some(14_226_520_737_620_288_370));
+        assert_eq!(n_choose_k(68, 34), None);
+        assert_eq!(n_choose_k(u64::MAX, 1), Some(u64::MAX));
+        assert_eq!(n_choose_k(u64::MAX, u64::MAX - 1), Some(u64::MAX));
+        assert_eq!(n_choose_k(u64::MAX, 2), None);
+
+        assert_eq!(permutations(20, 20), Some(2_432_902_008_176_640_000));
+        assert_eq!(permutations(21, 21), None);
+    }
+
+    #[test]
+    fn modular() {
+        let p = 1_000_000_007;
+        for n in 0..60 {
+            for k in 0..=n {
+                assert_eq!(n_choose_k_mod(n, k, p), n_choose_k(n, k).unwrap() % p);
+            }
+        }
+        // C(1000, 500) mod 10^9 + 7
+        assert_eq!(n_choose_k_mod(1000, 500, p), 159_835_829);
+    }
+
+    #[test]
+    fn modular_lucas() {
+        // n >= p, where k! would be divisible by p
+        for &p in &[2, 3, 5, 7, 13] {
+            for n in 0..60 {
+                for k in 0..=n {
+                    assert_eq!(n_choose_k_mod(n, k, p), n_choose_k(n, k).unwrap() % p);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+/// Returns the prime factorization of `n` as `(prime, exponent)` pairs in
+/// ascending order of the primes. 0 and 1 have no prime factors.
+///
+/// Trial division removes the factor 2 first and then tries odd candidates
+/// only. Once the candidate exceeds `√n` of what is left, the remaining
+/// cofactor must itself be prime, so this takes O(√n) divisions.
+pub fn prime_factors(n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    if n <= 1 {
+        return factors;
+    }
+
+    let mut n = n;
+    let twos = n.trailing_zeros();
+    if twos > 0 {
+        factors.push((2, twos));
+        n >>= twos;
+    }
+
+    let mut p = 3;
+    // p <= n / p is p * p <= n without overflowing
+    while p <= n / p {
+        let mut exponent = 0;
+        while n.is_multiple_of(p) {
+            n /= p;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((p, exponent));
+        }
+        p += 2;
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+/// Returns all positive divisors of `n` in ascending order, built from its
+/// prime factorization. 0 has no divisors listed.
+pub fn divisors(n: u64) -> Vec<u64> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut divisors = vec![1];
+    for (p, exponent) in prime_factors(n) {
+        let count = divisors.len();
+        let mut power = 1;
+        for _ in 0..exponent {
+            power *= p;
+            for i in 0..count {
+                divisors.push(divisors[i] * power);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn product(factors: &[(u64, u32)]) -> u64 {
+        factors.iter().map(|&(p, e)| p.pow(e)).product()
+    }
+
+    #[test]
+    fn small_numbers() {
+        assert!(prime_factors(0).is_empty());
+        assert!(prime_factors(1).is_empty());
+        assert_eq!(prime_factors(2), vec![(2, 1)]);
+        assert_eq!(divisors(0), vec![]);
+        assert_eq!(divisors(1), vec![1]);
+    }
+
+    #[test]
+    fn prime() {
+        assert_eq!(prime_factors(97), vec![(97, 1)]);
+        assert_eq!(prime_factors(1_000_000_007), vec![(1_000_000_007, 1)]);
+        assert_eq!(divisors(97), vec![1, 97]);
+    }
+
+    #[test]
+    fn prime_power() {
+        assert_eq!(prime_factors(8), vec![(2, 3)]);
+        assert_eq!(prime_factors(3u64.pow(20)), vec![(3, 20)]);
+        assert_eq!(divisors(8), vec![1, 2, 4, 8]);
+    }
+
+    #[test]
+    fn composite() {
+        assert_eq!(prime_factors(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(
+            divisors(360),
+            vec![
+                1, 2, 3, 4, 5, 6, 8, 9, 10, 12, 15, 18, 20, 24, 30, 36, 40, 45, 60, 72, 90, 120,
+                180, 360
+            ]
+        );
+        assert_eq!(
+            prime_factors(999_983 * 1_000_003),
+            vec![(999_983, 1), (1_000_003, 1)]
+        );
+    }
+
+    #[test]
+    fn reconstructs_n() {
+        for n in 1..2000 {
+            let factors = prime_factors(n);
+            assert_eq!(product(&factors), n);
+            assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+
+            let expected: Vec<u64> = (1..=n).filter(|d| n % d == 0).collect();
+            assert_eq!(divisors(n), expected);
+        }
+        assert_eq!(product(&prime_factors(u64::MAX)), u64::MAX);
+    }
+}
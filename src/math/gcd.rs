@@ -0,0 +1,64 @@
+/// Greatest common divisor by the iterative Euclidean algorithm.
+///
+/// By convention `gcd(0, 0) = 0`, which keeps `gcd(a, 0) = a` true for every
+/// `a` and makes 0 the identity of `gcd`.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Least common multiple, with `lcm(a, 0) = lcm(0, b) = 0`.
+///
+/// It is computed as `a / gcd(a, b) * b` rather than `a * b / gcd(a, b)`, so
+/// it only overflows if the result itself does not fit in a `u64`.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    a / gcd(a, b) * b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coprime() {
+        assert_eq!(gcd(17, 5), 1);
+        assert_eq!(gcd(35, 64), 1);
+        assert_eq!(lcm(4, 9), 36);
+    }
+
+    #[test]
+    fn common_factors() {
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(gcd(18, 48), 6);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(21, 6), 42);
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(0, 7), 0);
+        assert_eq!(lcm(7, 0), 0);
+        assert_eq!(lcm(0, 0), 0);
+    }
+
+    #[test]
+    fn large_values() {
+        // a * b overflows a u64, but the lcm itself fits.
+        let a: u64 = 6_000_000_000;
+        let b = 9_000_000_000;
+        assert!(a.checked_mul(b).is_none());
+        assert_eq!(gcd(a, b), 3_000_000_000);
+        assert_eq!(lcm(a, b), 18_000_000_000);
+        assert_eq!(lcm(u64::MAX, u64::MAX), u64::MAX);
+    }
+}
@@ -0,0 +1,134 @@
+use std::ops::{Add, Index, IndexMut, Mul};
+
+/// A dense matrix stored in row-major order.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> Matrix<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Default + Copy,
+{
+    /// Build a `rows` x `cols` matrix from its entries in row-major order.
+    /// Panics if `data` does not have exactly `rows * cols` entries.
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), rows * cols, "wrong number of entries");
+        Matrix { rows, cols, data }
+    }
+
+    /// A `rows` x `cols` matrix with every entry set to `T::default()`.
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix {
+            rows,
+            cols,
+            data: vec![T::default(); rows * cols],
+        }
+    }
+
+    /// The `n` x `n` identity matrix, with `T::from(1)` on the diagonal.
+    pub fn identity(n: usize) -> Self
+    where
+        T: From<u8>,
+    {
+        let mut matrix = Self::zeros(n, n);
+        for i in 0..n {
+            matrix[(i, i)] = T::from(1);
+        }
+        matrix
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut result = Self::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                result[(j, i)] = self[(i, j)];
+            }
+        }
+        result
+    }
+
+    /// Returns the product `self * other`, or `None` if the number of columns
+    /// of `self` differs from the number of rows of `other`.
+    pub fn multiply(&self, other: &Matrix<T>) -> Option<Matrix<T>> {
+        if self.cols != other.rows {
+            return None;
+        }
+
+        let mut result = Self::zeros(self.rows, other.cols);
+        for i in 0..self.rows {
+            // i-k-j loop order walks both `other` and `result` row by row.
+            for k in 0..self.cols {
+                let a = self[(i, k)];
+                for j in 0..other.cols {
+                    result[(i, j)] = result[(i, j)] + a * other[(k, j)];
+                }
+            }
+        }
+        Some(result)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn product() {
+        let a = Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let b = Matrix::from_vec(3, 2, vec![7, 8, 9, 10, 11, 12]);
+        assert_eq!(
+            a.multiply(&b),
+            Some(Matrix::from_vec(2, 2, vec![58, 64, 139, 154]))
+        );
+        assert_eq!(b.multiply(&a).map(|c| (c.rows(), c.cols())), Some((3, 3)));
+    }
+
+    #[test]
+    fn dimension_mismatch() {
+        let a: Matrix<i32> = Matrix::zeros(2, 3);
+        assert_eq!(a.multiply(&a), None);
+    }
+
+    #[test]
+    fn identity_is_neutral() {
+        let a = Matrix::from_vec(2, 3, vec![1.5, -2.0, 3.0, 0.0, 4.0, 0.25]);
+        assert_eq!(Matrix::identity(2).multiply(&a), Some(a.clone()));
+        assert_eq!(a.multiply(&Matrix::identity(3)), Some(a));
+    }
+
+    #[test]
+    fn transpose() {
+        let a = Matrix::from_vec(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let t = a.transpose();
+        assert_eq!(t, Matrix::from_vec(3, 2, vec![1, 4, 2, 5, 3, 6]));
+        assert_eq!(t.transpose(), a);
+        assert_eq!(t[(2, 1)], 6);
+    }
+}
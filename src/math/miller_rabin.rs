@@ -0,0 +1,82 @@
+use super::mod_pow;
+
+/// Deterministic Miller-Rabin primality test for any `u64`.
+///
+/// Write `n - 1 = d * 2^s` with `d` odd. For a prime `n` and any witness `a`,
+/// either `a^d = 1 (mod n)` or `a^(d * 2^r) = -1 (mod n)` for some `r < s`.
+/// A composite `n` fails this for most `a`, and checking the first twelve
+/// primes as witnesses is known to be exact for all
+/// `n < 318_665_857_834_031_151_167_461`, about `3.2 * 10^23`, which covers
+/// the whole `u64` range. Products are formed in `u128`, so nothing
+/// overflows.
+pub fn is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    match n {
+        0 | 1 => return false,
+        2 | 3 => return true,
+        _ if n.is_multiple_of(2) => return false,
+        _ => {}
+    }
+    // Also covers n being one of the witnesses, for which a = n fails below.
+    if let Some(&p) = WITNESSES.iter().find(|&&p| n.is_multiple_of(p)) {
+        return n == p;
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    'witness: for &a in WITNESSES.iter() {
+        let mut x = mod_pow(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = (x as u128 * x as u128 % n as u128) as u64;
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::primes_up_to;
+
+    #[test]
+    fn matches_sieve() {
+        let primes = primes_up_to(10_000);
+        let mut primes = primes.iter().peekable();
+        for n in 0..10_000u64 {
+            let expected = primes.peek() == Some(&&(n as usize));
+            if expected {
+                primes.next();
+            }
+            assert_eq!(is_prime(n), expected, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn large_primes() {
+        assert!(is_prime(1_000_000_007));
+        assert!(is_prime(2_147_483_647));
+        assert!(is_prime(18_446_744_073_709_551_557)); // largest u64 prime
+        assert!(is_prime((1 << 61) - 1));
+    }
+
+    #[test]
+    fn large_composites() {
+        assert!(!is_prime(u64::MAX));
+        assert!(!is_prime(1_000_000_007 * 998_244_353));
+        // strong pseudoprime to the bases 2, 3, 5 and 7
+        assert!(!is_prime(3_215_031_751));
+        // strong pseudoprime to the first nine primes, 149491 * 747451 * 34233211
+        assert!(!is_prime(3_825_123_056_546_413_051));
+        // Carmichael number
+        assert!(!is_prime(561));
+        assert!(!is_prime(4_294_967_297)); // 641 * 6700417
+    }
+}
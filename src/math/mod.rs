@@ -0,0 +1,23 @@
+mod combinatorics;
+mod factorization;
+mod gcd;
+mod matrix;
+mod miller_rabin;
+mod mod_inverse;
+mod mod_pow;
+mod sieve;
+
+pub use self::combinatorics::n_choose_k;
+pub use self::combinatorics::n_choose_k_mod;
+pub use self::combinatorics::permutations;
+pub use self::factorization::divisors;
+pub use self::factorization::prime_factors;
+pub use self::gcd::gcd;
+pub use self::gcd::lcm;
+pub use self::matrix::Matrix;
+pub use self::miller_rabin::is_prime;
+pub use self::mod_inverse::ext_gcd;
+pub use self::mod_inverse::mod_inverse;
+pub use self::mod_pow::mod_pow;
+pub use self::sieve::is_prime_sieve;
+pub use self::sieve::primes_up_to;
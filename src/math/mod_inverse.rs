@@ -0,0 +1,121 @@
+/// Extended Euclidean algorithm.
+///
+/// Returns `(g, x, y)` where `g = gcd(a, b) >= 0` and `a * x + b * y = g`.
+/// The algorithm keeps the invariant `a * x_i + b * y_i = r_i` for every
+/// remainder `r_i` of the ordinary Euclidean algorithm, so the coefficients
+/// of the last non-zero remainder solve the equation. `|x|` and `|y|` stay
+/// below `|b|` and `|a|` respectively, unless one of them is 0.
+///
+/// The steps run in `i128`, since `i64::MIN / -1` and `-i64::MIN` overflow
+/// an `i64`. Panics if `g` does not fit in an `i64`, which only happens for
+/// `gcd(i64::MIN, 0) = gcd(i64::MIN, i64::MIN) = 2^63`.
+pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r0, mut r1) = (a as i128, b as i128);
+    let (mut x0, mut x1) = (1i128, 0i128);
+    let (mut y0, mut y1) = (0i128, 1i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 {
+        (r0, x0, y0) = (-r0, -x0, -y0);
+    }
+    assert!(r0 <= i64::MAX as i128, "gcd does not fit in an i64");
+    (r0 as i64, x0 as i64, y0 as i64)
+}
+
+/// Returns the inverse of `a` modulo `m`, i.e. the `x` in `[0, m)` with
+/// `a * x = 1 (mod m)`, or `None` if `a` and `m` are not coprime.
+///
+/// Every value is congruent to 0 modulo 1, so `mod_inverse(a, 1) = Some(0)`.
+/// Panics if `m` is not positive.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    assert!(m > 0, "modulus must be positive");
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    if g == 1 {
+        Some(x.rem_euclid(m))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bezout_identity() {
+        for a in -30..30i64 {
+            for b in -30..30i64 {
+                let (g, x, y) = ext_gcd(a, b);
+                assert_eq!(a * x + b * y, g);
+                assert_eq!(
+                    g as u64,
+                    super::super::gcd(a.unsigned_abs(), b.unsigned_abs())
+                );
+            }
+        }
+        assert_eq!(ext_gcd(240, 46), (2, -9, 47));
+    }
+
+    #[test]
+    fn inverses() {
+        for &(a, m) in &[
+            (3, 11),
+            (10, 17),
+            (7, 26),
+            (-3, 11),
+            (123_456_789, 1_000_000_007),
+        ] {
+            let inverse = mod_inverse(a, m).unwrap();
+            assert!((0..m).contains(&inverse));
+            assert_eq!((a as i128 * inverse as i128).rem_euclid(m as i128), 1);
+        }
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(1, 1), Some(0));
+    }
+
+    #[test]
+    fn large_modulus() {
+        let m = i64::MAX; // 7^2 * 73 * 127 * 337 * 92737 * 649657
+        let inverse = mod_inverse(2, m).unwrap();
+        assert_eq!((2 * inverse as i128) % m as i128, 1);
+        assert_eq!(mod_inverse(7, m), None);
+    }
+
+    #[test]
+    fn extreme_values() {
+        let values = [i64::MIN, i64::MIN + 1, -2, -1, 0, 1, 2, 3, i64::MAX];
+        for &a in &values {
+            for &b in &values {
+                if (a == 0 || a == i64::MIN) && (b == 0 || b == i64::MIN) && a | b != 0 {
+                    continue;
+                }
+                let (g, x, y) = ext_gcd(a, b);
+                assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+                assert_eq!(
+                    g as u64,
+                    super::super::gcd(a.unsigned_abs(), b.unsigned_abs())
+                );
+            }
+        }
+        assert_eq!(ext_gcd(i64::MIN, -1), (1, 0, -1));
+        assert_eq!(mod_inverse(i64::MIN, 3), Some(1));
+        assert_eq!(mod_inverse(i64::MIN, i64::MAX), Some(i64::MAX - 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "gcd does not fit in an i64")]
+    fn gcd_of_min_overflows() {
+        ext_gcd(i64::MIN, 0);
+    }
+
+    #[test]
+    fn not_coprime() {
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(0, 7), None);
+        assert_eq!(mod_inverse(14, 7), None);
+    }
+}
@@ -0,0 +1,70 @@
+/// Computes `base.pow(exp) % modulus` by binary exponentiation.
+///
+/// The exponent is processed bit by bit, squaring the base at each step, so
+/// this takes O(log exp) multiplications. Products are formed in `u128`,
+/// which cannot overflow because both factors are below `modulus <= u64::MAX`.
+///
+/// Every value is congruent to 0 modulo 1, so `mod_pow(b, e, 1) = 0` even
+/// for `e = 0`; otherwise `mod_pow(b, 0, m) = 1`. Panics if `modulus` is 0.
+pub fn mod_pow(base: u64, exp: u64, modulus: u64) -> u64 {
+    assert!(modulus != 0, "modulus must be positive");
+    if modulus == 1 {
+        return 0;
+    }
+
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut exp = exp;
+    let mut result: u128 = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_naive() {
+        for base in 0..20u64 {
+            for exp in 0..12u64 {
+                for modulus in 1..30u64 {
+                    let mut expected = 1 % modulus;
+                    for _ in 0..exp {
+                        expected = expected * base % modulus;
+                    }
+                    assert_eq!(mod_pow(base, exp, modulus), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert_eq!(mod_pow(5, 0, 7), 1);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+        assert_eq!(mod_pow(5, 3, 1), 0);
+        assert_eq!(mod_pow(0, 0, 7), 1);
+        assert_eq!(mod_pow(0, 5, 7), 0);
+    }
+
+    #[test]
+    fn large_exponent() {
+        assert_eq!(mod_pow(2, 1_000_000, 1_000_000_007), 235_042_059);
+        // Fermat's little theorem: a^(p - 1) = 1 (mod p)
+        assert_eq!(mod_pow(3, 1_000_000_006, 1_000_000_007), 1);
+    }
+
+    #[test]
+    fn large_modulus() {
+        let m = u64::MAX;
+        assert_eq!(mod_pow(m - 1, 2, m), 1);
+        assert_eq!(mod_pow(2, 64, m), 1);
+    }
+}
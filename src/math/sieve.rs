@@ -0,0 +1,92 @@
+/// Sieve of Eratosthenes
+///
+/// Returns all primes `<= n` in ascending order.
+///
+/// Even numbers other than 2 are never stored: entry `i` of the sieve stands
+/// for the odd number `2 * i + 1`. This halves both the work and the memory,
+/// which is about `n / 2` bytes (one `bool` per odd number up to `n`).
+pub fn primes_up_to(n: usize) -> Vec<usize> {
+    if n < 2 {
+        return Vec::new();
+    }
+
+    let sieve = odd_sieve(n);
+    let mut primes = vec![2];
+    primes.extend(
+        sieve
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter(|&(_, &is_prime)| is_prime)
+            .map(|(i, _)| 2 * i + 1),
+    );
+    primes
+}
+
+/// Returns true iff `n` is prime, by sieving all numbers up to `n`.
+///
+/// This takes O(n log log n) time and O(n) memory, so it is only a
+/// convenience for small `n`. Use `primes_up_to` to test many numbers.
+pub fn is_prime_sieve(n: usize) -> bool {
+    match n {
+        0 | 1 => false,
+        2 => true,
+        _ if n.is_multiple_of(2) => false,
+        _ => odd_sieve(n)[n / 2],
+    }
+}
+
+// sieve[i] is true iff 2 * i + 1 is prime, for all odd numbers up to n.
+// Entry 0 (the number 1) is false.
+fn odd_sieve(n: usize) -> Vec<bool> {
+    let mut sieve = vec![true; n.div_ceil(2)];
+    sieve[0] = false;
+
+    let mut p = 3;
+    while p * p <= n {
+        if sieve[p / 2] {
+            // Smaller multiples of p have a smaller prime factor and are
+            // crossed off already. Step by 2p to skip the even multiples.
+            let mut multiple = p * p;
+            while multiple <= n {
+                sieve[multiple / 2] = false;
+                multiple += 2 * p;
+            }
+        }
+        p += 2;
+    }
+
+    sieve
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_primes() {
+        assert_eq!(primes_up_to(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(primes_up_to(29).last(), Some(&29));
+    }
+
+    #[test]
+    fn no_primes() {
+        assert!(primes_up_to(0).is_empty());
+        assert!(primes_up_to(1).is_empty());
+        assert_eq!(primes_up_to(2), vec![2]);
+    }
+
+    #[test]
+    fn prime_count() {
+        assert_eq!(primes_up_to(1000).len(), 168);
+        assert_eq!(primes_up_to(100_000).len(), 9592);
+    }
+
+    #[test]
+    fn is_prime() {
+        let primes = primes_up_to(200);
+        for n in 0..=200 {
+            assert_eq!(is_prime_sieve(n), primes.contains(&n));
+        }
+    }
+}
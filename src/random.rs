@@ -0,0 +1,41 @@
+//! A tiny seeded pseudo-random number generator.
+//!
+//! The crate has no dependencies, so this xorshift generator stands in for
+//! the `rand` crate wherever reproducible randomness is needed.
+
+/// Marsaglia's xorshift64* generator.
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    /// Create a new generator. A zero seed would get stuck at zero, so it is
+    /// replaced by a fixed non-zero constant.
+    pub fn new(seed: u64) -> Self {
+        XorShift {
+            state: if seed == 0 {
+                0x9E37_79B9_7F4A_7C15
+            } else {
+                seed
+            },
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Returns a number in the half-open range `[low, high)`.
+    pub fn gen_range(&mut self, low: u64, high: u64) -> u64 {
+        assert!(low < high);
+        low + self.next_u64() % (high - low)
+    }
+
+    /// Returns a float in `[0, 1)`, from the top 53 bits of the next number.
+    pub fn gen_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
@@ -1,39 +1,105 @@
 use std::cmp::{PartialEq, PartialOrd};
 
+/// Search the sorted slice `arr` for `item`.
+///
+/// If `arr` contains several elements equal to `item`, the index of any one
+/// of them may be returned. Use `lower_bound` to get the first one.
 pub fn binary_search<T: PartialEq + PartialOrd>(item: &T, arr: &[T]) -> Option<usize> {
+    // Search the half-open range [left, right) so that an empty slice needs
+    // no special casing.
     let mut left = 0;
-    let mut right = arr.len() - 1;
+    let mut right = arr.len();
 
     while left < right {
         let mid = left + (right - left) / 2;
 
         if &arr[mid] > item {
-            right = mid - 1;
+            right = mid;
         } else if &arr[mid] < item {
             left = mid + 1;
         } else {
-            left = mid;
-            break;
+            return Some(mid);
         }
     }
 
-    if &arr[left] == item {
-        Some(left)
-    } else {
-        None
+    None
+}
+
+/// Returns the index of the first element of the sorted slice `arr` that is
+/// not less than `item`, or `arr.len()` if there is none.
+pub fn lower_bound<T: PartialOrd>(item: &T, arr: &[T]) -> usize {
+    let mut left = 0;
+    let mut right = arr.len();
+
+    while left < right {
+        let mid = left + (right - left) / 2;
+
+        if &arr[mid] < item {
+            left = mid + 1;
+        } else {
+            right = mid;
+        }
+    }
+
+    left
+}
+
+/// Returns the index of the first element of the sorted slice `arr` that is
+/// greater than `item`, or `arr.len()` if there is none.
+pub fn upper_bound<T: PartialOrd>(item: &T, arr: &[T]) -> usize {
+    let mut left = 0;
+    let mut right = arr.len();
+
+    while left < right {
+        let mid = left + (right - left) / 2;
+
+        if &arr[mid] > item {
+            right = mid;
+        } else {
+            left = mid + 1;
+        }
+    }
+
+    left
+}
+
+/// Returns the index of the first element of `arr` for which `pred` is
+/// false, or `arr.len()` if there is none.
+///
+/// `arr` must be partitioned by `pred`: every element satisfying it comes
+/// before every element that does not. `lower_bound(item, arr)` is the
+/// special case `partition_point(arr, |x| x < item)`.
+pub fn partition_point<T, P>(arr: &[T], pred: P) -> usize
+where
+    P: Fn(&T) -> bool,
+{
+    let mut left = 0;
+    let mut right = arr.len();
+
+    while left < right {
+        let mid = left + (right - left) / 2;
+
+        if pred(&arr[mid]) {
+            left = mid + 1;
+        } else {
+            right = mid;
+        }
     }
+
+    left
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    // TODO: Fix this test; currently fails due to underflow
-    // #[test]
-    // fn empty() {
-    //     let index = binary_search(&"a", &vec![]);
-    //     assert_eq!(index, None);
-    // }
+    #[test]
+    fn empty() {
+        let index = binary_search(&"a", &[]);
+        assert_eq!(index, None);
+        assert_eq!(lower_bound(&"a", &[]), 0);
+        assert_eq!(upper_bound(&"a", &[]), 0);
+    }
 
     #[test]
     fn search_strings() {
@@ -61,4 +127,50 @@ mod tests {
         let index = binary_search(&5, &vec![1, 2, 3, 4]);
         assert_eq!(index, None);
     }
+
+    #[test]
+    fn duplicates() {
+        let arr = [1, 2, 2, 2, 3];
+        let index = binary_search(&2, &arr).unwrap();
+        assert!((1..=3).contains(&index));
+        assert_eq!(lower_bound(&2, &arr), 1);
+        assert_eq!(upper_bound(&2, &arr), 4);
+    }
+
+    #[test]
+    fn bounds_of_missing_item() {
+        let arr = [10, 20, 30];
+        assert_eq!(lower_bound(&5, &arr), 0);
+        assert_eq!(upper_bound(&5, &arr), 0);
+        assert_eq!(lower_bound(&25, &arr), 2);
+        assert_eq!(upper_bound(&25, &arr), 2);
+        assert_eq!(lower_bound(&35, &arr), 3);
+        assert_eq!(upper_bound(&30, &arr), 3);
+    }
+
+    #[test]
+    fn partition_point_all_true_or_false() {
+        let arr = [1, 2, 3, 4];
+        assert_eq!(partition_point(&arr, |_| true), 4);
+        assert_eq!(partition_point(&arr, |_| false), 0);
+        assert_eq!(partition_point(&[] as &[i32], |_| true), 0);
+    }
+
+    #[test]
+    fn partition_point_mixed() {
+        let arr = [2, 4, 6, 8, 1, 3, 5];
+        assert_eq!(partition_point(&arr, |x| x % 2 == 0), 4);
+
+        let words = ["fig", "kiwi", "apple", "banana", "cherry"];
+        assert_eq!(partition_point(&words, |w| w.len() < 6), 3);
+
+        // agrees with lower_bound on sorted input
+        let sorted = [1, 3, 3, 5, 7, 9];
+        for item in 0..11 {
+            assert_eq!(
+                partition_point(&sorted, |x| *x < item),
+                lower_bound(&item, &sorted)
+            );
+        }
+    }
 }
@@ -0,0 +1,66 @@
+use super::binary_search;
+use std::cmp::{PartialEq, PartialOrd};
+
+/// Search the sorted slice `arr` for `item` by doubling an upper bound until
+/// it passes `item`, then binary searching the last doubling step.
+///
+/// This takes O(log i) comparisons where `i` is the position of `item`, so
+/// it beats a plain binary search when matches are likely near the front.
+pub fn exponential_search<T: PartialEq + PartialOrd>(item: &T, arr: &[T]) -> Option<usize> {
+    if arr.is_empty() {
+        return None;
+    }
+    if &arr[0] == item {
+        return Some(0);
+    }
+
+    let mut bound = 1;
+    while bound < arr.len() && &arr[bound] < item {
+        bound *= 2;
+    }
+
+    // item lies in arr[bound / 2..=bound] if it is present at all
+    let low = bound / 2;
+    let high = std::cmp::min(bound + 1, arr.len());
+    binary_search(item, &arr[low..high]).map(|index| index + low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(exponential_search(&1, &[]), None);
+    }
+
+    #[test]
+    fn first_element() {
+        assert_eq!(exponential_search(&1, &[1, 2, 3]), Some(0));
+        assert_eq!(exponential_search(&"a", &["a"]), Some(0));
+    }
+
+    #[test]
+    fn every_element() {
+        let arr: Vec<u32> = (0..100).map(|i| 2 * i).collect();
+        for (i, value) in arr.iter().enumerate() {
+            assert_eq!(exponential_search(value, &arr), Some(i));
+        }
+    }
+
+    #[test]
+    fn beyond_doubling_bound() {
+        // 10 lies past the last power of two below the length.
+        let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        assert_eq!(exponential_search(&10, &arr), Some(9));
+        assert_eq!(exponential_search(&9, &arr), Some(8));
+    }
+
+    #[test]
+    fn not_found() {
+        let arr = [1, 3, 5, 7, 9, 11];
+        assert_eq!(exponential_search(&0, &arr), None);
+        assert_eq!(exponential_search(&6, &arr), None);
+        assert_eq!(exponential_search(&12, &arr), None);
+    }
+}
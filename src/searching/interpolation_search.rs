@@ -0,0 +1,86 @@
+/// Search the sorted slice `arr` for `item`, probing where `item` would be
+/// if the values were spread evenly between the current bounds.
+///
+/// On uniformly distributed data this takes O(log log n) probes on average.
+/// The worst case, e.g. exponentially growing values, degrades to O(n).
+pub fn interpolation_search(item: i64, arr: &[i64]) -> Option<usize> {
+    if arr.is_empty() {
+        return None;
+    }
+
+    let mut low = 0;
+    let mut high = arr.len() - 1;
+
+    while low <= high && arr[low] <= item && item <= arr[high] {
+        // All remaining values are equal; also avoids dividing by zero below.
+        if arr[high] == arr[low] {
+            return if arr[low] == item { Some(low) } else { None };
+        }
+
+        // Widen to i128 so neither the value range nor the product overflows.
+        let offset = (item as i128 - arr[low] as i128) * (high - low) as i128
+            / (arr[high] as i128 - arr[low] as i128);
+        let probe = low + offset as usize;
+
+        if arr[probe] == item {
+            return Some(probe);
+        } else if arr[probe] < item {
+            low = probe + 1;
+        } else {
+            // probe > low here, since arr[low] <= item < arr[probe]
+            high = probe - 1;
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(interpolation_search(1, &[]), None);
+    }
+
+    #[test]
+    fn uniform() {
+        let arr: Vec<i64> = (0..1000).map(|i| i * 3).collect();
+        for (i, &value) in arr.iter().enumerate() {
+            assert_eq!(interpolation_search(value, &arr), Some(i));
+        }
+        assert_eq!(interpolation_search(1, &arr), None);
+        assert_eq!(interpolation_search(-3, &arr), None);
+        assert_eq!(interpolation_search(3000, &arr), None);
+    }
+
+    #[test]
+    fn duplicates() {
+        let arr = [1, 2, 2, 2, 2, 5, 5, 9];
+        assert_eq!(arr[interpolation_search(2, &arr).unwrap()], 2);
+        assert_eq!(arr[interpolation_search(5, &arr).unwrap()], 5);
+        assert_eq!(interpolation_search(3, &arr), None);
+
+        let same = [7; 10];
+        assert_eq!(interpolation_search(7, &same), Some(0));
+        assert_eq!(interpolation_search(8, &same), None);
+    }
+
+    #[test]
+    fn non_uniform() {
+        let arr: Vec<i64> = (0..62).map(|i| 1 << i).collect();
+        for (i, &value) in arr.iter().enumerate() {
+            assert_eq!(interpolation_search(value, &arr), Some(i));
+        }
+        assert_eq!(interpolation_search(3, &arr), None);
+    }
+
+    #[test]
+    fn extreme_values() {
+        let arr = [i64::MIN, -1, 0, 1, i64::MAX];
+        for (i, &value) in arr.iter().enumerate() {
+            assert_eq!(interpolation_search(value, &arr), Some(i));
+        }
+    }
+}
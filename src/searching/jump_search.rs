@@ -0,0 +1,80 @@
+use std::cmp::min;
+
+/// Search the sorted slice `arr` for `item` by jumping ahead in blocks of
+/// `√n` elements until the block that may hold `item`, then scanning that
+/// block linearly.
+///
+/// This takes O(√n) comparisons, more than binary search, but it only ever
+/// moves forward, which suits storage where going back is expensive. If
+/// `arr` contains several elements equal to `item`, the first is returned.
+pub fn jump_search<T: Ord>(item: &T, arr: &[T]) -> Option<usize> {
+    let len = arr.len();
+    let step = len.isqrt().max(1);
+
+    // Skip every block whose last element is still less than item.
+    let mut start = 0;
+    while start + step < len && &arr[start + step - 1] < item {
+        start += step;
+    }
+
+    let end = min(start + step, len);
+    arr[start..end]
+        .iter()
+        .position(|value| value >= item)
+        .filter(|&i| &arr[start + i] == item)
+        .map(|i| start + i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(jump_search(&1, &[]), None);
+    }
+
+    #[test]
+    fn found() {
+        let arr: Vec<i32> = (0..100).map(|i| 2 * i).collect();
+        for (i, value) in arr.iter().enumerate() {
+            assert_eq!(jump_search(value, &arr), Some(i));
+        }
+        assert_eq!(
+            jump_search(&"c", &["a", "b", "c", "d", "google", "zoo"]),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn not_found() {
+        let arr: Vec<i32> = (0..100).map(|i| 2 * i).collect();
+        for value in (-1..200).step_by(2) {
+            assert_eq!(jump_search(&value, &arr), None);
+        }
+        assert_eq!(jump_search(&200, &arr), None);
+    }
+
+    #[test]
+    fn block_boundaries() {
+        // With 16 elements the blocks are 0..4, 4..8, 8..12 and 12..16.
+        let arr: Vec<i32> = (0..16).collect();
+        for &i in &[0, 3, 4, 7, 8, 11, 12, 15] {
+            assert_eq!(jump_search(&i, &arr), Some(i as usize));
+        }
+    }
+
+    #[test]
+    fn duplicates_across_blocks() {
+        let arr = [1, 2, 2, 2, 2, 2, 2, 3, 4];
+        assert_eq!(jump_search(&2, &arr), Some(1));
+        assert_eq!(jump_search(&3, &arr), Some(7));
+    }
+
+    #[test]
+    fn single_element() {
+        assert_eq!(jump_search(&5, &[5]), Some(0));
+        assert_eq!(jump_search(&4, &[5]), None);
+        assert_eq!(jump_search(&6, &[5]), None);
+    }
+}
@@ -1,5 +1,17 @@
 mod binary_search;
+mod exponential_search;
+mod interpolation_search;
+mod jump_search;
 mod linear_search;
+mod ternary_search;
 
 pub use self::binary_search::binary_search;
+pub use self::binary_search::lower_bound;
+pub use self::binary_search::partition_point;
+pub use self::binary_search::upper_bound;
+pub use self::exponential_search::exponential_search;
+pub use self::interpolation_search::interpolation_search;
+pub use self::jump_search::jump_search;
 pub use self::linear_search::linear_search;
+pub use self::ternary_search::ternary_search_int;
+pub use self::ternary_search::ternary_search_max;
@@ -0,0 +1,102 @@
+use std::cmp::Reverse;
+
+/// Returns the point in `[lo, hi]` where the unimodal function `f` is
+/// largest, i.e. `f` strictly increases up to that point and strictly
+/// decreases after it.
+///
+/// Each of the `iters` steps evaluates `f` at the two points splitting the
+/// interval into thirds and drops the third that cannot contain the
+/// maximum, so the interval shrinks to `(2/3)^iters` of its length. To find
+/// a minimum, search the maximum of `-f` instead.
+pub fn ternary_search_max<F>(lo: f64, hi: f64, iters: usize, f: F) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut lo, mut hi) = (lo, hi);
+    for _ in 0..iters {
+        let third = (hi - lo) / 3.0;
+        let (m1, m2) = (lo + third, hi - third);
+        if f(m1) < f(m2) {
+            lo = m1;
+        } else {
+            hi = m2;
+        }
+    }
+    (lo + hi) / 2.0
+}
+
+/// Returns the integer in `lo..=hi` where the unimodal function `f` is
+/// largest, using O(log(hi - lo)) evaluations of `f`. The width of the
+/// range is computed in `i128`, so any `lo` and `hi` work, even `i64::MIN`
+/// and `i64::MAX`.
+pub fn ternary_search_int<F>(lo: i64, hi: i64, f: F) -> i64
+where
+    F: Fn(i64) -> i64,
+{
+    assert!(lo <= hi, "empty range");
+    let (mut lo, mut hi) = (lo, hi);
+    while hi as i128 - lo as i128 > 2 {
+        // A third of the width is below 2^63, so it fits in an i64 again.
+        let third = ((hi as i128 - lo as i128) / 3) as i64;
+        let (m1, m2) = (lo + third, hi - third);
+        if f(m1) < f(m2) {
+            lo = m1 + 1;
+        } else {
+            hi = m2;
+        }
+    }
+    // At most three candidates are left.
+    (lo..=hi).max_by_key(|&x| (f(x), Reverse(x))).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maximize_parabola() {
+        let x = ternary_search_max(-10.0, 10.0, 100, |x| -(x - 3.0) * (x - 3.0) + 5.0);
+        assert!((x - 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn minimize_convex() {
+        let f = |x: f64| (x - 1.5).abs() + x * x / 10.0;
+        let x = ternary_search_max(-5.0, 5.0, 100, |x| -f(x));
+        assert!((x - 1.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn maximum_at_border() {
+        let x = ternary_search_max(0.0, 1.0, 100, |x| x);
+        assert!((x - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn integer_domain() {
+        assert_eq!(ternary_search_int(-100, 100, |x| -(x - 17) * (x - 17)), 17);
+        assert_eq!(ternary_search_int(0, 1000, |x| -(x - 3) * (x - 3)), 3);
+        assert_eq!(ternary_search_int(0, 1000, |x| x), 1000);
+        assert_eq!(ternary_search_int(0, 1000, |x| -x), 0);
+    }
+
+    #[test]
+    fn integer_small_ranges() {
+        assert_eq!(ternary_search_int(4, 4, |x| x), 4);
+        assert_eq!(ternary_search_int(4, 5, |x| -x), 4);
+        for peak in 0..10 {
+            assert_eq!(ternary_search_int(0, 9, |x| -(x - peak).abs()), peak);
+        }
+    }
+
+    #[test]
+    fn integer_full_range() {
+        let (lo, hi) = (i64::MIN, i64::MAX);
+        let tent = |x: i64| if x <= 17 { x } else { 34 - x };
+        assert_eq!(ternary_search_int(lo, hi, tent), 17);
+        assert_eq!(ternary_search_int(lo, hi, |x| x), i64::MAX);
+        assert_eq!(ternary_search_int(lo, hi, |x| -(x / 2)), i64::MIN);
+        assert_eq!(ternary_search_int(lo, lo + 1, |x| -(x / 2)), i64::MIN);
+        assert_eq!(ternary_search_int(hi - 2, hi, |x| x), i64::MAX);
+    }
+}
@@ -0,0 +1,171 @@
+/// Sort a mutable slice with a stable in-place merge sort.
+///
+/// # Algorithm
+///
+/// Like merge sort, but merging two adjacent sorted runs `A` and `B` is done
+/// without a scratch buffer, using block rotations:
+///
+/// 1. Split the longer run in half, say `A = A1 A2`, and find where the
+///    first item of `A2` belongs in `B`, splitting `B = B1 B2` so that every
+///    item of `B1` is smaller than it.
+/// 2. Rotate `A2 B1` into `B1 A2`. Now `A1 B1` only holds items that belong
+///    before `A2 B2`, and equal items keep their relative order.
+/// 3. Merge `A1 B1` and `A2 B2` recursively.
+///
+/// Rotations are done in place by `slice::rotate_left`, so besides the
+/// O(log n) recursion stack no extra memory is needed. This is the simple
+/// rotation-based scheme behind block merge sorts such as WikiSort, without
+/// their internal buffers, and takes O(n log^2 n) time instead of O(n log n).
+pub fn block_sort<T: Ord>(arr: &mut [T]) {
+    const RUN: usize = 16;
+
+    // Short runs are cheapest to sort by insertion.
+    for run in arr.chunks_mut(RUN) {
+        insertion_sort(run);
+    }
+
+    let mut width = RUN;
+    while width < arr.len() {
+        for chunk in arr.chunks_mut(2 * width) {
+            if chunk.len() > width {
+                merge_in_place(chunk, width);
+            }
+        }
+        width *= 2;
+    }
+}
+
+fn insertion_sort<T: Ord>(arr: &mut [T]) {
+    for i in 1..arr.len() {
+        let mut j = i;
+        while j > 0 && arr[j] < arr[j - 1] {
+            arr.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+// Stably merge the sorted runs arr[..mid] and arr[mid..].
+fn merge_in_place<T: Ord>(arr: &mut [T], mid: usize) {
+    let (left, right) = (mid, arr.len() - mid);
+    if left == 0 || right == 0 {
+        return;
+    }
+    if left + right == 2 {
+        if arr[1] < arr[0] {
+            arr.swap(0, 1);
+        }
+        return;
+    }
+
+    // Items of the right run go before equal items of the left run, never
+    // the other way round.
+    let (cut_left, cut_right) = if left >= right {
+        let cut_left = left / 2;
+        let pivot = &arr[cut_left];
+        (cut_left, arr[mid..].partition_point(|x| x < pivot))
+    } else {
+        let cut_right = right / 2;
+        let pivot = &arr[mid + cut_right];
+        (arr[..mid].partition_point(|x| x <= pivot), cut_right)
+    };
+
+    arr[cut_left..mid + cut_right].rotate_left(mid - cut_left);
+    let new_mid = cut_left + cut_right;
+    let (first, second) = arr.split_at_mut(new_mid);
+    merge_in_place(first, cut_left);
+    merge_in_place(second, mid - cut_left);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::is_sorted;
+    use super::*;
+
+    #[test]
+    fn descending() {
+        let mut ve1: Vec<i32> = (0..100).rev().collect();
+        block_sort(&mut ve1);
+        assert!(is_sorted(&ve1));
+    }
+
+    #[test]
+    fn pre_sorted() {
+        let mut ve2: Vec<i32> = (0..100).collect();
+        block_sort(&mut ve2);
+        assert!(is_sorted(&ve2));
+    }
+
+    #[test]
+    fn empty_and_single() {
+        let mut empty: Vec<i32> = vec![];
+        block_sort(&mut empty);
+        assert!(empty.is_empty());
+
+        let mut single = vec![1];
+        block_sort(&mut single);
+        assert_eq!(single, vec![1]);
+    }
+
+    // The stability check compares against the crate's merge sort, which is
+    // stable as well.
+    #[cfg(feature = "alloc")]
+    mod stability {
+        use super::*;
+        use crate::random::XorShift;
+        use crate::sorting::merge_sort;
+        use std::cmp::Ordering;
+
+        // Compares by `key` only, so the order of equal keys is observable.
+        #[derive(Debug, Clone, Copy)]
+        struct Item {
+            key: u64,
+            original_index: usize,
+        }
+
+        impl PartialEq for Item {
+            fn eq(&self, other: &Self) -> bool {
+                self.key == other.key
+            }
+        }
+
+        impl Eq for Item {}
+
+        impl PartialOrd for Item {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Item {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.key.cmp(&other.key)
+            }
+        }
+
+        #[test]
+        fn stable() {
+            let mut rng = XorShift::new(31);
+            for &n in &[5, 17, 100, 1000, 1234] {
+                let items: Vec<Item> = (0..n)
+                    .map(|original_index| Item {
+                        key: rng.gen_range(0, 20),
+                        original_index,
+                    })
+                    .collect();
+
+                let mut expected = items.clone();
+                merge_sort(&mut expected);
+                let mut actual = items;
+                block_sort(&mut actual);
+
+                let order = |arr: &[Item]| -> Vec<(u64, usize)> {
+                    arr.iter()
+                        .map(|item| (item.key, item.original_index))
+                        .collect()
+                };
+                assert_eq!(order(&actual), order(&expected));
+            }
+        }
+    }
+}
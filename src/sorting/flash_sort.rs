@@ -0,0 +1,138 @@
+/// Sort a slice of finite floats using flash sort.
+///
+/// # Algorithm
+///
+/// 1. Classification: split the range from the minimum to the maximum into
+///    `m ≈ 0.43 n` classes of equal width, and count the items falling into
+///    each class. The prefix sums of the counts give the part of the slice
+///    each class will occupy.
+/// 2. Permutation: walk every class's part and swap each item that belongs
+///    elsewhere straight into the next free slot of its own class. Each swap
+///    settles one item for good, so this takes O(n) time.
+/// 3. Insertion: items now only need to move within their class, which an
+///    insertion sort over the whole slice finishes.
+///
+/// The classes are only balanced if the values are spread roughly uniformly
+/// over their range: then every class holds O(1) items and the whole sort
+/// takes O(n) time. Clustered values crowd into a few classes, in the worst
+/// case all but one item into a single class, and the insertion phase
+/// degrades to O(n^2). The result is correct either way. Memory use is
+/// O(m) for the class bounds.
+///
+/// Panics if `arr` contains NaN or an infinity.
+pub fn flash_sort(arr: &mut [f64]) {
+    let len = arr.len();
+    assert!(arr.iter().all(|x| x.is_finite()), "values must be finite");
+    if len < 2 {
+        return;
+    }
+
+    let min = arr.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = arr.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    if min == max {
+        return;
+    }
+
+    let classes = (len * 43 / 100).max(2);
+    // Halving every term keeps max - min from overflowing for values near
+    // the ends of the f64 range.
+    let scale = (classes - 1) as f64 / (max / 2.0 - min / 2.0);
+    let class = |x: f64| (((x / 2.0 - min / 2.0) * scale) as usize).min(classes - 1);
+
+    // start[k]..start[k + 1] is the part of the slice for class k
+    let mut start = alloc::vec![0; classes + 1];
+    for &x in arr.iter() {
+        start[class(x) + 1] += 1;
+    }
+    for k in 0..classes {
+        start[k + 1] += start[k];
+    }
+
+    // Everything before next[k] in the part of class k belongs there.
+    let mut next = start.clone();
+    for k in 0..classes {
+        while next[k] < start[k + 1] {
+            let c = class(arr[next[k]]);
+            if c != k {
+                arr.swap(next[k], next[c]);
+            }
+            next[c] += 1;
+        }
+    }
+
+    for i in 1..len {
+        let mut j = i;
+        while j > 0 && arr[j - 1] > arr[j] {
+            arr.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::is_sorted;
+    use super::*;
+    use crate::random::XorShift;
+
+    fn check(mut arr: Vec<f64>) {
+        let mut expected = arr.clone();
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        flash_sort(&mut arr);
+        assert!(is_sorted(&arr));
+        assert_eq!(arr, expected);
+    }
+
+    fn uniform(rng: &mut XorShift) -> f64 {
+        (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    #[test]
+    fn small() {
+        check(vec![]);
+        check(vec![1.5]);
+        check(vec![2.0, 1.0]);
+        check(vec![3.0, 3.0, 3.0]);
+        check(vec![0.5, -2.25, 10.0, 0.5, -0.0, 7.75]);
+    }
+
+    #[test]
+    fn uniform_values() {
+        let mut rng = XorShift::new(79);
+        for &len in &[10, 100, 1000, 10_000] {
+            check(
+                (0..len)
+                    .map(|_| uniform(&mut rng) * 200.0 - 100.0)
+                    .collect(),
+            );
+        }
+    }
+
+    #[test]
+    fn clustered_values() {
+        let mut rng = XorShift::new(83);
+        // almost everything lands in the first class
+        let mut arr: Vec<f64> = (0..1000).map(|_| uniform(&mut rng) * 1e-6).collect();
+        arr.push(1e6);
+        check(arr);
+
+        // a few tight clusters far apart
+        let arr = (0..1000)
+            .map(|i| (i % 4) as f64 * 1e9 + uniform(&mut rng))
+            .collect();
+        check(arr);
+    }
+
+    #[test]
+    fn duplicates_and_extremes() {
+        let mut rng = XorShift::new(89);
+        check((0..500).map(|_| rng.gen_range(0, 5) as f64).collect());
+        check(vec![f64::MAX, f64::MIN, 0.0, f64::MAX, 1.0, f64::MIN]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan() {
+        flash_sort(&mut [1.0, f64::NAN, 0.0]);
+    }
+}
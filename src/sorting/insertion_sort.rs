@@ -1,4 +1,5 @@
-use std::cmp;
+use alloc::vec::Vec;
+use core::cmp;
 
 #[allow(dead_code)]
 pub fn insertion_sort<T>(arr: &[T]) -> Vec<T>
@@ -0,0 +1,105 @@
+use super::block_sort;
+use alloc::vec::{IntoIter, Vec};
+use core::cmp::Ordering;
+
+/// Sorting adaptors for any iterator.
+///
+/// Both methods collect the items into a `Vec`, sort it with the stable
+/// `block_sort` and return an iterator over the result, so equal items keep
+/// their relative order.
+pub trait SortedIterator: Iterator + Sized {
+    /// Returns the items in ascending order.
+    fn sorted(self) -> IntoIter<Self::Item>
+    where
+        Self::Item: Ord,
+    {
+        let mut items: Vec<Self::Item> = self.collect();
+        block_sort(&mut items);
+        items.into_iter()
+    }
+
+    /// Returns the items in the order given by `compare`.
+    fn sorted_by<F>(self, compare: F) -> IntoIter<Self::Item>
+    where
+        F: Fn(&Self::Item, &Self::Item) -> Ordering,
+    {
+        let mut items: Vec<By<Self::Item, F>> = self
+            .map(|item| By {
+                item,
+                compare: &compare,
+            })
+            .collect();
+        block_sort(&mut items);
+        items
+            .into_iter()
+            .map(|by| by.item)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl<I: Iterator> SortedIterator for I {}
+
+// Orders items by a comparator, so that `block_sort` can be used with it.
+struct By<'a, T, F> {
+    item: T,
+    compare: &'a F,
+}
+
+impl<T, F: Fn(&T, &T) -> Ordering> PartialEq for By<'_, T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T, F: Fn(&T, &T) -> Ordering> Eq for By<'_, T, F> {}
+
+impl<T, F: Fn(&T, &T) -> Ordering> PartialOrd for By<'_, T, F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, F: Fn(&T, &T) -> Ordering> Ord for By<'_, T, F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.compare)(&self.item, &other.item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::is_sorted;
+    use super::*;
+
+    #[test]
+    fn sorted() {
+        let v: Vec<_> = (0..10).rev().sorted().collect();
+        assert!(is_sorted(&v));
+        assert_eq!(v, (0..10).collect::<Vec<_>>());
+
+        let empty: Vec<i32> = std::iter::empty().sorted().collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn sorted_by_reverse() {
+        let v: Vec<_> = [3, 1, 4, 1, 5, 9, 2, 6]
+            .iter()
+            .sorted_by(|a, b| b.cmp(a))
+            .collect();
+        assert_eq!(v, vec![&9, &6, &5, &4, &3, &2, &1, &1]);
+    }
+
+    #[test]
+    fn sorted_by_is_stable() {
+        let words = ["pear", "fig", "plum", "kiwi", "date", "apple"];
+        let v: Vec<_> = words
+            .iter()
+            .sorted_by(|a, b| a.len().cmp(&b.len()))
+            .collect();
+        assert_eq!(
+            v,
+            vec![&"fig", &"pear", &"plum", &"kiwi", &"date", &"apple"]
+        );
+    }
+}
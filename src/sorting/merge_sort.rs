@@ -1,20 +1,24 @@
 pub fn merge_sort<T: Ord>(array: &mut [T]) {
-    let mut index = vec![0usize; array.len()];
-    let mut buffer = vec![0usize; array.len()];
+    let mut index = alloc::vec![0usize; array.len()];
+    let mut buffer = alloc::vec![0usize; array.len()];
     merge_sort_conquer(array, &mut index, &mut buffer);
-    rearrange(array, &mut index, &mut buffer);
+    rearrange(array, &mut index);
 }
 
-fn rearrange<T>(array: &mut [T], index: &mut [usize], buffer: &mut [usize]) {
-    let n = array.len();
-
-    for i in 0..n {
-        buffer[index[i]] = i;
-    }
-
-    for i in 0..n {
-        array.swap(i, index[i]);
-        index[buffer[i]] = index[i];
+// Move array[index[i]] to position i for every i, following each cycle of
+// the permutation. Finished positions are marked with index[i] == i.
+fn rearrange<T>(array: &mut [T], index: &mut [usize]) {
+    for start in 0..array.len() {
+        let mut current = start;
+        while index[current] != current {
+            let next = index[current];
+            index[current] = current;
+            if next == start {
+                break;
+            }
+            array.swap(current, next);
+            current = next;
+        }
     }
 }
 
@@ -29,7 +33,7 @@ fn merge_sort_merge<T: Ord>(
     let mut it = index.iter_mut();
 
     while let (Some(l), Some(r)) = (lt.peek(), rt.peek()) {
-        *it.next().unwrap() = if array[**l] < array[**r] {
+        *it.next().unwrap() = if array[**l] <= array[**r] {
             *lt.next().unwrap()
         } else {
             *rt.next().unwrap()
@@ -94,6 +98,55 @@ mod tests {
         assert!(is_sorted(&v));
     }
 
+    #[test]
+    fn merge_sort_random() {
+        // Permutations of a hundred or more items used to be applied wrongly.
+        let mut rng = crate::random::XorShift::new(103);
+        for len in 0..300 {
+            let mut v: Vec<u64> = (0..len).map(|_| rng.gen_range(0, 1000)).collect();
+            let mut expected = v.clone();
+            expected.sort();
+            merge_sort(&mut v);
+            assert_eq!(v, expected);
+        }
+    }
+
+    #[test]
+    fn merge_sort_stable() {
+        // Sort by the key only and check that equal keys keep their order.
+        let mut v: Vec<(u8, usize)> = [3, 1, 2, 1, 3, 2, 1, 0, 3]
+            .iter()
+            .enumerate()
+            .map(|(i, &key)| (key, i))
+            .collect();
+        let mut keyed: Vec<Key> = v.iter().map(|&(key, i)| Key(key, i)).collect();
+        merge_sort(&mut keyed);
+        v.sort_by_key(|&(key, _)| key);
+        assert_eq!(keyed.iter().map(|k| (k.0, k.1)).collect::<Vec<_>>(), v);
+    }
+
+    struct Key(u8, usize);
+
+    impl PartialEq for Key {
+        fn eq(&self, other: &Key) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Key {}
+
+    impl PartialOrd for Key {
+        fn partial_cmp(&self, other: &Key) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Key {
+        fn cmp(&self, other: &Key) -> Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
     use std::cmp::Ordering;
     use std::sync::Mutex;
 
@@ -1,19 +1,50 @@
+mod block_sort;
 mod bubble_sort;
+#[cfg(feature = "alloc")]
 mod counting_sort;
+#[cfg(feature = "alloc")]
+mod flash_sort;
 mod heap_sort;
+#[cfg(feature = "alloc")]
 mod insertion_sort;
+#[cfg(feature = "alloc")]
+mod iter_ext;
+#[cfg(feature = "alloc")]
 mod merge_sort;
+mod msd_radix_sort;
+#[cfg(feature = "alloc")]
+mod natural_merge_sort;
 mod quick_sort;
 mod selection_sort;
+#[cfg(feature = "alloc")]
+mod spread_sort;
+#[cfg(feature = "alloc")]
+mod strand_sort;
 
+pub use self::block_sort::block_sort;
 pub use self::bubble_sort::bubble_sort;
+#[cfg(feature = "alloc")]
 pub use self::counting_sort::counting_sort;
+#[cfg(feature = "alloc")]
 pub use self::counting_sort::generic_counting_sort;
+#[cfg(feature = "alloc")]
+pub use self::flash_sort::flash_sort;
 pub use self::heap_sort::heap_sort;
+#[cfg(feature = "alloc")]
 pub use self::insertion_sort::insertion_sort;
+#[cfg(feature = "alloc")]
+pub use self::iter_ext::SortedIterator;
+#[cfg(feature = "alloc")]
 pub use self::merge_sort::merge_sort;
+pub use self::msd_radix_sort::msd_radix_sort;
+#[cfg(feature = "alloc")]
+pub use self::natural_merge_sort::natural_merge_sort;
 pub use self::quick_sort::quick_sort;
 pub use self::selection_sort::selection_sort;
+#[cfg(feature = "alloc")]
+pub use self::spread_sort::spread_sort;
+#[cfg(feature = "alloc")]
+pub use self::strand_sort::strand_sort;
 
 // Help functions used in tests.
 #[cfg(test)]
@@ -0,0 +1,167 @@
+/// Sort byte strings lexicographically with an in-place MSD radix sort,
+/// also known as American flag sort.
+///
+/// # Algorithm
+///
+/// Distribute the strings into 257 buckets by their byte at the current
+/// depth, where bucket 0 holds the strings that are shorter than that and
+/// therefore come first. The bucket sizes give each bucket's final range, so
+/// the strings are moved into place by swapping, without a second buffer.
+/// Then sort every bucket except bucket 0, whose strings are all equal, by
+/// the next byte.
+///
+/// Buckets of at most 32 strings are finished with an insertion sort
+/// instead. Only the buckets other than the largest are sorted by recursion;
+/// the largest one is handled by the next iteration of a loop. Each
+/// recursive call thus gets at most half of the strings, so the recursion
+/// is at most log2(n) levels deep however long the shared prefixes are.
+///
+/// Each level takes O(n) time, so sorting `n` strings with a total of `w`
+/// bytes examined takes O(n + w) time, plus 2 * 257 counters per level of
+/// recursion. Works for anything that can be viewed as bytes, such as
+/// `String` or `Vec<u8>`; UTF-8 strings are ordered by code point.
+pub fn msd_radix_sort<T: AsRef<[u8]>>(arr: &mut [T]) {
+    sort_from(arr, 0);
+}
+
+const BUCKETS: usize = 257;
+const SMALL: usize = 32;
+
+fn key<T: AsRef<[u8]>>(s: &T, depth: usize) -> usize {
+    s.as_ref().get(depth).map_or(0, |&b| b as usize + 1)
+}
+
+fn sort_from<T: AsRef<[u8]>>(mut arr: &mut [T], mut depth: usize) {
+    while arr.len() > SMALL {
+        let mut counts = [0; BUCKETS];
+        for s in arr.iter() {
+            counts[key(s, depth)] += 1;
+        }
+
+        // Bucket b ends up in arr[ends[b] - counts[b]..ends[b]], and next[b]
+        // is the first slot of bucket b not yet known to be in place.
+        let mut next = [0; BUCKETS];
+        let mut ends = [0; BUCKETS];
+        let mut sum = 0;
+        for b in 0..BUCKETS {
+            next[b] = sum;
+            sum += counts[b];
+            ends[b] = sum;
+        }
+        for b in 0..BUCKETS {
+            while next[b] < ends[b] {
+                let k = key(&arr[next[b]], depth);
+                if k == b {
+                    next[b] += 1;
+                } else {
+                    arr.swap(next[b], next[k]);
+                    next[k] += 1;
+                }
+            }
+        }
+
+        // Bucket 0 holds strings that are all equal and needs no sorting.
+        let largest = (1..BUCKETS).max_by_key(|&b| counts[b]).unwrap();
+        for b in (1..BUCKETS).filter(|&b| b != largest) {
+            sort_from(&mut arr[ends[b] - counts[b]..ends[b]], depth + 1);
+        }
+        arr = &mut arr[ends[largest] - counts[largest]..ends[largest]];
+        depth += 1;
+    }
+
+    // All strings share their first `depth` bytes, so comparing the rest
+    // is enough.
+    for i in 1..arr.len() {
+        let mut j = i;
+        while j > 0 && arr[j - 1].as_ref()[depth..] > arr[j].as_ref()[depth..] {
+            arr.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::is_sorted;
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn words() {
+        let mut words = vec![
+            "banana", "apple", "cherry", "apricot", "band", "ban", "a", "", "bandana", "apple",
+        ];
+        let mut expected = words.clone();
+        expected.sort();
+        msd_radix_sort(&mut words);
+        assert_eq!(words, expected);
+    }
+
+    #[test]
+    fn prefixes() {
+        let mut arr = vec!["abc", "ab", "abcd", "a", "abc", ""];
+        msd_radix_sort(&mut arr);
+        assert_eq!(arr, vec!["", "a", "ab", "abc", "abc", "abcd"]);
+    }
+
+    #[test]
+    fn shuffled_byte_strings() {
+        let mut rng = XorShift::new(9);
+        let mut arr: Vec<Vec<u8>> = (0..500)
+            .map(|_| {
+                let len = rng.gen_range(0, 6) as usize;
+                // a small alphabet produces many shared prefixes
+                (0..len).map(|_| rng.gen_range(0, 4) as u8 * 85).collect()
+            })
+            .collect();
+        let mut expected = arr.clone();
+        expected.sort();
+        msd_radix_sort(&mut arr);
+        assert!(is_sorted(&arr));
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn strings() {
+        let mut arr: Vec<String> = ["zeta", "émile", "eta", "Zeta"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        msd_radix_sort(&mut arr);
+        assert_eq!(arr, vec!["Zeta", "eta", "zeta", "émile"]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut arr: Vec<&str> = vec![];
+        msd_radix_sort(&mut arr);
+        assert!(arr.is_empty());
+    }
+
+    #[test]
+    fn long_shared_prefixes() {
+        // identical long strings used to recurse once per byte
+        let long = vec![b'a'; 5000];
+        let mut arr = vec![long.clone(); 2];
+        msd_radix_sort(&mut arr);
+        assert_eq!(arr, vec![long.clone(); 2]);
+        let mut arr = vec![long.clone(); 100];
+        msd_radix_sort(&mut arr);
+        assert_eq!(arr, vec![long.clone(); 100]);
+
+        // nested prefixes used to recurse once per string as well
+        let mut arr: Vec<Vec<u8>> = (0..1000)
+            .rev()
+            .map(|len| {
+                let mut s = vec![b'a'; len];
+                s.push(b'b');
+                s
+            })
+            .chain((0..50).map(|_| long.clone()))
+            .collect();
+        let mut expected = arr.clone();
+        expected.sort();
+        msd_radix_sort(&mut arr);
+        assert_eq!(arr, expected);
+    }
+}
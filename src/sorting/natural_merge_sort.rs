@@ -0,0 +1,215 @@
+use alloc::vec::Vec;
+
+/// Sort a mutable slice using natural merge sort.
+///
+/// # Algorithm
+///
+/// Instead of splitting the input at fixed midpoints like merge sort, scan
+/// it once for the runs it already contains. Ascending runs are kept as they
+/// are and strictly descending runs are reversed in place, which cannot
+/// reorder equal items. Then merge neighbouring runs pairwise, halving the
+/// number of runs in each pass, until a single run is left.
+///
+/// With `r` runs this takes O(n log r) time, so it is O(n) on sorted or
+/// reverse sorted input and O(n log n) in the worst case. The sort is stable
+/// and needs O(n) extra memory.
+pub fn natural_merge_sort<T: Ord + Clone>(arr: &mut [T]) {
+    let len = arr.len();
+
+    // runs[i]..runs[i + 1] are sorted
+    let mut runs = alloc::vec![0];
+    let mut start = 0;
+    while start < len {
+        // The first pair decides the direction of the run.
+        let mut end = start + 1;
+        if end < len {
+            let descending = arr[end] < arr[start];
+            end += 1;
+            if descending {
+                while end < len && arr[end] < arr[end - 1] {
+                    end += 1;
+                }
+                arr[start..end].reverse();
+            } else {
+                while end < len && arr[end] >= arr[end - 1] {
+                    end += 1;
+                }
+            }
+        }
+        runs.push(end);
+        start = end;
+    }
+
+    let mut buffer = Vec::with_capacity(len);
+    while runs.len() > 2 {
+        let mut merged = Vec::with_capacity(runs.len() / 2 + 1);
+        for bounds in runs.windows(3).step_by(2) {
+            merge(
+                &mut arr[bounds[0]..bounds[2]],
+                bounds[1] - bounds[0],
+                &mut buffer,
+            );
+            merged.push(bounds[0]);
+        }
+        // An odd run out is carried over to the next pass.
+        if runs.len() % 2 == 0 {
+            merged.push(runs[runs.len() - 2]);
+        }
+        merged.push(len);
+        runs = merged;
+    }
+}
+
+// Merge the sorted halves arr[..mid] and arr[mid..]. Only the left half is
+// copied out: the next write never passes the next unread item of the right
+// half. On ties the left half comes first, which keeps the sort stable.
+fn merge<T: Ord + Clone>(arr: &mut [T], mid: usize, buffer: &mut Vec<T>) {
+    buffer.clear();
+    buffer.extend_from_slice(&arr[..mid]);
+
+    let (mut i, mut j) = (0, mid);
+    for k in 0..arr.len() {
+        if i == buffer.len() {
+            break;
+        }
+        if j < arr.len() && arr[j] < buffer[i] {
+            arr[k] = arr[j].clone();
+            j += 1;
+        } else {
+            arr[k] = buffer[i].clone();
+            i += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::merge_sort;
+    use super::super::tests::is_sorted;
+    use super::*;
+    use crate::random::XorShift;
+    use std::cell::Cell;
+    use std::cmp::Ordering;
+    use std::rc::Rc;
+
+    #[test]
+    fn empty_and_single() {
+        let mut empty: Vec<i32> = vec![];
+        natural_merge_sort(&mut empty);
+        assert!(empty.is_empty());
+
+        let mut single = vec![1];
+        natural_merge_sort(&mut single);
+        assert_eq!(single, vec![1]);
+    }
+
+    #[test]
+    fn three_runs() {
+        let mut arr = vec![4, 5, 9, 12, 1, 2, 3, 10, 11, 0, 6, 7, 8];
+        natural_merge_sort(&mut arr);
+        assert_eq!(arr, (0..13).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn descending_runs() {
+        let mut arr = vec![9, 8, 7, 1, 2, 3, 6, 5, 4, 4];
+        natural_merge_sort(&mut arr);
+        assert_eq!(arr, vec![1, 2, 3, 4, 4, 5, 6, 7, 8, 9]);
+
+        let mut reversed: Vec<i32> = (0..100).rev().collect();
+        natural_merge_sort(&mut reversed);
+        assert!(is_sorted(&reversed));
+    }
+
+    #[test]
+    fn random() {
+        let mut rng = XorShift::new(71);
+        for len in 0..200 {
+            let mut arr: Vec<u64> = (0..len).map(|_| rng.gen_range(0, 50)).collect();
+            let mut expected = arr.clone();
+            expected.sort();
+            natural_merge_sort(&mut arr);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    // Compares by `key` only, so the order of equal keys is observable, and
+    // counts every comparison.
+    #[derive(Clone)]
+    struct Item {
+        key: u32,
+        tag: usize,
+        comparisons: Rc<Cell<usize>>,
+    }
+
+    impl PartialEq for Item {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+
+    impl Eq for Item {}
+
+    impl PartialOrd for Item {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Item {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.comparisons.set(self.comparisons.get() + 1);
+            self.key.cmp(&other.key)
+        }
+    }
+
+    fn items(keys: &[u32], comparisons: &Rc<Cell<usize>>) -> Vec<Item> {
+        keys.iter()
+            .enumerate()
+            .map(|(tag, &key)| Item {
+                key,
+                tag,
+                comparisons: comparisons.clone(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn stable() {
+        let comparisons = Rc::new(Cell::new(0));
+        let mut arr = items(&[2, 1, 2, 1, 0, 3, 3, 2, 1, 1], &comparisons);
+        natural_merge_sort(&mut arr);
+        let tags: Vec<usize> = arr.iter().map(|item| item.tag).collect();
+        assert_eq!(tags, vec![4, 1, 3, 8, 9, 0, 2, 7, 5, 6]);
+    }
+
+    #[test]
+    fn fewer_comparisons_on_runs() {
+        // three sorted runs of 300 items each
+        let keys: Vec<u32> = (0..900).map(|i| (i % 300) * 3 + i / 300).collect();
+
+        let natural = Rc::new(Cell::new(0));
+        let mut arr = items(&keys, &natural);
+        natural_merge_sort(&mut arr);
+        assert!(arr.iter().map(|item| item.key).eq(0..900));
+
+        let plain = Rc::new(Cell::new(0));
+        let mut arr = items(&keys, &plain);
+        merge_sort(&mut arr);
+
+        // one pass to find the runs plus two merges
+        assert!(natural.get() < 3 * keys.len());
+        assert!(natural.get() < plain.get());
+        assert!(is_sorted(&arr));
+    }
+
+    #[test]
+    fn linear_on_sorted_input() {
+        // a single run is found with n - 1 comparisons and never merged
+        let comparisons = Rc::new(Cell::new(0));
+        let keys: Vec<u32> = (0..1000).collect();
+        let mut arr = items(&keys, &comparisons);
+        natural_merge_sort(&mut arr);
+        assert_eq!(comparisons.get(), keys.len() - 1);
+    }
+}
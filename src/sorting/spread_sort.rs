@@ -0,0 +1,138 @@
+use super::heap_sort;
+
+/// Sort a slice of integers with a Spreadsort-style hybrid of radix and
+/// comparison sorting.
+///
+/// # Algorithm
+///
+/// Find the minimum and maximum and spread the items into up to
+/// `2^bucket_bits` buckets by the highest bits of their offset from the
+/// minimum, moving them into place by swapping like American flag sort.
+/// Then sort every bucket the same way, with a smaller range left each time.
+///
+/// The number of buckets grows with `log2(n)`, up to 2^11, so that the
+/// counters of a level stay about as large as the slice and fit in cache.
+/// Before each level, the number of radix passes still needed to cover the
+/// range is compared with the `log2(n)` levels of a comparison sort, and
+/// slices where the comparison sort wins are handed to heap sort. Tiny
+/// slices use insertion sort. This takes O(n log n) time in the worst case
+/// and O(n) when the values span a range that few passes can cover.
+pub fn spread_sort(arr: &mut [u64]) {
+    let len = arr.len();
+    if len <= SMALL {
+        insertion_sort(arr);
+        return;
+    }
+
+    let min = *arr.iter().min().unwrap();
+    let max = *arr.iter().max().unwrap();
+    if min == max {
+        return;
+    }
+
+    let range_bits = 64 - (max - min).leading_zeros();
+    let log_len = usize::BITS - 1 - len.leading_zeros();
+    let bucket_bits = log_len.min(MAX_BUCKET_BITS).min(range_bits);
+    if range_bits.div_ceil(bucket_bits) > log_len {
+        heap_sort(arr);
+        return;
+    }
+
+    let shift = range_bits - bucket_bits;
+    let bucket = |x: u64| ((x - min) >> shift) as usize;
+    let buckets = bucket(max) + 1;
+
+    // Bucket b ends up in arr[starts[b]..starts[b + 1]].
+    let mut starts = alloc::vec![0; buckets + 1];
+    for &x in arr.iter() {
+        starts[bucket(x) + 1] += 1;
+    }
+    for b in 0..buckets {
+        starts[b + 1] += starts[b];
+    }
+
+    // next[b] is the first slot of bucket b not yet known to be in place.
+    let mut next = starts.clone();
+    for b in 0..buckets {
+        while next[b] < starts[b + 1] {
+            let k = bucket(arr[next[b]]);
+            if k != b {
+                arr.swap(next[b], next[k]);
+            }
+            next[k] += 1;
+        }
+    }
+
+    // With no bits left below the bucket bits, every bucket holds equal
+    // values.
+    if shift > 0 {
+        for b in 0..buckets {
+            spread_sort(&mut arr[starts[b]..starts[b + 1]]);
+        }
+    }
+}
+
+const SMALL: usize = 16;
+const MAX_BUCKET_BITS: u32 = 11;
+
+fn insertion_sort(arr: &mut [u64]) {
+    for i in 1..arr.len() {
+        let mut j = i;
+        while j > 0 && arr[j - 1] > arr[j] {
+            arr.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::is_sorted;
+    use super::*;
+    use crate::random::XorShift;
+
+    fn check(mut arr: Vec<u64>) {
+        let mut expected = arr.clone();
+        expected.sort_unstable();
+        spread_sort(&mut arr);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn small() {
+        check(vec![]);
+        check(vec![7]);
+        check(vec![3, 1, 2]);
+        check(vec![5; 40]);
+        check(vec![u64::MAX, 0, u64::MAX, 1, 0]);
+    }
+
+    #[test]
+    fn sizes_and_ranges() {
+        let mut rng = XorShift::new(97);
+        for &len in &[10, 17, 100, 1000, 10_000, 50_000] {
+            for &range in &[2, 100, 1 << 20, 1 << 40] {
+                check((0..len).map(|_| rng.gen_range(0, range)).collect());
+            }
+            check((0..len).map(|_| rng.next_u64()).collect());
+        }
+    }
+
+    #[test]
+    fn presorted() {
+        check((0..10_000).collect());
+        check((0..10_000).rev().collect());
+        check((0..10_000).map(|i| i << 40).collect());
+    }
+
+    #[test]
+    fn large_input() {
+        let mut rng = XorShift::new(101);
+        let mut arr: Vec<u64> = (0..200_000).map(|_| rng.next_u64() >> 16).collect();
+        let mut expected = arr.clone();
+        expected.sort_unstable();
+        spread_sort(&mut arr);
+        assert!(is_sorted(&arr));
+        assert_eq!(arr, expected);
+    }
+}
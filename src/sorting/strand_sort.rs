@@ -0,0 +1,129 @@
+use alloc::vec::Vec;
+
+/// Sort a mutable slice using strand sort.
+///
+/// # Algorithm
+///
+/// Repeatedly pull a "strand" out of the unsorted items: take the first
+/// item, then every following item that is not smaller than the last one
+/// taken. The strand is sorted, so it can be merged into the output like in
+/// merge sort. Stop when no items are left.
+///
+/// Each pass takes at least one item, so strand sort takes O(n^2) time in
+/// the worst case, on descending input. It is O(n) on data that is already
+/// sorted and fast whenever the input consists of a few long ascending runs.
+/// The sort is stable and needs O(n) extra memory.
+pub fn strand_sort<T: Ord + Clone>(arr: &mut [T]) {
+    let mut remaining = arr.to_vec();
+    let mut sorted: Vec<T> = Vec::with_capacity(arr.len());
+
+    while !remaining.is_empty() {
+        let mut strand: Vec<T> = Vec::new();
+        let mut rest = Vec::new();
+        for item in remaining {
+            if strand.last().is_none_or(|last| *last <= item) {
+                strand.push(item);
+            } else {
+                rest.push(item);
+            }
+        }
+        sorted = merge(sorted, strand);
+        remaining = rest;
+    }
+
+    arr.clone_from_slice(&sorted);
+}
+
+// Merge two sorted vectors. On ties items of `left` come first, which keeps
+// the sort stable since earlier strands only hold earlier items.
+fn merge<T: Ord>(left: Vec<T>, right: Vec<T>) -> Vec<T> {
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    loop {
+        let take_left = match (left.peek(), right.peek()) {
+            (Some(l), Some(r)) => l <= r,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => return merged,
+        };
+        merged.push(if take_left { left.next() } else { right.next() }.unwrap());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::is_sorted;
+    use super::*;
+
+    #[test]
+    fn descending() {
+        let mut ve1 = vec![6, 5, 4, 3, 2, 1];
+        strand_sort(&mut ve1);
+        assert!(is_sorted(&ve1));
+    }
+
+    #[test]
+    fn pre_sorted() {
+        let mut ve2 = vec![1, 2, 3, 4, 5, 6];
+        strand_sort(&mut ve2);
+        assert!(is_sorted(&ve2));
+    }
+
+    #[test]
+    fn natural_runs() {
+        // three interleaved ascending runs
+        let mut arr = vec![1, 10, 2, 20, 11, 3, 21, 12, 4, 22, 5, 13];
+        strand_sort(&mut arr);
+        assert_eq!(arr, vec![1, 2, 3, 4, 5, 10, 11, 12, 13, 20, 21, 22]);
+    }
+
+    // Compares by `key` only, so the order of equal keys is observable.
+    #[derive(Clone, Debug)]
+    struct Item {
+        key: u32,
+        tag: char,
+    }
+
+    impl PartialEq for Item {
+        fn eq(&self, other: &Self) -> bool {
+            self.key == other.key
+        }
+    }
+
+    impl Eq for Item {}
+
+    impl PartialOrd for Item {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Item {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.key.cmp(&other.key)
+        }
+    }
+
+    #[test]
+    fn stable() {
+        let mut arr: Vec<Item> = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')]
+            .iter()
+            .map(|&(key, tag)| Item { key, tag })
+            .collect();
+        strand_sort(&mut arr);
+        let tags: String = arr.iter().map(|item| item.tag).collect();
+        assert_eq!(tags, "ebdac");
+    }
+
+    #[test]
+    fn empty_and_single() {
+        let mut empty: Vec<i32> = vec![];
+        strand_sort(&mut empty);
+        assert!(empty.is_empty());
+
+        let mut single = vec![1];
+        strand_sort(&mut single);
+        assert_eq!(single, vec![1]);
+    }
+}
@@ -0,0 +1,180 @@
+//! Aho-Corasick multi-pattern search
+//!
+//! # Algorithm
+//!
+//! Put all patterns into a trie (the goto function). For every trie node,
+//! the fail link points to the node of the longest proper suffix of its
+//! string that is also in the trie, and the output link to the nearest node
+//! along the fail links that ends a pattern. Fail links are computed by a
+//! breadth-first traversal, since a node's fail link is shallower than the
+//! node itself.
+//!
+//! The text is then fed through the automaton one byte at a time, following
+//! fail links whenever the trie has no matching edge, exactly like the
+//! failure function in Knuth-Morris-Pratt. At every position the output
+//! links list all patterns ending there. The search takes
+//! O(n + total pattern length + number of matches) time.
+//!
+//! The search runs over bytes, so positions are byte offsets into the text.
+
+use std::collections::{HashMap, VecDeque};
+
+/// An automaton finding all occurrences of a fixed set of patterns at once.
+pub struct AhoCorasick {
+    nodes: Vec<Node>,
+    pattern_lengths: Vec<usize>,
+}
+
+#[derive(Default)]
+struct Node {
+    goto: HashMap<u8, usize>,
+    fail: usize,
+    // nearest node along the fail links, excluding this one, that ends a
+    // pattern
+    output: Option<usize>,
+    // patterns ending exactly at this node
+    patterns: Vec<usize>,
+}
+
+impl AhoCorasick {
+    pub fn new(patterns: &[&str]) -> Self {
+        let mut nodes = vec![Node::default()];
+        for (index, pattern) in patterns.iter().enumerate() {
+            let mut node = 0;
+            for &b in pattern.as_bytes() {
+                node = match nodes[node].goto.get(&b) {
+                    Some(&next) => next,
+                    None => {
+                        nodes.push(Node::default());
+                        let next = nodes.len() - 1;
+                        nodes[node].goto.insert(b, next);
+                        next
+                    }
+                };
+            }
+            nodes[node].patterns.push(index);
+        }
+
+        let mut queue: VecDeque<usize> = nodes[0].goto.values().cloned().collect();
+        while let Some(node) = queue.pop_front() {
+            let edges: Vec<(u8, usize)> = nodes[node].goto.iter().map(|(&b, &n)| (b, n)).collect();
+            for (b, child) in edges {
+                // The fail link of the child extends the fail link of some
+                // suffix of the parent by `b`.
+                let mut fail = nodes[node].fail;
+                let child_fail = loop {
+                    if let Some(&next) = nodes[fail].goto.get(&b) {
+                        break next;
+                    }
+                    if fail == 0 {
+                        break 0;
+                    }
+                    fail = nodes[fail].fail;
+                };
+                nodes[child].fail = child_fail;
+                nodes[child].output = if nodes[child_fail].patterns.is_empty() {
+                    nodes[child_fail].output
+                } else {
+                    Some(child_fail)
+                };
+                queue.push_back(child);
+            }
+        }
+
+        AhoCorasick {
+            nodes,
+            pattern_lengths: patterns.iter().map(|p| p.len()).collect(),
+        }
+    }
+
+    /// Returns `(pattern_index, end)` for every occurrence of every pattern
+    /// in `text`, where `end` is the byte offset just past the match, so the
+    /// match is `text[end - pattern.len()..end]`. Matches are ordered by
+    /// `end`, longer patterns first. Empty patterns are never reported.
+    pub fn find_all(&self, text: &str) -> Vec<(usize, usize)> {
+        let mut matches = Vec::new();
+        let mut node = 0;
+        for (i, &b) in text.as_bytes().iter().enumerate() {
+            node = loop {
+                if let Some(&next) = self.nodes[node].goto.get(&b) {
+                    break next;
+                }
+                if node == 0 {
+                    break 0;
+                }
+                node = self.nodes[node].fail;
+            };
+
+            let mut current = Some(node);
+            while let Some(n) = current {
+                for &pattern in &self.nodes[n].patterns {
+                    if self.pattern_lengths[pattern] > 0 {
+                        matches.push((pattern, i + 1));
+                    }
+                }
+                current = self.nodes[n].output;
+            }
+        }
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strings::kmp_search_all;
+
+    #[test]
+    fn ushers() {
+        let automaton = AhoCorasick::new(&["he", "she", "his", "hers"]);
+        // "she" and "he" both end at 4, "hers" ends at 6.
+        assert_eq!(automaton.find_all("ushers"), vec![(1, 4), (0, 4), (3, 6)]);
+    }
+
+    #[test]
+    fn suffix_patterns() {
+        let automaton = AhoCorasick::new(&["abcd", "bcd", "cd", "d"]);
+        assert_eq!(
+            automaton.find_all("xabcd"),
+            vec![(0, 5), (1, 5), (2, 5), (3, 5)]
+        );
+    }
+
+    #[test]
+    fn overlapping_occurrences() {
+        let automaton = AhoCorasick::new(&["aa", "a"]);
+        assert_eq!(
+            automaton.find_all("aaa"),
+            vec![(1, 1), (0, 2), (1, 2), (0, 3), (1, 3)]
+        );
+    }
+
+    #[test]
+    fn against_kmp() {
+        let patterns = ["ana", "nan", "a", "banana", "nab", "x"];
+        let text = "bananabanana";
+        let automaton = AhoCorasick::new(&patterns);
+        let mut found = automaton.find_all(text);
+        found.sort_unstable();
+
+        let mut expected = Vec::new();
+        for (index, pattern) in patterns.iter().enumerate() {
+            for start in kmp_search_all(text, pattern) {
+                expected.push((index, start + pattern.len()));
+            }
+        }
+        expected.sort_unstable();
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn duplicates_and_empty() {
+        let automaton = AhoCorasick::new(&["ab", "", "ab"]);
+        assert_eq!(
+            automaton.find_all("abab"),
+            vec![(0, 2), (2, 2), (0, 4), (2, 4)]
+        );
+        assert!(AhoCorasick::new(&[]).find_all("abc").is_empty());
+        assert!(automaton.find_all("").is_empty());
+    }
+}
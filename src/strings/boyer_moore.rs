@@ -0,0 +1,154 @@
+//! Boyer-Moore substring search
+//!
+//! # Algorithm
+//!
+//! Align the pattern with the text and compare it from right to left. On a
+//! mismatch the pattern is shifted by the larger of two precomputed shifts:
+//!
+//! - The bad-character rule aligns the mismatched text byte with its last
+//!   occurrence in the pattern, or moves the pattern past it entirely.
+//! - The good-suffix rule aligns the part that did match with its next
+//!   occurrence in the pattern, or with the longest prefix of the pattern
+//!   that is a suffix of it.
+//!
+//! The bad-character rule lets the search skip most of the text when the
+//! alphabet is large compared to the pattern, so on typical text only about
+//! `n / m` bytes are examined. The worst case is O(n * m).
+//!
+//! The search runs over bytes, so the returned index is a byte offset into
+//! the text. Since both arguments are valid UTF-8, a match always starts on a
+//! `char` boundary and can be used to slice the text.
+
+/// Returns the byte offset of the first occurrence of `pattern` in `text`.
+pub fn boyer_moore(text: &str, pattern: &str) -> Option<usize> {
+    let (text, pattern) = (text.as_bytes(), pattern.as_bytes());
+    let (n, m) = (text.len(), pattern.len());
+    if m > n {
+        return None;
+    }
+    if m == 0 {
+        return Some(0);
+    }
+
+    let last = last_occurrence(pattern);
+    let good_suffix = good_suffix_shifts(pattern);
+
+    let mut shift = 0;
+    while shift <= n - m {
+        // j is the number of bytes not matched yet
+        let mut j = m;
+        while j > 0 && pattern[j - 1] == text[shift + j - 1] {
+            j -= 1;
+        }
+        if j == 0 {
+            return Some(shift);
+        }
+        let bad_char = (j - 1) as isize - last[text[shift + j - 1] as usize];
+        shift += good_suffix[j].max(bad_char.max(1) as usize);
+    }
+    None
+}
+
+// last[b] is the index of the last occurrence of byte b in the pattern,
+// or -1 if it does not occur.
+fn last_occurrence(pattern: &[u8]) -> [isize; 256] {
+    let mut last = [-1; 256];
+    for (i, &b) in pattern.iter().enumerate() {
+        last[b as usize] = i as isize;
+    }
+    last
+}
+
+// shift[j] is the good-suffix shift after matching pattern[j..] and
+// failing at pattern[j - 1]. shift[0] is the shift after a full match.
+fn good_suffix_shifts(pattern: &[u8]) -> Vec<usize> {
+    let m = pattern.len();
+    let mut shift = vec![0; m + 1];
+    // border[i] is the start of the widest border of pattern[i..]
+    let mut border = vec![0; m + 1];
+
+    // Case 1: the matched suffix occurs elsewhere in the pattern, preceded
+    // by a different byte.
+    let (mut i, mut j) = (m, m + 1);
+    border[i] = j;
+    while i > 0 {
+        while j <= m && pattern[i - 1] != pattern[j - 1] {
+            if shift[j] == 0 {
+                shift[j] = j - i;
+            }
+            j = border[j];
+        }
+        i -= 1;
+        j -= 1;
+        border[i] = j;
+    }
+
+    // Case 2: only a prefix of the pattern matches a part of the suffix.
+    let mut j = border[0];
+    for (i, s) in shift.iter_mut().enumerate() {
+        if *s == 0 {
+            *s = j;
+        }
+        if i == j {
+            j = border[j];
+        }
+    }
+    shift
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn good_suffix() {
+        assert_eq!(good_suffix_shifts(b"abbabab"), vec![5, 5, 5, 5, 2, 5, 4, 1]);
+        // A mismatch in "aaaa" means the text byte is not an "a" at all.
+        assert_eq!(good_suffix_shifts(b"aaaa"), vec![1, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn first_match() {
+        assert_eq!(boyer_moore("hello world", "world"), Some(6));
+        assert_eq!(boyer_moore("abababc", "ababc"), Some(2));
+        assert_eq!(boyer_moore("aaaaaa", "aaa"), Some(0));
+    }
+
+    #[test]
+    fn match_near_end_of_long_text() {
+        let mut text = "the quick brown fox jumps over the lazy dog ".repeat(1000);
+        let offset = text.len();
+        text.push_str("needle in a haystack");
+        assert_eq!(boyer_moore(&text, "needle"), Some(offset));
+        assert_eq!(boyer_moore(&text, "haystack"), Some(offset + 12));
+    }
+
+    #[test]
+    fn no_match() {
+        assert_eq!(boyer_moore("abc", "abd"), None);
+        assert_eq!(boyer_moore("ab", "abc"), None);
+        assert_eq!(boyer_moore("", "a"), None);
+        assert_eq!(boyer_moore(&"ab".repeat(100), "aa"), None);
+    }
+
+    #[test]
+    fn single_byte_pattern() {
+        assert_eq!(boyer_moore("abcdef", "a"), Some(0));
+        assert_eq!(boyer_moore("abcdef", "f"), Some(5));
+        assert_eq!(boyer_moore("abcdef", "x"), None);
+    }
+
+    #[test]
+    fn empty_pattern() {
+        assert_eq!(boyer_moore("abc", ""), Some(0));
+        assert_eq!(boyer_moore("", ""), Some(0));
+    }
+
+    #[test]
+    fn byte_offsets() {
+        let text = "añb añb";
+        let index = boyer_moore(text, "b añ").unwrap();
+        assert_eq!(index, 3);
+        assert_eq!(&text[index..], "b añb");
+    }
+}
@@ -0,0 +1,114 @@
+//! Knuth-Morris-Pratt substring search
+//!
+//! # Algorithm
+//!
+//! Precompute for every prefix of the needle the length of its longest proper
+//! prefix that is also a suffix (the failure function). On a mismatch the
+//! search falls back to that border instead of restarting, so no byte of the
+//! haystack is examined twice and the search takes O(n + m) time.
+//!
+//! The search runs over bytes, so all returned indices are byte offsets into
+//! the haystack. Since both arguments are valid UTF-8, every match starts on a
+//! `char` boundary and can be used to slice the haystack.
+
+/// Returns the byte offset of the first occurrence of `needle` in `haystack`.
+pub fn kmp_search(haystack: &str, needle: &str) -> Option<usize> {
+    matches(haystack.as_bytes(), needle.as_bytes(), true)
+        .first()
+        .cloned()
+}
+
+/// Returns the byte offsets of all, possibly overlapping, occurrences of
+/// `needle` in `haystack`. An empty needle matches at every offset.
+pub fn kmp_search_all(haystack: &str, needle: &str) -> Vec<usize> {
+    matches(haystack.as_bytes(), needle.as_bytes(), false)
+}
+
+// failure[i] is the length of the longest proper border of needle[..=i].
+fn failure_function(needle: &[u8]) -> Vec<usize> {
+    let mut failure = vec![0; needle.len()];
+    let mut border = 0;
+    for i in 1..needle.len() {
+        while border > 0 && needle[i] != needle[border] {
+            border = failure[border - 1];
+        }
+        if needle[i] == needle[border] {
+            border += 1;
+        }
+        failure[i] = border;
+    }
+    failure
+}
+
+fn matches(haystack: &[u8], needle: &[u8], first_only: bool) -> Vec<usize> {
+    if needle.is_empty() {
+        let count = if first_only { 1 } else { haystack.len() + 1 };
+        return (0..count).collect();
+    }
+
+    let failure = failure_function(needle);
+    let mut result = Vec::new();
+    let mut matched = 0;
+    for (i, &byte) in haystack.iter().enumerate() {
+        while matched > 0 && byte != needle[matched] {
+            matched = failure[matched - 1];
+        }
+        if byte == needle[matched] {
+            matched += 1;
+        }
+        if matched == needle.len() {
+            result.push(i + 1 - matched);
+            if first_only {
+                break;
+            }
+            matched = failure[matched - 1];
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failure() {
+        assert_eq!(failure_function(b"abacabab"), vec![0, 0, 1, 0, 1, 2, 3, 2]);
+        assert_eq!(failure_function(b"aaaa"), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn first_match() {
+        assert_eq!(kmp_search("hello world", "world"), Some(6));
+        assert_eq!(kmp_search("abababc", "ababc"), Some(2));
+    }
+
+    #[test]
+    fn overlapping_matches() {
+        assert_eq!(kmp_search_all("aaaaaa", "aaaa"), vec![0, 1, 2]);
+        assert_eq!(kmp_search_all("abababa", "aba"), vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn empty_needle() {
+        assert_eq!(kmp_search("abc", ""), Some(0));
+        assert_eq!(kmp_search_all("abc", ""), vec![0, 1, 2, 3]);
+        assert_eq!(kmp_search("", ""), Some(0));
+    }
+
+    #[test]
+    fn no_match() {
+        assert_eq!(kmp_search("abc", "abd"), None);
+        assert_eq!(kmp_search("ab", "abc"), None);
+        assert!(kmp_search_all("", "a").is_empty());
+    }
+
+    #[test]
+    fn byte_offsets() {
+        let haystack = "añb añb";
+        let index = kmp_search(haystack, "b").unwrap();
+        assert_eq!(index, 3);
+        assert_eq!(&haystack[index..index + 1], "b");
+        assert_eq!(kmp_search_all(haystack, "ñb"), vec![1, 6]);
+    }
+}
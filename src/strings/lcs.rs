@@ -0,0 +1,99 @@
+//! Longest common subsequence
+//!
+//! # Algorithm
+//!
+//! `table[i][j]` holds the length of the LCS of `a[..i]` and `b[..j]`. If
+//! `a[i - 1] == b[j - 1]`, that element extends the LCS of both prefixes;
+//! otherwise the better of dropping the last element of either sequence is
+//! taken. Following those choices back from `table[n][m]` reconstructs one
+//! longest common subsequence.
+//!
+//! Both functions work on slices of any `Eq` type. For strings, pass
+//! `s.as_bytes()` or a `Vec<char>`.
+
+use std::cmp::max;
+
+fn lcs_table<T: Eq>(a: &[T], b: &[T]) -> Vec<Vec<usize>> {
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            table[i + 1][j + 1] = if x == y {
+                table[i][j] + 1
+            } else {
+                max(table[i][j + 1], table[i + 1][j])
+            };
+        }
+    }
+    table
+}
+
+/// Returns the length of the longest common subsequence of `a` and `b`.
+pub fn lcs_length<T: Eq>(a: &[T], b: &[T]) -> usize {
+    lcs_table(a, b)[a.len()][b.len()]
+}
+
+/// Returns one longest common subsequence of `a` and `b`.
+pub fn lcs<T: Eq + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let table = lcs_table(a, b);
+    let mut result = Vec::with_capacity(table[a.len()][b.len()]);
+
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            result.push(a[i - 1].clone());
+            i -= 1;
+            j -= 1;
+        } else if table[i - 1][j] >= table[i][j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    result.reverse();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Returns true iff `sub` can be obtained by deleting elements of `seq`.
+    fn is_subsequence<T: Eq>(sub: &[T], seq: &[T]) -> bool {
+        let mut seq = seq.iter();
+        sub.iter().all(|x| seq.any(|y| x == y))
+    }
+
+    #[test]
+    fn known_lcs() {
+        let a = b"ABCBDAB";
+        let b = b"BDCABA";
+        assert_eq!(lcs_length(a, b), 4);
+
+        let common = lcs(a, b);
+        assert_eq!(common.len(), 4);
+        assert!(is_subsequence(&common, a));
+        assert!(is_subsequence(&common, b));
+    }
+
+    #[test]
+    fn strings() {
+        let a: Vec<char> = "AGGTAB".chars().collect();
+        let b: Vec<char> = "GXTXAYB".chars().collect();
+        assert_eq!(lcs(&a, &b).into_iter().collect::<String>(), "GTAB");
+    }
+
+    #[test]
+    fn integers() {
+        let a = [1, 3, 4, 1, 2, 1, 3];
+        let b = [3, 4, 1, 2, 1, 3];
+        assert_eq!(lcs(&a, &b), vec![3, 4, 1, 2, 1, 3]);
+    }
+
+    #[test]
+    fn nothing_in_common() {
+        assert_eq!(lcs_length(&[1, 2, 3], &[4, 5, 6]), 0);
+        assert!(lcs(&[1, 2, 3], &[4, 5, 6]).is_empty());
+        assert!(lcs::<u8>(&[], &[1]).is_empty());
+    }
+}
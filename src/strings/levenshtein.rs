@@ -0,0 +1,200 @@
+//! Levenshtein distance
+//!
+//! # Algorithm
+//!
+//! `d[i][j]`, the distance between the first `i` chars of `a` and the first
+//! `j` chars of `b`, only depends on row `i - 1` and on `d[i][j - 1]`. So
+//! instead of the full table only two rows are kept, indexed by the shorter
+//! string, which needs O(min(n, m)) memory.
+//!
+//! Recovering the edits themselves needs the full O(n * m) table: starting
+//! from `d[n][m]`, step back to whichever neighbor the value was derived
+//! from until reaching `d[0][0]`.
+
+use std::cmp::min;
+
+/// Returns the least number of single-char insertions, deletions and
+/// substitutions needed to turn `a` into `b`.
+///
+/// The strings are compared `char` by `char`, so a multibyte character
+/// counts as a single edit.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // The distance is symmetric, so let the rows run over the shorter string.
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+
+    let mut prev: Vec<usize> = (0..=short.len()).collect();
+    let mut curr = vec![0; short.len() + 1];
+
+    for (i, &lc) in long.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, &sc) in short.iter().enumerate() {
+            let substitution = prev[j] + if lc == sc { 0 } else { 1 };
+            let deletion = prev[j + 1] + 1;
+            let insertion = curr[j] + 1;
+            curr[j + 1] = min(substitution, min(deletion, insertion));
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[short.len()]
+}
+
+/// A single step of an alignment, as returned by `edit_operations`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOp {
+    /// The char is the same in both strings.
+    Keep(char),
+    /// The char only occurs in the second string.
+    Insert(char),
+    /// The char only occurs in the first string.
+    Delete(char),
+    /// The first char of the first string is replaced by the second one.
+    Substitute(char, char),
+}
+
+/// Returns a shortest sequence of edits that turns `a` into `b`, together
+/// with the chars kept along the way, in string order. The number of edits
+/// other than `Keep` is `levenshtein(a, b)`.
+pub fn edit_operations(a: &str, b: &str) -> Vec<EditOp> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (n, m) = (a.len(), b.len());
+
+    let mut d = vec![vec![0; m + 1]; n + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=n {
+        for j in 1..=m {
+            let substitution = d[i - 1][j - 1] + if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = min(substitution, min(d[i - 1][j], d[i][j - 1]) + 1);
+        }
+    }
+
+    let mut ops = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && a[i - 1] == b[j - 1] && d[i][j] == d[i - 1][j - 1] {
+            ops.push(EditOp::Keep(a[i - 1]));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && d[i][j] == d[i - 1][j - 1] + 1 {
+            ops.push(EditOp::Substitute(a[i - 1], b[j - 1]));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && d[i][j] == d[i - 1][j] + 1 {
+            ops.push(EditOp::Delete(a[i - 1]));
+            i -= 1;
+        } else {
+            ops.push(EditOp::Insert(b[j - 1]));
+            j -= 1;
+        }
+    }
+    ops.reverse();
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kitten_sitting() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("sitting", "kitten"), 3);
+    }
+
+    #[test]
+    fn identical() {
+        assert_eq!(levenshtein("rust", "rust"), 0);
+        assert_eq!(levenshtein("", ""), 0);
+    }
+
+    #[test]
+    fn one_empty() {
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("abc", ""), 3);
+    }
+
+    #[test]
+    fn multibyte() {
+        assert_eq!(levenshtein("naïve", "naive"), 1);
+        assert_eq!(levenshtein("日本語", "日本"), 1);
+    }
+
+    #[test]
+    fn unrelated() {
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("abc", "xyz"), 3);
+    }
+
+    // Apply `ops` to `a`, checking that they actually describe `a`.
+    fn apply(a: &str, ops: &[EditOp]) -> String {
+        let mut chars = a.chars();
+        let mut result = String::new();
+        for &op in ops {
+            match op {
+                EditOp::Keep(c) => {
+                    assert_eq!(chars.next(), Some(c));
+                    result.push(c);
+                }
+                EditOp::Insert(c) => result.push(c),
+                EditOp::Delete(c) => assert_eq!(chars.next(), Some(c)),
+                EditOp::Substitute(from, to) => {
+                    assert_eq!(chars.next(), Some(from));
+                    result.push(to);
+                }
+            }
+        }
+        assert_eq!(chars.next(), None);
+        result
+    }
+
+    fn edit_count(ops: &[EditOp]) -> usize {
+        ops.iter()
+            .filter(|op| !matches!(op, EditOp::Keep(_)))
+            .count()
+    }
+
+    #[test]
+    fn kitten_sitting_operations() {
+        let ops = edit_operations("kitten", "sitting");
+        assert_eq!(edit_count(&ops), 3);
+        assert_eq!(apply("kitten", &ops), "sitting");
+        assert_eq!(
+            ops,
+            vec![
+                EditOp::Substitute('k', 's'),
+                EditOp::Keep('i'),
+                EditOp::Keep('t'),
+                EditOp::Keep('t'),
+                EditOp::Substitute('e', 'i'),
+                EditOp::Keep('n'),
+                EditOp::Insert('g'),
+            ]
+        );
+    }
+
+    #[test]
+    fn operations_match_distance() {
+        let pairs = [
+            ("", ""),
+            ("", "abc"),
+            ("abc", ""),
+            ("flaw", "lawn"),
+            ("naïve", "naive"),
+            ("日本語", "日本"),
+            ("intention", "execution"),
+        ];
+        for &(a, b) in pairs.iter() {
+            let ops = edit_operations(a, b);
+            assert_eq!(edit_count(&ops), levenshtein(a, b));
+            assert_eq!(apply(a, &ops), b);
+        }
+    }
+}
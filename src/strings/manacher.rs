@@ -0,0 +1,97 @@
+//! Manacher's longest palindromic substring
+//!
+//! # Algorithm
+//!
+//! Put a separator between every two characters and at both ends, so that
+//! every palindrome, of odd or even length, has a single center in the new
+//! sequence. Then compute for each center the radius of the longest
+//! palindrome around it, from left to right. Inside the rightmost palindrome
+//! found so far, a center starts from the radius of its mirror image, so the
+//! right border only ever moves forward and the whole scan takes O(n) time.
+//!
+//! The string is processed by `char`, so the result is always a valid slice
+//! of the input and palindromes are not broken up inside multi-byte
+//! characters.
+
+/// Returns the longest palindromic substring of `s`. If several have the
+/// maximal length, the leftmost one is returned.
+pub fn longest_palindrome(s: &str) -> &str {
+    // None is the separator, so it can never equal a real character.
+    let chars: Vec<Option<char>> = s.chars().map(Some).collect();
+    let mut t = Vec::with_capacity(2 * chars.len() + 1);
+    t.push(None);
+    for c in chars {
+        t.push(c);
+        t.push(None);
+    }
+
+    // radius[i] is the number of elements on each side of t[i] that mirror
+    // each other, which is also the length of that palindrome in `s`.
+    let mut radius = vec![0; t.len()];
+    let (mut center, mut right) = (0, 0);
+    for i in 0..t.len() {
+        let mut r = if i < right {
+            (right - i).min(radius[2 * center - i])
+        } else {
+            0
+        };
+        while i > r && i + r + 1 < t.len() && t[i - r - 1] == t[i + r + 1] {
+            r += 1;
+        }
+        radius[i] = r;
+        if i + r > right {
+            center = i;
+            right = i + r;
+        }
+    }
+
+    let (best, &len) = radius
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|&(_, len)| len)
+        .unwrap();
+    let start = (best - len) / 2;
+
+    // Map the char range back to byte offsets.
+    let mut offsets = s.char_indices().map(|(i, _)| i).chain(Some(s.len()));
+    let begin = offsets.nth(start).unwrap();
+    let end = if len == 0 {
+        begin
+    } else {
+        offsets.nth(len - 1).unwrap()
+    };
+    &s[begin..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn odd_length() {
+        let result = longest_palindrome("babad");
+        assert!(result == "bab" || result == "aba");
+        assert_eq!(longest_palindrome("forgeeksskeegfor"), "geeksskeeg");
+    }
+
+    #[test]
+    fn even_length() {
+        assert_eq!(longest_palindrome("cbbd"), "bb");
+        assert_eq!(longest_palindrome("abccbaxy"), "abccba");
+    }
+
+    #[test]
+    fn trivial() {
+        assert_eq!(longest_palindrome("a"), "a");
+        assert_eq!(longest_palindrome(""), "");
+        assert_eq!(longest_palindrome("abc"), "a");
+        assert_eq!(longest_palindrome("aaaa"), "aaaa");
+    }
+
+    #[test]
+    fn unicode() {
+        assert_eq!(longest_palindrome("xñañy"), "ñañ");
+        assert_eq!(longest_palindrome("日本日"), "日本日");
+    }
+}
@@ -0,0 +1,25 @@
+mod aho_corasick;
+mod boyer_moore;
+mod kmp;
+mod lcs;
+mod levenshtein;
+mod manacher;
+mod rabin_karp;
+mod suffix_array;
+mod z_algorithm;
+
+pub use self::aho_corasick::AhoCorasick;
+pub use self::boyer_moore::boyer_moore;
+pub use self::kmp::kmp_search;
+pub use self::kmp::kmp_search_all;
+pub use self::lcs::lcs;
+pub use self::lcs::lcs_length;
+pub use self::levenshtein::edit_operations;
+pub use self::levenshtein::levenshtein;
+pub use self::levenshtein::EditOp;
+pub use self::manacher::longest_palindrome;
+pub use self::rabin_karp::rabin_karp;
+pub use self::suffix_array::longest_common_prefix;
+pub use self::suffix_array::suffix_array;
+pub use self::z_algorithm::z_array;
+pub use self::z_algorithm::z_search;
@@ -0,0 +1,95 @@
+//! Rabin-Karp substring search
+//!
+//! # Algorithm
+//!
+//! Treat every window of `pattern.len()` bytes of the text as a number in
+//! base `BASE` and compare it with the pattern's number modulo `MODULUS`.
+//! Sliding the window by one byte removes the leading digit and appends a
+//! new one, so each hash is updated in O(1) and the scan takes O(n + m)
+//! expected time.
+//!
+//! Different windows may share a hash, so every candidate is compared byte
+//! by byte before it is reported. Hence a match is never a false positive,
+//! only the running time suffers from collisions.
+
+const BASE: u64 = 256;
+// A prime close to 2^31, so products of two residues fit in a u64.
+const MODULUS: u64 = 2_147_483_647;
+
+/// Returns the byte offsets of all, possibly overlapping, occurrences of
+/// `pattern` in `text`. An empty pattern matches at every offset.
+pub fn rabin_karp(text: &str, pattern: &str) -> Vec<usize> {
+    search(text.as_bytes(), pattern.as_bytes(), BASE, MODULUS)
+}
+
+fn search(text: &[u8], pattern: &[u8], base: u64, modulus: u64) -> Vec<usize> {
+    let m = pattern.len();
+    if m > text.len() {
+        return Vec::new();
+    }
+    if m == 0 {
+        return (0..=text.len()).collect();
+    }
+
+    let hash = |bytes: &[u8]| {
+        bytes
+            .iter()
+            .fold(0, |h, &b| (h * base + u64::from(b)) % modulus)
+    };
+    let pattern_hash = hash(pattern);
+    let mut window_hash = hash(&text[..m]);
+
+    // weight of the leading byte of a window: base^(m - 1)
+    let leading = (1..m).fold(1 % modulus, |w, _| w * base % modulus);
+
+    let mut result = Vec::new();
+    for start in 0..=text.len() - m {
+        if window_hash == pattern_hash && &text[start..start + m] == pattern {
+            result.push(start);
+        }
+        if start + m < text.len() {
+            let outgoing = u64::from(text[start]) * leading % modulus;
+            window_hash = (window_hash + modulus - outgoing) % modulus;
+            window_hash = (window_hash * base + u64::from(text[start + m])) % modulus;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multiple_matches() {
+        assert_eq!(rabin_karp("abracadabra", "abra"), vec![0, 7]);
+        assert_eq!(rabin_karp("aaaaaa", "aaaa"), vec![0, 1, 2]);
+        assert_eq!(rabin_karp("hello world", "world"), vec![6]);
+    }
+
+    #[test]
+    fn pattern_longer_than_text() {
+        assert!(rabin_karp("ab", "abc").is_empty());
+        assert!(rabin_karp("", "a").is_empty());
+    }
+
+    #[test]
+    fn empty_pattern() {
+        assert_eq!(rabin_karp("abc", ""), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn collisions_are_verified() {
+        // With modulus 1 every window collides with the pattern.
+        assert_eq!(search(b"abcabcab", b"cab", BASE, 1), vec![2, 5]);
+
+        // "ab" and "ba" hash alike when base is 1, since only the byte sum
+        // matters.
+        assert_eq!(search(b"abba", b"ab", 1, MODULUS), vec![0]);
+    }
+
+    #[test]
+    fn byte_offsets() {
+        assert_eq!(rabin_karp("añb añb", "ñb"), vec![1, 6]);
+    }
+}
@@ -0,0 +1,125 @@
+//! Suffix array and LCP array
+//!
+//! # Algorithm
+//!
+//! The suffix array lists the starting offsets of all suffixes of a string
+//! in lexicographic order. It is built by prefix doubling: after round `k`
+//! the suffixes are sorted by their first `2^k` bytes and every suffix has a
+//! rank, equal for suffixes that agree on those bytes. The first `2^(k+1)`
+//! bytes of suffix `i` are described by the pair of ranks of `i` and
+//! `i + 2^k`, so sorting by these pairs gives the next round. There are at
+//! most `log n` rounds of an O(n log n) sort, for O(n log^2 n) overall.
+//!
+//! Kasai's algorithm then finds the longest common prefix of every pair of
+//! neighbors in the suffix array in O(n). It visits the suffixes in text
+//! order and uses that the common prefix with the previous suffix shrinks by
+//! at most one from `i` to `i + 1`.
+
+/// Returns the starting offsets of the suffixes of `s`, in lexicographic
+/// order.
+pub fn suffix_array(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let mut sa: Vec<usize> = (0..n).collect();
+    let mut rank: Vec<usize> = s.iter().map(|&b| b as usize).collect();
+    let mut next_rank = vec![0; n];
+
+    let mut k = 1;
+    while k < n {
+        // A suffix shorter than k sorts before every longer one sharing its
+        // prefix, hence the None.
+        let key = |i: usize| (rank[i], rank.get(i + k).copied());
+        sa.sort_by_key(|&i| key(i));
+
+        next_rank[sa[0]] = 0;
+        for w in 1..n {
+            let differs = key(sa[w - 1]) != key(sa[w]);
+            next_rank[sa[w]] = next_rank[sa[w - 1]] + usize::from(differs);
+        }
+        std::mem::swap(&mut rank, &mut next_rank);
+
+        // All ranks distinct: the order is final. This happens by the
+        // time 2 * k >= n at the latest.
+        if rank[sa[n - 1]] == n - 1 {
+            break;
+        }
+        k *= 2;
+    }
+    sa
+}
+
+/// Returns the LCP array of `s` for its suffix array `sa`: entry `i` is the
+/// length of the longest common prefix of the suffixes `sa[i - 1]` and
+/// `sa[i]`, and entry 0 is 0.
+pub fn longest_common_prefix(s: &[u8], sa: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    assert_eq!(sa.len(), n, "not a suffix array of s");
+    let mut position = vec![0; n];
+    for (i, &start) in sa.iter().enumerate() {
+        position[start] = i;
+    }
+
+    let mut lcp = vec![0; n];
+    let mut common = 0;
+    for i in 0..n {
+        if position[i] == 0 {
+            common = 0;
+            continue;
+        }
+        let prev = sa[position[i] - 1];
+        while i + common < n && prev + common < n && s[i + common] == s[prev + common] {
+            common += 1;
+        }
+        lcp[position[i]] = common;
+        common = common.saturating_sub(1);
+    }
+    lcp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn banana() {
+        let sa = suffix_array(b"banana");
+        // a, ana, anana, banana, na, nana
+        assert_eq!(sa, vec![5, 3, 1, 0, 4, 2]);
+        assert_eq!(
+            longest_common_prefix(b"banana", &sa),
+            vec![0, 1, 3, 0, 0, 2]
+        );
+    }
+
+    #[test]
+    fn trivial() {
+        assert!(suffix_array(b"").is_empty());
+        assert_eq!(suffix_array(b"x"), vec![0]);
+        assert_eq!(suffix_array(b"aaaa"), vec![3, 2, 1, 0]);
+        assert_eq!(
+            longest_common_prefix(b"aaaa", &[3, 2, 1, 0]),
+            vec![0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn against_naive_sort() {
+        let mut rng = XorShift::new(37);
+        for _ in 0..200 {
+            let len = rng.gen_range(0, 30) as usize;
+            let s: Vec<u8> = (0..len).map(|_| b'a' + rng.gen_range(0, 3) as u8).collect();
+
+            let mut expected: Vec<usize> = (0..len).collect();
+            expected.sort_by_key(|&i| &s[i..]);
+            let sa = suffix_array(&s);
+            assert_eq!(sa, expected);
+
+            let lcp = longest_common_prefix(&s, &sa);
+            for i in 1..len {
+                let (a, b) = (&s[sa[i - 1]..], &s[sa[i]..]);
+                let common = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+                assert_eq!(lcp[i], common);
+            }
+        }
+    }
+}
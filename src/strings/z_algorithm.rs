@@ -0,0 +1,96 @@
+//! Z-algorithm
+//!
+//! # Algorithm
+//!
+//! The Z-array stores for every position `i` the length of the longest
+//! common prefix of `s` and `s[i..]`. Positions are processed left to right
+//! while keeping the rightmost segment `[left, right)` known to match a
+//! prefix of `s`. A position inside that segment starts from the value of
+//! its counterpart in the prefix, so `right` only ever grows and the whole
+//! array is computed in O(n) time.
+//!
+//! To search, compute the Z-array of `pattern + text`: `pattern` occurs at
+//! offset `j` of `text` iff the entry at `pattern.len() + j` is at least
+//! `pattern.len()`. The usual `$` separator is not needed because the
+//! pattern length is used as the threshold instead, so the bytes of the
+//! inputs are not restricted in any way.
+
+/// Returns the Z-array of `s`. By convention `z[0]` is `s.len()`.
+pub fn z_array(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let mut z = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+
+    let (mut left, mut right) = (0, 0);
+    for i in 1..n {
+        let mut len = if i < right {
+            (right - i).min(z[i - left])
+        } else {
+            0
+        };
+        while i + len < n && s[len] == s[i + len] {
+            len += 1;
+        }
+        z[i] = len;
+        if i + len > right {
+            left = i;
+            right = i + len;
+        }
+    }
+    z
+}
+
+/// Returns the byte offsets of all, possibly overlapping, occurrences of
+/// `pattern` in `text`. An empty pattern matches at every offset.
+pub fn z_search(text: &str, pattern: &str) -> Vec<usize> {
+    let m = pattern.len();
+    let mut s = Vec::with_capacity(m + text.len());
+    s.extend_from_slice(pattern.as_bytes());
+    s.extend_from_slice(text.as_bytes());
+
+    z_array(&s)
+        .iter()
+        .enumerate()
+        .skip(m)
+        .filter(|&(_, &len)| len >= m)
+        .map(|(i, _)| i - m)
+        .chain(if m == 0 { Some(text.len()) } else { None })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hand_computed() {
+        assert_eq!(z_array(b"aabaab"), vec![6, 1, 0, 3, 1, 0]);
+        assert_eq!(z_array(b"aaaaa"), vec![5, 4, 3, 2, 1]);
+        assert_eq!(z_array(b"abacaba"), vec![7, 0, 1, 0, 3, 0, 1]);
+        assert!(z_array(b"").is_empty());
+    }
+
+    #[test]
+    fn overlapping_matches() {
+        assert_eq!(z_search("aaaaaa", "aaaa"), vec![0, 1, 2]);
+        assert_eq!(z_search("abababa", "aba"), vec![0, 2, 4]);
+        assert_eq!(z_search("hello world", "o"), vec![4, 7]);
+    }
+
+    #[test]
+    fn pattern_longer_than_match() {
+        // Without a separator the prefix match must not run past the pattern.
+        assert_eq!(z_search("aab", "aa"), vec![0]);
+        assert!(z_search("ab", "abc").is_empty());
+        assert!(z_search("", "a").is_empty());
+    }
+
+    #[test]
+    fn empty_pattern() {
+        assert_eq!(z_search("abc", ""), vec![0, 1, 2, 3]);
+        assert_eq!(z_search("", ""), vec![0]);
+    }
+}
@@ -0,0 +1,32 @@
+//! Uses the sorts that need neither `std` nor `alloc` the way a `no_std`
+//! user would, on fixed-size arrays.
+//!
+//! Integration tests link against the library as built for users, not with
+//! `cfg(test)`, so `cargo test --no-default-features --test no_std` runs
+//! these against the `no_std` build of the crate.
+
+extern crate the_algorithms_rust;
+
+use the_algorithms_rust::sorting::{block_sort, heap_sort, msd_radix_sort, quick_sort};
+
+#[test]
+fn quick_sort_array() {
+    let mut arr: [i32; 8] = [5, -1, 4, 2, 8, 0, 2, 9];
+    quick_sort(&mut arr);
+    assert_eq!(arr, [-1, 0, 2, 2, 4, 5, 8, 9]);
+}
+
+#[test]
+fn other_sorts_on_arrays() {
+    let mut arr: [i32; 6] = [3, 1, 2, 3, 0, -7];
+    heap_sort(&mut arr);
+    assert_eq!(arr, [-7, 0, 1, 2, 3, 3]);
+
+    let mut arr: [u8; 5] = [9, 9, 1, 0, 4];
+    block_sort(&mut arr);
+    assert_eq!(arr, [0, 1, 4, 9, 9]);
+
+    let mut words: [&str; 4] = ["pear", "fig", "apple", "figs"];
+    msd_radix_sort(&mut words);
+    assert_eq!(words, ["apple", "fig", "figs", "pear"]);
+}